@@ -187,6 +187,23 @@ impl From<u8> for ChannelProtocolType {
     }
 }
 
+impl From<ChannelProtocolType> for u8 {
+    fn from(value: ChannelProtocolType) -> Self {
+        match value {
+            ChannelProtocolType::Reserved => 0x00,
+            ChannelProtocolType::IpmbV1_0 => 0x01,
+            ChannelProtocolType::IcmbV1_0 => 0x02,
+            ChannelProtocolType::IpmiSmbus => 0x04,
+            ChannelProtocolType::Kcs => 0x05,
+            ChannelProtocolType::Smic => 0x06,
+            ChannelProtocolType::Bt10 => 0x07,
+            ChannelProtocolType::Bt15 => 0x08,
+            ChannelProtocolType::TerminalMode => 0x09,
+            ChannelProtocolType::Oem(value) | ChannelProtocolType::ReservedValue(value) => value,
+        }
+    }
+}
+
 impl core::fmt::Display for ChannelProtocolType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         match self {
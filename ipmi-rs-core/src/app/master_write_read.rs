@@ -0,0 +1,99 @@
+use crate::connection::{Address, Channel, IpmiCommand, Message, NetFn, NotEnoughData};
+
+/// An I2C/SMBus bus that can be accessed with a [`MasterWriteRead`] command.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum I2cBus {
+    /// A public bus. Bus ID 0 is the IPMB.
+    Public { bus_id: u8 },
+    /// A private bus behind the management controller.
+    Private { channel: Channel, bus_id: u8 },
+}
+
+impl From<I2cBus> for u8 {
+    fn from(value: I2cBus) -> Self {
+        match value {
+            I2cBus::Public { bus_id } => (bus_id & 0b111) << 1,
+            I2cBus::Private { channel, bus_id } => {
+                (channel.value() << 4) | ((bus_id & 0b111) << 1) | 0x01
+            }
+        }
+    }
+}
+
+/// The Master Write-Read command.
+///
+/// This command writes `data` to, and then reads `read_count` bytes from, a
+/// device on an I2C/SMBus bus. It is used to access non-intelligent devices,
+/// such as FRU EEPROMs.
+///
+/// Reference: IPMI 2.0 Specification, Section 22.11, Table 22-14
+#[derive(Clone, Debug)]
+pub struct MasterWriteRead {
+    bus: I2cBus,
+    slave_address: u8,
+    read_count: u8,
+    data: Vec<u8>,
+    target: Option<(Address, Channel)>,
+}
+
+impl MasterWriteRead {
+    /// Create a new Master Write-Read command for the device with 7-bit
+    /// address `slave_address` on `bus`.
+    pub fn new(bus: I2cBus, slave_address: u8, read_count: u8, data: Vec<u8>) -> Self {
+        Self {
+            bus,
+            slave_address,
+            read_count,
+            data,
+            target: None,
+        }
+    }
+
+    /// Send this command to the management controller at `address` on
+    /// `channel` instead of the BMC.
+    pub fn with_target(mut self, address: Address, channel: Channel) -> Self {
+        self.target = Some((address, channel));
+        self
+    }
+}
+
+impl From<MasterWriteRead> for Message {
+    fn from(value: MasterWriteRead) -> Self {
+        let mut data = Vec::with_capacity(3 + value.data.len());
+        data.push(value.bus.into());
+        data.push(value.slave_address << 1);
+        data.push(value.read_count);
+        data.extend_from_slice(&value.data);
+
+        Message::new_request(NetFn::App, 0x52, data)
+    }
+}
+
+impl IpmiCommand for MasterWriteRead {
+    type Output = Vec<u8>;
+    type Error = NotEnoughData;
+
+    fn parse_success_response(data: &[u8]) -> Result<Self::Output, Self::Error> {
+        Ok(data.to_vec())
+    }
+
+    fn target(&self) -> Option<(Address, Channel)> {
+        self.target
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn encode_private_bus() {
+        let bus = I2cBus::Private {
+            channel: Channel::new(2).unwrap(),
+            bus_id: 3,
+        };
+
+        let message: Message = MasterWriteRead::new(bus, 0x50, 8, vec![0x00]).into();
+        assert_eq!(&[0x27, 0xA0, 0x08, 0x00], message.data());
+    }
+}
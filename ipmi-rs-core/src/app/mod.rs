@@ -14,4 +14,7 @@ pub use get_channel_access::{
     ChannelAccess, ChannelAccessMode, ChannelAccessType, ChannelPrivilegeLevel, GetChannelAccess,
 };
 
+mod master_write_read;
+pub use master_write_read::{I2cBus, MasterWriteRead};
+
 pub mod auth;
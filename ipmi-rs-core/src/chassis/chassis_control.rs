@@ -0,0 +1,66 @@
+use crate::connection::{IpmiCommand, Message, NetFn, NotEnoughData};
+
+/// The action to perform with a [`ChassisControl`] command.
+///
+/// Reference: IPMI 2.0 Specification, Table 28-4
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ChassisControlAction {
+    /// Force the system into a soft off (S4/S5) state, without
+    /// orderly shutdown.
+    PowerDown,
+    /// Power up the system.
+    PowerUp,
+    /// Power the system down, wait for at least 1 second, and power
+    /// it back up.
+    PowerCycle,
+    /// Pulse the system reset signal.
+    HardReset,
+    /// Pulse a version of a diagnostic interrupt (typically NMI).
+    PulseDiagnosticInterrupt,
+    /// Initiate a soft shutdown of the OS via ACPI by emulating a fatal
+    /// overtemperature.
+    SoftShutdown,
+}
+
+impl From<ChassisControlAction> for u8 {
+    fn from(value: ChassisControlAction) -> Self {
+        match value {
+            ChassisControlAction::PowerDown => 0x00,
+            ChassisControlAction::PowerUp => 0x01,
+            ChassisControlAction::PowerCycle => 0x02,
+            ChassisControlAction::HardReset => 0x03,
+            ChassisControlAction::PulseDiagnosticInterrupt => 0x04,
+            ChassisControlAction::SoftShutdown => 0x05,
+        }
+    }
+}
+
+/// The Chassis Control command.
+///
+/// Reference: IPMI 2.0 Specification, Section 28.3, Table 28-4
+#[derive(Clone, Copy, Debug)]
+pub struct ChassisControl {
+    action: ChassisControlAction,
+}
+
+impl ChassisControl {
+    /// Create a new Chassis Control command performing `action`.
+    pub fn new(action: ChassisControlAction) -> Self {
+        Self { action }
+    }
+}
+
+impl From<ChassisControl> for Message {
+    fn from(value: ChassisControl) -> Self {
+        Message::new_request(NetFn::Chassis, 0x02, vec![value.action.into()])
+    }
+}
+
+impl IpmiCommand for ChassisControl {
+    type Output = ();
+    type Error = NotEnoughData;
+
+    fn parse_success_response(_: &[u8]) -> Result<Self::Output, Self::Error> {
+        Ok(())
+    }
+}
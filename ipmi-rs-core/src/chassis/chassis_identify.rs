@@ -0,0 +1,58 @@
+use crate::connection::{IpmiCommand, Message, NetFn, NotEnoughData};
+
+/// The Chassis Identify command.
+///
+/// Reference: IPMI 2.0 Specification, Section 28.5, Table 28-5
+#[derive(Clone, Copy, Debug)]
+pub struct ChassisIdentify {
+    interval: u8,
+    force_on: bool,
+}
+
+impl ChassisIdentify {
+    /// Create a new Chassis Identify command that turns on the identify
+    /// indicator for `interval` seconds.
+    ///
+    /// An `interval` of 0 turns the identify indicator off.
+    pub fn new(interval: u8) -> Self {
+        Self {
+            interval,
+            force_on: false,
+        }
+    }
+
+    /// Create a new Chassis Identify command that turns off the identify indicator.
+    pub fn off() -> Self {
+        Self::new(0)
+    }
+
+    /// Create a new Chassis Identify command that turns on the identify
+    /// indicator until it is explicitly turned off.
+    pub fn force_on() -> Self {
+        Self {
+            interval: 0,
+            force_on: true,
+        }
+    }
+}
+
+impl From<ChassisIdentify> for Message {
+    fn from(value: ChassisIdentify) -> Self {
+        let data = if value.force_on {
+            vec![value.interval, 0x01]
+        } else {
+            vec![value.interval]
+        };
+
+        Message::new_request(NetFn::Chassis, 0x04, data)
+    }
+}
+
+impl IpmiCommand for ChassisIdentify {
+    type Output = ();
+    type Error = NotEnoughData;
+
+    fn parse_success_response(_: &[u8]) -> Result<Self::Output, Self::Error> {
+        Ok(())
+    }
+}
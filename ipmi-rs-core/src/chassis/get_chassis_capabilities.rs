@@ -0,0 +1,70 @@
+use crate::connection::{Address, IpmiCommand, Message, NetFn, NotEnoughData};
+
+/// The Get Chassis Capabilities command.
+///
+/// Reference: IPMI 2.0 Specification, Section 28.1, Table 28-1
+pub struct GetChassisCapabilities;
+
+impl From<GetChassisCapabilities> for Message {
+    fn from(_: GetChassisCapabilities) -> Self {
+        Message::new_request(NetFn::Chassis, 0x00, Vec::new())
+    }
+}
+
+impl IpmiCommand for GetChassisCapabilities {
+    type Output = ChassisCapabilities;
+    type Error = NotEnoughData;
+
+    fn parse_success_response(data: &[u8]) -> Result<Self::Output, Self::Error> {
+        ChassisCapabilities::parse(data).ok_or(NotEnoughData)
+    }
+}
+
+/// The capabilities of a chassis, and the addresses of the devices
+/// that provide chassis functionality.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ChassisCapabilities {
+    /// The chassis provides a power interlock.
+    pub power_interlock: bool,
+    /// The chassis provides a front panel diagnostic interrupt (NMI).
+    pub diagnostic_interrupt: bool,
+    /// The chassis provides a front panel lockout.
+    pub front_panel_lockout: bool,
+    /// The chassis provides an intrusion sensor.
+    pub intrusion_sensor: bool,
+    /// The address of the chassis FRU info device.
+    pub fru_info_device_address: Address,
+    /// The address of the chassis SDR device.
+    pub sdr_device_address: Address,
+    /// The address of the chassis SEL device.
+    pub sel_device_address: Address,
+    /// The address of the system management device.
+    pub system_management_device_address: Address,
+    /// The address of the chassis bridge device, if reported.
+    pub bridge_device_address: Option<Address>,
+}
+
+impl ChassisCapabilities {
+    /// Parse `ChassisCapabilities` from IPMI response data.
+    ///
+    /// Reference: IPMI 2.0 Specification, Table 28-1
+    pub fn parse(data: &[u8]) -> Option<Self> {
+        if data.len() < 5 {
+            return None;
+        }
+
+        let flags = data[0];
+
+        Some(Self {
+            power_interlock: (flags & 0x08) == 0x08,
+            diagnostic_interrupt: (flags & 0x04) == 0x04,
+            front_panel_lockout: (flags & 0x02) == 0x02,
+            intrusion_sensor: (flags & 0x01) == 0x01,
+            fru_info_device_address: Address(data[1]),
+            sdr_device_address: Address(data[2]),
+            sel_device_address: Address(data[3]),
+            system_management_device_address: Address(data[4]),
+            bridge_device_address: data.get(5).copied().map(Address),
+        })
+    }
+}
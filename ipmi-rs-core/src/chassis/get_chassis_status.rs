@@ -0,0 +1,295 @@
+use crate::connection::{IpmiCommand, Message, NetFn, NotEnoughData};
+
+/// The Get Chassis Status command.
+///
+/// Reference: IPMI 2.0 Specification, Section 28.2, Table 28-3
+pub struct GetChassisStatus;
+
+impl From<GetChassisStatus> for Message {
+    fn from(_: GetChassisStatus) -> Self {
+        Message::new_request(NetFn::Chassis, 0x01, Vec::new())
+    }
+}
+
+impl IpmiCommand for GetChassisStatus {
+    type Output = ChassisStatus;
+    type Error = NotEnoughData;
+
+    fn parse_success_response(data: &[u8]) -> Result<Self::Output, Self::Error> {
+        ChassisStatus::parse(data).ok_or(NotEnoughData)
+    }
+}
+
+/// The power state of the system.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum PowerState {
+    On,
+    Off,
+}
+
+impl core::fmt::Display for PowerState {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            PowerState::On => write!(f, "On"),
+            PowerState::Off => write!(f, "Off"),
+        }
+    }
+}
+
+/// The policy the chassis follows when AC power is restored.
+///
+/// Reference: IPMI 2.0 Specification, Table 28-3, byte 1 bits \[6:5\]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum PowerRestorePolicy {
+    /// The chassis stays powered off after AC/mains returns.
+    AlwaysOff,
+    /// The chassis restores the power state that was in effect when
+    /// AC/mains was lost.
+    PreviousState,
+    /// The chassis always powers up after AC/mains returns.
+    AlwaysOn,
+    /// The policy is unknown.
+    Unknown,
+}
+
+impl From<u8> for PowerRestorePolicy {
+    fn from(value: u8) -> Self {
+        match value & 0b11 {
+            0b00 => Self::AlwaysOff,
+            0b01 => Self::PreviousState,
+            0b10 => Self::AlwaysOn,
+            _ => Self::Unknown,
+        }
+    }
+}
+
+impl core::fmt::Display for PowerRestorePolicy {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            PowerRestorePolicy::AlwaysOff => write!(f, "Always off"),
+            PowerRestorePolicy::PreviousState => write!(f, "Previous state"),
+            PowerRestorePolicy::AlwaysOn => write!(f, "Always on"),
+            PowerRestorePolicy::Unknown => write!(f, "Unknown"),
+        }
+    }
+}
+
+/// The current power state of the chassis.
+///
+/// Reference: IPMI 2.0 Specification, Table 28-3, byte 1
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct CurrentPowerState {
+    /// The power restore policy of the chassis.
+    pub power_restore_policy: PowerRestorePolicy,
+    /// The controller attempted to turn system power on or off, but
+    /// the system did not enter the desired state.
+    pub power_control_fault: bool,
+    /// A fault has been detected in the main power subsystem.
+    pub power_fault: bool,
+    /// The system was shut down because a chassis panel interlock switch
+    /// is active.
+    pub interlock: bool,
+    /// The system was shut down because of a power overload.
+    pub power_overload: bool,
+    /// System power is on.
+    pub power_on: bool,
+}
+
+impl From<u8> for CurrentPowerState {
+    fn from(value: u8) -> Self {
+        Self {
+            power_restore_policy: PowerRestorePolicy::from(value >> 5),
+            power_control_fault: (value & 0x10) == 0x10,
+            power_fault: (value & 0x08) == 0x08,
+            interlock: (value & 0x04) == 0x04,
+            power_overload: (value & 0x02) == 0x02,
+            power_on: (value & 0x01) == 0x01,
+        }
+    }
+}
+
+/// The cause of the last power event.
+///
+/// Reference: IPMI 2.0 Specification, Table 28-3, byte 2
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct LastPowerEvent {
+    /// The last power-on was entered via an IPMI command.
+    pub power_on_via_ipmi: bool,
+    /// The last power down was caused by a power fault.
+    pub power_down_by_power_fault: bool,
+    /// The last power down was caused by a power interlock being activated.
+    pub power_down_by_interlock: bool,
+    /// The last power down was caused by a power overload.
+    pub power_down_by_overload: bool,
+    /// AC failed.
+    pub ac_failed: bool,
+}
+
+impl From<u8> for LastPowerEvent {
+    fn from(value: u8) -> Self {
+        Self {
+            power_on_via_ipmi: (value & 0x10) == 0x10,
+            power_down_by_power_fault: (value & 0x08) == 0x08,
+            power_down_by_interlock: (value & 0x04) == 0x04,
+            power_down_by_overload: (value & 0x02) == 0x02,
+            ac_failed: (value & 0x01) == 0x01,
+        }
+    }
+}
+
+/// The state of the chassis identify function.
+///
+/// Reference: IPMI 2.0 Specification, Table 28-3, byte 3 bits \[5:4\]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ChassisIdentifyState {
+    Off,
+    TemporaryOn,
+    IndefiniteOn,
+    Reserved,
+}
+
+impl From<u8> for ChassisIdentifyState {
+    fn from(value: u8) -> Self {
+        match value & 0b11 {
+            0b00 => Self::Off,
+            0b01 => Self::TemporaryOn,
+            0b10 => Self::IndefiniteOn,
+            _ => Self::Reserved,
+        }
+    }
+}
+
+/// Miscellaneous chassis state.
+///
+/// Reference: IPMI 2.0 Specification, Table 28-3, byte 3
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct MiscChassisState {
+    /// The state of the chassis identify function, if the
+    /// chassis reports it.
+    pub identify_state: Option<ChassisIdentifyState>,
+    /// A cooling or fan fault has been detected.
+    pub cooling_fault: bool,
+    /// A drive fault has been detected.
+    pub drive_fault: bool,
+    /// The front panel lockout is active (power off and reset via the
+    /// chassis push-buttons are disabled).
+    pub front_panel_lockout: bool,
+    /// Chassis intrusion is active.
+    pub chassis_intrusion: bool,
+}
+
+impl From<u8> for MiscChassisState {
+    fn from(value: u8) -> Self {
+        let identify_state = if (value & 0x40) == 0x40 {
+            Some(ChassisIdentifyState::from(value >> 4))
+        } else {
+            None
+        };
+
+        Self {
+            identify_state,
+            cooling_fault: (value & 0x08) == 0x08,
+            drive_fault: (value & 0x04) == 0x04,
+            front_panel_lockout: (value & 0x02) == 0x02,
+            chassis_intrusion: (value & 0x01) == 0x01,
+        }
+    }
+}
+
+/// The capabilities and state of the front panel buttons.
+///
+/// Reference: IPMI 2.0 Specification, Table 28-3, byte 4
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct FrontPanelButtonCapabilities {
+    pub standby_disable_allowed: bool,
+    pub diagnostic_interrupt_disable_allowed: bool,
+    pub reset_disable_allowed: bool,
+    pub power_off_disable_allowed: bool,
+    pub standby_disabled: bool,
+    pub diagnostic_interrupt_disabled: bool,
+    pub reset_disabled: bool,
+    pub power_off_disabled: bool,
+}
+
+impl From<u8> for FrontPanelButtonCapabilities {
+    fn from(value: u8) -> Self {
+        Self {
+            standby_disable_allowed: (value & 0x80) == 0x80,
+            diagnostic_interrupt_disable_allowed: (value & 0x40) == 0x40,
+            reset_disable_allowed: (value & 0x20) == 0x20,
+            power_off_disable_allowed: (value & 0x10) == 0x10,
+            standby_disabled: (value & 0x08) == 0x08,
+            diagnostic_interrupt_disabled: (value & 0x04) == 0x04,
+            reset_disabled: (value & 0x02) == 0x02,
+            power_off_disabled: (value & 0x01) == 0x01,
+        }
+    }
+}
+
+/// The status of a chassis.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ChassisStatus {
+    pub current_power_state: CurrentPowerState,
+    pub last_power_event: LastPowerEvent,
+    pub misc_chassis_state: MiscChassisState,
+    /// The front panel button capabilities, if reported.
+    pub front_panel_buttons: Option<FrontPanelButtonCapabilities>,
+}
+
+impl ChassisStatus {
+    /// Parse `ChassisStatus` from IPMI response data.
+    ///
+    /// Reference: IPMI 2.0 Specification, Table 28-3
+    pub fn parse(data: &[u8]) -> Option<Self> {
+        if data.len() < 3 {
+            return None;
+        }
+
+        Some(Self {
+            current_power_state: CurrentPowerState::from(data[0]),
+            last_power_event: LastPowerEvent::from(data[1]),
+            misc_chassis_state: MiscChassisState::from(data[2]),
+            front_panel_buttons: data.get(3).copied().map(FrontPanelButtonCapabilities::from),
+        })
+    }
+
+    /// The power state of the system.
+    pub fn power_state(&self) -> PowerState {
+        if self.current_power_state.power_on {
+            PowerState::On
+        } else {
+            PowerState::Off
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_chassis_status() {
+        let status = ChassisStatus::parse(&[0x41, 0x10, 0x60]).unwrap();
+
+        assert_eq!(PowerState::On, status.power_state());
+        assert_eq!(
+            PowerRestorePolicy::AlwaysOn,
+            status.current_power_state.power_restore_policy
+        );
+        assert!(status.last_power_event.power_on_via_ipmi);
+        assert_eq!(
+            Some(ChassisIdentifyState::IndefiniteOn),
+            status.misc_chassis_state.identify_state
+        );
+        assert_eq!(None, status.front_panel_buttons);
+    }
+
+    #[test]
+    fn identify_state_requires_support_bit() {
+        let status = ChassisStatus::parse(&[0x00, 0x00, 0x20, 0x00]).unwrap();
+
+        assert_eq!(PowerState::Off, status.power_state());
+        assert_eq!(None, status.misc_chassis_state.identify_state);
+        assert!(status.front_panel_buttons.is_some());
+    }
+}
@@ -0,0 +1,66 @@
+use core::time::Duration;
+
+use crate::connection::{IpmiCommand, Message, NetFn, NotEnoughData};
+
+/// The Get POH (Power-On Hours) Counter command.
+///
+/// Reference: IPMI 2.0 Specification, Section 28.14, Table 28-15
+pub struct GetPohCounter;
+
+impl From<GetPohCounter> for Message {
+    fn from(_: GetPohCounter) -> Self {
+        Message::new_request(NetFn::Chassis, 0x0F, Vec::new())
+    }
+}
+
+impl IpmiCommand for GetPohCounter {
+    type Output = PohCounter;
+    type Error = NotEnoughData;
+
+    fn parse_success_response(data: &[u8]) -> Result<Self::Output, Self::Error> {
+        PohCounter::parse(data).ok_or(NotEnoughData)
+    }
+}
+
+/// The value of the power-on hours counter.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PohCounter {
+    /// The amount of minutes represented by a single count.
+    pub minutes_per_count: u8,
+    /// The raw counter value.
+    pub counter: u32,
+}
+
+impl PohCounter {
+    /// Parse `PohCounter` from IPMI response data.
+    ///
+    /// Reference: IPMI 2.0 Specification, Table 28-15
+    pub fn parse(data: &[u8]) -> Option<Self> {
+        if data.len() < 5 {
+            return None;
+        }
+
+        Some(Self {
+            minutes_per_count: data[0],
+            counter: u32::from_le_bytes([data[1], data[2], data[3], data[4]]),
+        })
+    }
+
+    /// The total amount of time that the system has been powered on.
+    pub fn power_on_time(&self) -> Duration {
+        Duration::from_secs(u64::from(self.counter) * u64::from(self.minutes_per_count) * 60)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn poh_counter_duration() {
+        let counter = PohCounter::parse(&[0x3C, 0x10, 0x27, 0x00, 0x00]).unwrap();
+
+        assert_eq!(10_000, counter.counter);
+        assert_eq!(Duration::from_secs(10_000 * 3600), counter.power_on_time());
+    }
+}
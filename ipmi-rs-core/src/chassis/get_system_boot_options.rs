@@ -0,0 +1,773 @@
+use crate::{
+    connection::{IpmiCommand, Message, NetFn, NotEnoughData},
+    storage::Timestamp,
+};
+
+/// Get System Boot Options command.
+///
+/// Reference: IPMI 2.0 Specification, Section 28.13, Table 28-14.
+#[derive(Clone, Debug)]
+pub struct GetSystemBootOptions {
+    parameter: BootOptionParameter,
+    set_selector: u8,
+    block_selector: u8,
+}
+
+impl GetSystemBootOptions {
+    /// Create a new Get System Boot Options command.
+    pub fn new(parameter: BootOptionParameter) -> Self {
+        Self {
+            parameter,
+            set_selector: 0,
+            block_selector: 0,
+        }
+    }
+
+    /// Set the set selector used for parameters that have multiple entries.
+    pub fn with_set_selector(mut self, set_selector: u8) -> Self {
+        self.set_selector = set_selector;
+        self
+    }
+
+    /// Set the block selector used for parameters that are paged.
+    pub fn with_block_selector(mut self, block_selector: u8) -> Self {
+        self.block_selector = block_selector;
+        self
+    }
+}
+
+impl From<GetSystemBootOptions> for Message {
+    fn from(value: GetSystemBootOptions) -> Self {
+        Message::new_request(
+            NetFn::Chassis,
+            0x09,
+            vec![
+                value.parameter.value() & 0x7F,
+                value.set_selector,
+                value.block_selector,
+            ],
+        )
+    }
+}
+
+impl IpmiCommand for GetSystemBootOptions {
+    type Output = BootOptionParameterResponse;
+    type Error = NotEnoughData;
+
+    fn parse_success_response(data: &[u8]) -> Result<Self::Output, Self::Error> {
+        if data.len() < 2 {
+            return Err(NotEnoughData);
+        }
+
+        Ok(BootOptionParameterResponse {
+            parameter_version: data[0] & 0x0F,
+            parameter_valid: (data[1] & 0x80) != 0x80,
+            parameter: BootOptionParameter::from(data[1] & 0x7F),
+            data: data[2..].to_vec(),
+        })
+    }
+}
+
+/// Boot option parameters.
+///
+/// Reference: IPMI 2.0 Specification, Table 28-14.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum BootOptionParameter {
+    SetInProgress,
+    ServicePartitionSelector,
+    ServicePartitionScan,
+    BootFlagValidBitClearing,
+    BootInfoAcknowledge,
+    BootFlags,
+    BootInitiatorInfo,
+    BootInitiatorMailbox,
+    Oem(u8),
+    Other(u8),
+}
+
+impl From<u8> for BootOptionParameter {
+    fn from(value: u8) -> Self {
+        match value & 0x7F {
+            0 => Self::SetInProgress,
+            1 => Self::ServicePartitionSelector,
+            2 => Self::ServicePartitionScan,
+            3 => Self::BootFlagValidBitClearing,
+            4 => Self::BootInfoAcknowledge,
+            5 => Self::BootFlags,
+            6 => Self::BootInitiatorInfo,
+            7 => Self::BootInitiatorMailbox,
+            v @ 96..=127 => Self::Oem(v),
+            v => Self::Other(v),
+        }
+    }
+}
+
+impl BootOptionParameter {
+    /// Get the raw parameter selector value.
+    pub fn value(&self) -> u8 {
+        match self {
+            BootOptionParameter::SetInProgress => 0,
+            BootOptionParameter::ServicePartitionSelector => 1,
+            BootOptionParameter::ServicePartitionScan => 2,
+            BootOptionParameter::BootFlagValidBitClearing => 3,
+            BootOptionParameter::BootInfoAcknowledge => 4,
+            BootOptionParameter::BootFlags => 5,
+            BootOptionParameter::BootInitiatorInfo => 6,
+            BootOptionParameter::BootInitiatorMailbox => 7,
+            BootOptionParameter::Oem(value) | BootOptionParameter::Other(value) => *value,
+        }
+    }
+
+    /// Parse known boot option parameter data.
+    pub fn parse(&self, data: &[u8]) -> Result<BootOptionParameterData, NotEnoughData> {
+        use BootOptionParameterData::*;
+
+        if data.is_empty() {
+            return Ok(None);
+        }
+
+        let value = match self {
+            BootOptionParameter::SetInProgress => SetInProgress(self::SetInProgress::from(data[0])),
+            BootOptionParameter::ServicePartitionSelector => ServicePartitionSelector(data[0]),
+            BootOptionParameter::ServicePartitionScan => {
+                ServicePartitionScan(self::ServicePartitionScan::from(data[0]))
+            }
+            BootOptionParameter::BootFlagValidBitClearing => {
+                BootFlagValidBitClearing(self::BootFlagValidBitClearing::from(data[0]))
+            }
+            BootOptionParameter::BootInfoAcknowledge => {
+                BootInfoAcknowledge(self::BootInfoAcknowledge::from_slice(data)?)
+            }
+            BootOptionParameter::BootFlags => BootFlags(self::BootFlags::from_slice(data)?),
+            BootOptionParameter::BootInitiatorInfo => {
+                BootInitiatorInfo(self::BootInitiatorInfo::from_slice(data)?)
+            }
+            BootOptionParameter::BootInitiatorMailbox => {
+                BootInitiatorMailbox(self::BootInitiatorMailbox::from_slice(data)?)
+            }
+            _ => Raw(data.to_vec()),
+        };
+
+        Ok(value)
+    }
+}
+
+/// Boot option parameter response data.
+#[derive(Clone, Debug, PartialEq)]
+pub struct BootOptionParameterResponse {
+    pub parameter_version: u8,
+    /// `false` if the parameter has been marked invalid/locked.
+    pub parameter_valid: bool,
+    pub parameter: BootOptionParameter,
+    pub data: Vec<u8>,
+}
+
+impl BootOptionParameterResponse {
+    /// Parse the boot option parameter data of this response.
+    pub fn parse(&self) -> Result<BootOptionParameterData, NotEnoughData> {
+        self.parameter.parse(&self.data)
+    }
+}
+
+/// Boot option parameter data variants.
+#[derive(Clone, Debug, PartialEq)]
+pub enum BootOptionParameterData {
+    None,
+    SetInProgress(SetInProgress),
+    ServicePartitionSelector(u8),
+    ServicePartitionScan(ServicePartitionScan),
+    BootFlagValidBitClearing(BootFlagValidBitClearing),
+    BootInfoAcknowledge(BootInfoAcknowledge),
+    BootFlags(BootFlags),
+    BootInitiatorInfo(BootInitiatorInfo),
+    BootInitiatorMailbox(BootInitiatorMailbox),
+    Raw(Vec<u8>),
+}
+
+impl BootOptionParameterData {
+    /// Serialize boot option parameter data into raw bytes.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        match self {
+            BootOptionParameterData::None => Vec::new(),
+            BootOptionParameterData::SetInProgress(value) => vec![(*value).into()],
+            BootOptionParameterData::ServicePartitionSelector(value) => vec![*value],
+            BootOptionParameterData::ServicePartitionScan(value) => vec![(*value).into()],
+            BootOptionParameterData::BootFlagValidBitClearing(value) => vec![(*value).into()],
+            BootOptionParameterData::BootInfoAcknowledge(value) => value.to_bytes().to_vec(),
+            BootOptionParameterData::BootFlags(value) => value.to_bytes().to_vec(),
+            BootOptionParameterData::BootInitiatorInfo(value) => value.to_bytes().to_vec(),
+            BootOptionParameterData::BootInitiatorMailbox(value) => value.to_bytes(),
+            BootOptionParameterData::Raw(bytes) => bytes.clone(),
+        }
+    }
+}
+
+/// The state of the "set in progress" parameter.
+///
+/// Reference: IPMI 2.0 Specification, Table 28-14, parameter #0.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SetInProgress {
+    SetComplete,
+    SetInProgress,
+    CommitWrite,
+    Reserved,
+}
+
+impl From<u8> for SetInProgress {
+    fn from(value: u8) -> Self {
+        match value & 0b11 {
+            0b00 => Self::SetComplete,
+            0b01 => Self::SetInProgress,
+            0b10 => Self::CommitWrite,
+            _ => Self::Reserved,
+        }
+    }
+}
+
+impl From<SetInProgress> for u8 {
+    fn from(value: SetInProgress) -> Self {
+        match value {
+            SetInProgress::SetComplete => 0b00,
+            SetInProgress::SetInProgress => 0b01,
+            SetInProgress::CommitWrite => 0b10,
+            SetInProgress::Reserved => 0b11,
+        }
+    }
+}
+
+/// Service partition scan.
+///
+/// Reference: IPMI 2.0 Specification, Table 28-14, parameter #2.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ServicePartitionScan {
+    /// Request BIOS to scan for the specified service partition.
+    pub request_scan: bool,
+    /// The service partition has been discovered.
+    pub discovered: bool,
+}
+
+impl From<u8> for ServicePartitionScan {
+    fn from(value: u8) -> Self {
+        Self {
+            request_scan: (value & 0x02) == 0x02,
+            discovered: (value & 0x01) == 0x01,
+        }
+    }
+}
+
+impl From<ServicePartitionScan> for u8 {
+    fn from(value: ServicePartitionScan) -> Self {
+        (if value.request_scan { 0x02 } else { 0x00 })
+            | (if value.discovered { 0x01 } else { 0x00 })
+    }
+}
+
+/// Conditions under which the BMC should _not_ automatically clear the
+/// boot flags valid bit.
+///
+/// Reference: IPMI 2.0 Specification, Table 28-14, parameter #3.
+#[derive(Clone, Copy, Debug, PartialEq, Default)]
+pub struct BootFlagValidBitClearing {
+    /// Don't clear the valid bit on a reset or power cycle caused by PEF.
+    pub dont_clear_on_pef: bool,
+    /// Don't automatically clear the valid bit if the 60 second chassis
+    /// control command timeout expires.
+    pub dont_clear_on_command_timeout: bool,
+    /// Don't clear the valid bit on a reset or power cycle caused by the
+    /// watchdog timer.
+    pub dont_clear_on_watchdog: bool,
+    /// Don't clear the valid bit on a push-button reset or soft reset.
+    pub dont_clear_on_reset: bool,
+    /// Don't clear the valid bit on a power up via the power push-button
+    /// or a wake event.
+    pub dont_clear_on_power_up: bool,
+}
+
+impl From<u8> for BootFlagValidBitClearing {
+    fn from(value: u8) -> Self {
+        Self {
+            dont_clear_on_pef: (value & 0x10) == 0x10,
+            dont_clear_on_command_timeout: (value & 0x08) == 0x08,
+            dont_clear_on_watchdog: (value & 0x04) == 0x04,
+            dont_clear_on_reset: (value & 0x02) == 0x02,
+            dont_clear_on_power_up: (value & 0x01) == 0x01,
+        }
+    }
+}
+
+impl From<BootFlagValidBitClearing> for u8 {
+    fn from(value: BootFlagValidBitClearing) -> Self {
+        let mut out = 0;
+        for (set, bit) in [
+            (value.dont_clear_on_pef, 0x10),
+            (value.dont_clear_on_command_timeout, 0x08),
+            (value.dont_clear_on_watchdog, 0x04),
+            (value.dont_clear_on_reset, 0x02),
+            (value.dont_clear_on_power_up, 0x01),
+        ] {
+            if set {
+                out |= bit;
+            }
+        }
+        out
+    }
+}
+
+bitflags::bitflags! {
+    /// Parties that can handle boot information.
+    ///
+    /// Reference: IPMI 2.0 Specification, Table 28-14, parameter #4.
+    pub struct BootInitiators: u8 {
+        const OEM = 1 << 4;
+        const SMS = 1 << 3;
+        const OS_SERVICE_PARTITION = 1 << 2;
+        const OS_LOADER = 1 << 1;
+        const BIOS_POST = 1 << 0;
+    }
+}
+
+/// Boot info acknowledge.
+///
+/// Reference: IPMI 2.0 Specification, Table 28-14, parameter #4.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct BootInfoAcknowledge {
+    /// The parties whose acknowledge state is written by a set command.
+    /// Ignored when read.
+    pub write_mask: BootInitiators,
+    /// The parties that have handled the boot information.
+    pub handled_by: BootInitiators,
+}
+
+impl BootInfoAcknowledge {
+    fn from_slice(data: &[u8]) -> Result<Self, NotEnoughData> {
+        if data.len() < 2 {
+            return Err(NotEnoughData);
+        }
+
+        Ok(Self {
+            write_mask: BootInitiators::from_bits_truncate(data[0]),
+            // A cleared bit indicates that the party has handled the boot info.
+            handled_by: BootInitiators::from_bits_truncate(!data[1]),
+        })
+    }
+
+    fn to_bytes(self) -> [u8; 2] {
+        [
+            self.write_mask.bits(),
+            !self.handled_by.bits() & BootInitiators::all().bits(),
+        ]
+    }
+}
+
+/// The BIOS boot type.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum BiosBootType {
+    /// PC compatible (legacy) boot.
+    Legacy,
+    /// Extensible Firmware Interface boot.
+    Efi,
+}
+
+/// The device to boot from.
+///
+/// Reference: IPMI 2.0 Specification, Table 28-14, parameter #5, data 2 bits \[5:2\].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum BootDevice {
+    NoOverride,
+    Pxe,
+    HardDrive,
+    HardDriveSafeMode,
+    DiagnosticPartition,
+    CdDvd,
+    BiosSetup,
+    RemoteFloppy,
+    RemoteCdDvd,
+    PrimaryRemoteMedia,
+    RemoteHardDrive,
+    Floppy,
+    Reserved(u8),
+}
+
+impl From<u8> for BootDevice {
+    fn from(value: u8) -> Self {
+        match value & 0x0F {
+            0b0000 => Self::NoOverride,
+            0b0001 => Self::Pxe,
+            0b0010 => Self::HardDrive,
+            0b0011 => Self::HardDriveSafeMode,
+            0b0100 => Self::DiagnosticPartition,
+            0b0101 => Self::CdDvd,
+            0b0110 => Self::BiosSetup,
+            0b0111 => Self::RemoteFloppy,
+            0b1000 => Self::RemoteCdDvd,
+            0b1001 => Self::PrimaryRemoteMedia,
+            0b1011 => Self::RemoteHardDrive,
+            0b1111 => Self::Floppy,
+            v => Self::Reserved(v),
+        }
+    }
+}
+
+impl From<BootDevice> for u8 {
+    fn from(value: BootDevice) -> Self {
+        match value {
+            BootDevice::NoOverride => 0b0000,
+            BootDevice::Pxe => 0b0001,
+            BootDevice::HardDrive => 0b0010,
+            BootDevice::HardDriveSafeMode => 0b0011,
+            BootDevice::DiagnosticPartition => 0b0100,
+            BootDevice::CdDvd => 0b0101,
+            BootDevice::BiosSetup => 0b0110,
+            BootDevice::RemoteFloppy => 0b0111,
+            BootDevice::RemoteCdDvd => 0b1000,
+            BootDevice::PrimaryRemoteMedia => 0b1001,
+            BootDevice::RemoteHardDrive => 0b1011,
+            BootDevice::Floppy => 0b1111,
+            BootDevice::Reserved(v) => v & 0x0F,
+        }
+    }
+}
+
+impl core::fmt::Display for BootDevice {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            BootDevice::NoOverride => write!(f, "No override"),
+            BootDevice::Pxe => write!(f, "PXE"),
+            BootDevice::HardDrive => write!(f, "Hard drive"),
+            BootDevice::HardDriveSafeMode => write!(f, "Hard drive (safe mode)"),
+            BootDevice::DiagnosticPartition => write!(f, "Diagnostic partition"),
+            BootDevice::CdDvd => write!(f, "CD/DVD"),
+            BootDevice::BiosSetup => write!(f, "BIOS setup"),
+            BootDevice::RemoteFloppy => write!(f, "Remote floppy/primary removable media"),
+            BootDevice::RemoteCdDvd => write!(f, "Remote CD/DVD"),
+            BootDevice::PrimaryRemoteMedia => write!(f, "Primary remote media"),
+            BootDevice::RemoteHardDrive => write!(f, "Remote hard drive"),
+            BootDevice::Floppy => write!(f, "Floppy/primary removable media"),
+            BootDevice::Reserved(v) => write!(f, "Reserved (0x{v:02X})"),
+        }
+    }
+}
+
+/// Firmware (BIOS) verbosity.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum FirmwareVerbosity {
+    Default,
+    Quiet,
+    Verbose,
+    Reserved,
+}
+
+impl From<u8> for FirmwareVerbosity {
+    fn from(value: u8) -> Self {
+        match value & 0b11 {
+            0b00 => Self::Default,
+            0b01 => Self::Quiet,
+            0b10 => Self::Verbose,
+            _ => Self::Reserved,
+        }
+    }
+}
+
+impl From<FirmwareVerbosity> for u8 {
+    fn from(value: FirmwareVerbosity) -> Self {
+        match value {
+            FirmwareVerbosity::Default => 0b00,
+            FirmwareVerbosity::Quiet => 0b01,
+            FirmwareVerbosity::Verbose => 0b10,
+            FirmwareVerbosity::Reserved => 0b11,
+        }
+    }
+}
+
+/// Console redirection control.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ConsoleRedirection {
+    Default,
+    Suppress,
+    Enable,
+    Reserved,
+}
+
+impl From<u8> for ConsoleRedirection {
+    fn from(value: u8) -> Self {
+        match value & 0b11 {
+            0b00 => Self::Default,
+            0b01 => Self::Suppress,
+            0b10 => Self::Enable,
+            _ => Self::Reserved,
+        }
+    }
+}
+
+impl From<ConsoleRedirection> for u8 {
+    fn from(value: ConsoleRedirection) -> Self {
+        match value {
+            ConsoleRedirection::Default => 0b00,
+            ConsoleRedirection::Suppress => 0b01,
+            ConsoleRedirection::Enable => 0b10,
+            ConsoleRedirection::Reserved => 0b11,
+        }
+    }
+}
+
+/// BIOS mux control override.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum BiosMuxControl {
+    /// BIOS uses the recommended setting of the mux at the end of POST.
+    Recommended,
+    /// Request BIOS to force the mux to the BMC at the end of POST.
+    ForceBmc,
+    /// Request BIOS to force the mux to the system at the end of POST.
+    ForceSystem,
+    Reserved(u8),
+}
+
+impl From<u8> for BiosMuxControl {
+    fn from(value: u8) -> Self {
+        match value & 0b111 {
+            0b000 => Self::Recommended,
+            0b001 => Self::ForceBmc,
+            0b010 => Self::ForceSystem,
+            v => Self::Reserved(v),
+        }
+    }
+}
+
+impl From<BiosMuxControl> for u8 {
+    fn from(value: BiosMuxControl) -> Self {
+        match value {
+            BiosMuxControl::Recommended => 0b000,
+            BiosMuxControl::ForceBmc => 0b001,
+            BiosMuxControl::ForceSystem => 0b010,
+            BiosMuxControl::Reserved(v) => v & 0b111,
+        }
+    }
+}
+
+/// Boot flags.
+///
+/// Reference: IPMI 2.0 Specification, Table 28-14, parameter #5.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct BootFlags {
+    /// The boot flags are valid.
+    pub valid: bool,
+    /// `true` if the options apply to all future boots, `false` if they
+    /// apply to the next boot only.
+    pub persistent: bool,
+    pub boot_type: BiosBootType,
+    pub clear_cmos: bool,
+    pub lock_keyboard: bool,
+    pub boot_device: BootDevice,
+    pub screen_blank: bool,
+    pub lock_out_reset_button: bool,
+    pub lock_out_power_button: bool,
+    pub firmware_verbosity: FirmwareVerbosity,
+    pub force_progress_event_traps: bool,
+    pub user_password_bypass: bool,
+    pub lock_out_sleep_button: bool,
+    pub console_redirection: ConsoleRedirection,
+    pub bios_shared_mode_override: bool,
+    pub bios_mux_control: BiosMuxControl,
+    /// The device instance selector (5 bits).
+    pub device_instance: u8,
+}
+
+impl BootFlags {
+    /// Create valid boot flags that boot from `boot_device` on the next boot,
+    /// leaving all other options at their system defaults.
+    pub fn new(boot_device: BootDevice) -> Self {
+        Self {
+            valid: true,
+            persistent: false,
+            boot_type: BiosBootType::Legacy,
+            clear_cmos: false,
+            lock_keyboard: false,
+            boot_device,
+            screen_blank: false,
+            lock_out_reset_button: false,
+            lock_out_power_button: false,
+            firmware_verbosity: FirmwareVerbosity::Default,
+            force_progress_event_traps: false,
+            user_password_bypass: false,
+            lock_out_sleep_button: false,
+            console_redirection: ConsoleRedirection::Default,
+            bios_shared_mode_override: false,
+            bios_mux_control: BiosMuxControl::Recommended,
+            device_instance: 0,
+        }
+    }
+
+    /// Apply these boot flags to all future boots instead of only the next one.
+    pub fn persistent(mut self, persistent: bool) -> Self {
+        self.persistent = persistent;
+        self
+    }
+
+    /// Set the BIOS boot type.
+    pub fn with_boot_type(mut self, boot_type: BiosBootType) -> Self {
+        self.boot_type = boot_type;
+        self
+    }
+
+    fn from_slice(data: &[u8]) -> Result<Self, NotEnoughData> {
+        if data.len() < 5 {
+            return Err(NotEnoughData);
+        }
+
+        let boot_type = if (data[0] & 0x20) == 0x20 {
+            BiosBootType::Efi
+        } else {
+            BiosBootType::Legacy
+        };
+
+        Ok(Self {
+            valid: (data[0] & 0x80) == 0x80,
+            persistent: (data[0] & 0x40) == 0x40,
+            boot_type,
+            clear_cmos: (data[1] & 0x80) == 0x80,
+            lock_keyboard: (data[1] & 0x40) == 0x40,
+            boot_device: BootDevice::from(data[1] >> 2),
+            screen_blank: (data[1] & 0x02) == 0x02,
+            lock_out_reset_button: (data[1] & 0x01) == 0x01,
+            lock_out_power_button: (data[2] & 0x80) == 0x80,
+            firmware_verbosity: FirmwareVerbosity::from(data[2] >> 5),
+            force_progress_event_traps: (data[2] & 0x10) == 0x10,
+            user_password_bypass: (data[2] & 0x08) == 0x08,
+            lock_out_sleep_button: (data[2] & 0x04) == 0x04,
+            console_redirection: ConsoleRedirection::from(data[2]),
+            bios_shared_mode_override: (data[3] & 0x08) == 0x08,
+            bios_mux_control: BiosMuxControl::from(data[3]),
+            device_instance: data[4] & 0x1F,
+        })
+    }
+
+    /// Serialize the boot flags into raw bytes.
+    pub fn to_bytes(&self) -> [u8; 5] {
+        let bit = |set: bool, bit: u8| if set { bit } else { 0 };
+
+        let data1 = bit(self.valid, 0x80)
+            | bit(self.persistent, 0x40)
+            | bit(self.boot_type == BiosBootType::Efi, 0x20);
+
+        let data2 = bit(self.clear_cmos, 0x80)
+            | bit(self.lock_keyboard, 0x40)
+            | (u8::from(self.boot_device) << 2)
+            | bit(self.screen_blank, 0x02)
+            | bit(self.lock_out_reset_button, 0x01);
+
+        let data3 = bit(self.lock_out_power_button, 0x80)
+            | (u8::from(self.firmware_verbosity) << 5)
+            | bit(self.force_progress_event_traps, 0x10)
+            | bit(self.user_password_bypass, 0x08)
+            | bit(self.lock_out_sleep_button, 0x04)
+            | u8::from(self.console_redirection);
+
+        let data4 = bit(self.bios_shared_mode_override, 0x08) | u8::from(self.bios_mux_control);
+
+        let data5 = self.device_instance & 0x1F;
+
+        [data1, data2, data3, data4, data5]
+    }
+}
+
+/// Boot initiator info.
+///
+/// Reference: IPMI 2.0 Specification, Table 28-14, parameter #6.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct BootInitiatorInfo {
+    /// The channel through which the boot was initiated.
+    pub channel: u8,
+    pub session_id: u32,
+    pub timestamp: Timestamp,
+}
+
+impl BootInitiatorInfo {
+    fn from_slice(data: &[u8]) -> Result<Self, NotEnoughData> {
+        if data.len() < 9 {
+            return Err(NotEnoughData);
+        }
+
+        Ok(Self {
+            channel: data[0] & 0x0F,
+            session_id: u32::from_le_bytes([data[1], data[2], data[3], data[4]]),
+            timestamp: Timestamp::from(u32::from_le_bytes([data[5], data[6], data[7], data[8]])),
+        })
+    }
+
+    fn to_bytes(self) -> [u8; 9] {
+        let mut bytes = [0u8; 9];
+        bytes[0] = self.channel & 0x0F;
+        bytes[1..5].copy_from_slice(&self.session_id.to_le_bytes());
+        bytes[5..9].copy_from_slice(&u32::from(self.timestamp).to_le_bytes());
+        bytes
+    }
+}
+
+/// A block of the boot initiator mailbox.
+///
+/// Reference: IPMI 2.0 Specification, Table 28-14, parameter #7.
+#[derive(Clone, Debug, PartialEq)]
+pub struct BootInitiatorMailbox {
+    /// The block selector.
+    pub set_selector: u8,
+    /// The block data (at most 16 bytes).
+    pub data: Vec<u8>,
+}
+
+impl BootInitiatorMailbox {
+    fn from_slice(data: &[u8]) -> Result<Self, NotEnoughData> {
+        if data.is_empty() {
+            return Err(NotEnoughData);
+        }
+
+        Ok(Self {
+            set_selector: data[0],
+            data: data[1..].iter().copied().take(16).collect(),
+        })
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(1 + self.data.len().min(16));
+        bytes.push(self.set_selector);
+        bytes.extend(self.data.iter().take(16));
+        bytes
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_persistent_efi_pxe() {
+        let data = BootOptionParameter::BootFlags
+            .parse(&[0xE0, 0x04, 0x00, 0x00, 0x00])
+            .unwrap();
+
+        let BootOptionParameterData::BootFlags(flags) = data else {
+            panic!("Expected boot flags, got {data:?}");
+        };
+
+        assert!(flags.valid);
+        assert!(flags.persistent);
+        assert_eq!(BiosBootType::Efi, flags.boot_type);
+        assert_eq!(BootDevice::Pxe, flags.boot_device);
+    }
+
+    #[test]
+    fn boot_flags_round_trip() {
+        let flags = BootFlags::new(BootDevice::BiosSetup)
+            .persistent(false)
+            .with_boot_type(BiosBootType::Legacy);
+
+        assert_eq!([0x80, 0x18, 0x00, 0x00, 0x00], flags.to_bytes());
+        assert_eq!(flags, BootFlags::from_slice(&flags.to_bytes()).unwrap());
+    }
+
+    #[test]
+    fn boot_info_acknowledge_inverts_handled() {
+        let ack = BootInfoAcknowledge::from_slice(&[0x00, 0x1E]).unwrap();
+        assert_eq!(BootInitiators::BIOS_POST, ack.handled_by);
+        assert_eq!([0x00, 0x1E], ack.to_bytes());
+    }
+}
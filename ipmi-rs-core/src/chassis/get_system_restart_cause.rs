@@ -0,0 +1,137 @@
+use crate::connection::{Channel, IpmiCommand, Message, NetFn, NotEnoughData};
+
+/// The Get System Restart Cause command.
+///
+/// Reference: IPMI 2.0 Specification, Section 28.11, Table 28-11
+pub struct GetSystemRestartCause;
+
+impl From<GetSystemRestartCause> for Message {
+    fn from(_: GetSystemRestartCause) -> Self {
+        Message::new_request(NetFn::Chassis, 0x07, Vec::new())
+    }
+}
+
+impl IpmiCommand for GetSystemRestartCause {
+    type Output = SystemRestartCause;
+    type Error = NotEnoughData;
+
+    fn parse_success_response(data: &[u8]) -> Result<Self::Output, Self::Error> {
+        SystemRestartCause::parse(data).ok_or(NotEnoughData)
+    }
+}
+
+/// The cause of the most recent system restart.
+///
+/// Reference: IPMI 2.0 Specification, Table 28-11, byte 1
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum RestartCause {
+    Unknown,
+    /// A Chassis Control command.
+    ChassisControl,
+    /// A reset via the reset button.
+    ResetButton,
+    /// A power up via the power button.
+    PowerButton,
+    /// Expiration of the watchdog timer.
+    WatchdogExpiration,
+    /// An OEM cause.
+    Oem,
+    /// Automatic power up on AC being applied, due to the "always restore"
+    /// power restore policy.
+    AutomaticPowerUpAlwaysOn,
+    /// Automatic power up on AC being applied, due to the "restore previous
+    /// power state" power restore policy.
+    AutomaticPowerUpPreviousState,
+    /// A reset via PEF.
+    PefReset,
+    /// A power cycle via PEF.
+    PefPowerCycle,
+    /// A soft reset (e.g. CTRL-ALT-DEL).
+    SoftReset,
+    /// A power up via RTC (system real time clock) wakeup.
+    RtcWakeup,
+    Reserved(u8),
+}
+
+impl From<u8> for RestartCause {
+    fn from(value: u8) -> Self {
+        match value & 0x0F {
+            0x0 => Self::Unknown,
+            0x1 => Self::ChassisControl,
+            0x2 => Self::ResetButton,
+            0x3 => Self::PowerButton,
+            0x4 => Self::WatchdogExpiration,
+            0x5 => Self::Oem,
+            0x6 => Self::AutomaticPowerUpAlwaysOn,
+            0x7 => Self::AutomaticPowerUpPreviousState,
+            0x8 => Self::PefReset,
+            0x9 => Self::PefPowerCycle,
+            0xA => Self::SoftReset,
+            0xB => Self::RtcWakeup,
+            v => Self::Reserved(v),
+        }
+    }
+}
+
+impl core::fmt::Display for RestartCause {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            RestartCause::Unknown => write!(f, "Unknown"),
+            RestartCause::ChassisControl => write!(f, "Chassis control command"),
+            RestartCause::ResetButton => write!(f, "Reset button"),
+            RestartCause::PowerButton => write!(f, "Power button"),
+            RestartCause::WatchdogExpiration => write!(f, "Watchdog expiration"),
+            RestartCause::Oem => write!(f, "OEM"),
+            RestartCause::AutomaticPowerUpAlwaysOn => {
+                write!(f, "Automatic power up (always restore)")
+            }
+            RestartCause::AutomaticPowerUpPreviousState => {
+                write!(f, "Automatic power up (restore previous state)")
+            }
+            RestartCause::PefReset => write!(f, "PEF reset"),
+            RestartCause::PefPowerCycle => write!(f, "PEF power cycle"),
+            RestartCause::SoftReset => write!(f, "Soft reset"),
+            RestartCause::RtcWakeup => write!(f, "RTC wakeup"),
+            RestartCause::Reserved(v) => write!(f, "Reserved (0x{v:01X})"),
+        }
+    }
+}
+
+/// The system restart cause.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SystemRestartCause {
+    pub cause: RestartCause,
+    /// The channel through which the command that caused the
+    /// restart was received, if it is valid.
+    pub channel: Option<Channel>,
+}
+
+impl SystemRestartCause {
+    /// Parse `SystemRestartCause` from IPMI response data.
+    ///
+    /// Reference: IPMI 2.0 Specification, Table 28-11
+    pub fn parse(data: &[u8]) -> Option<Self> {
+        if data.len() < 2 {
+            return None;
+        }
+
+        Some(Self {
+            cause: RestartCause::from(data[0]),
+            channel: Channel::new(data[1] & 0x0F),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_restart_cause() {
+        let cause = SystemRestartCause::parse(&[0x07, 0x01]).unwrap();
+
+        assert_eq!(RestartCause::AutomaticPowerUpPreviousState, cause.cause);
+        assert_eq!(Channel::new(1), cause.channel);
+        assert_eq!(None, SystemRestartCause::parse(&[0x07]));
+    }
+}
@@ -0,0 +1,39 @@
+//! Definitions for IPMI chassis commands.
+
+mod get_chassis_capabilities;
+pub use get_chassis_capabilities::{ChassisCapabilities, GetChassisCapabilities};
+
+mod get_chassis_status;
+pub use get_chassis_status::{
+    ChassisIdentifyState, ChassisStatus, CurrentPowerState, FrontPanelButtonCapabilities,
+    GetChassisStatus, LastPowerEvent, MiscChassisState, PowerRestorePolicy, PowerState,
+};
+
+mod chassis_control;
+pub use chassis_control::{ChassisControl, ChassisControlAction};
+
+mod get_system_boot_options;
+pub use get_system_boot_options::{
+    BiosBootType, BiosMuxControl, BootDevice, BootFlagValidBitClearing, BootFlags,
+    BootInfoAcknowledge, BootInitiatorInfo, BootInitiatorMailbox, BootInitiators,
+    BootOptionParameter, BootOptionParameterData, BootOptionParameterResponse, ConsoleRedirection,
+    FirmwareVerbosity, GetSystemBootOptions, ServicePartitionScan, SetInProgress,
+};
+
+mod set_system_boot_options;
+pub use set_system_boot_options::SetSystemBootOptions;
+
+mod chassis_identify;
+pub use chassis_identify::ChassisIdentify;
+
+mod set_front_panel_enables;
+pub use set_front_panel_enables::SetFrontPanelEnables;
+
+mod set_power_restore_policy;
+pub use set_power_restore_policy::{SetPowerRestorePolicy, SupportedPowerRestorePolicies};
+
+mod get_system_restart_cause;
+pub use get_system_restart_cause::{GetSystemRestartCause, RestartCause, SystemRestartCause};
+
+mod get_poh_counter;
+pub use get_poh_counter::{GetPohCounter, PohCounter};
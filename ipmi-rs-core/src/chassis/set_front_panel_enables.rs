@@ -0,0 +1,49 @@
+use crate::connection::{IpmiCommand, Message, NetFn, NotEnoughData};
+
+/// The Set Front Panel Button Enables command.
+///
+/// Disabling a button is only possible if the chassis reports that
+/// disabling it is allowed (see [`FrontPanelButtonCapabilities`]).
+///
+/// Reference: IPMI 2.0 Specification, Section 28.6, Table 28-6
+///
+/// [`FrontPanelButtonCapabilities`]: super::FrontPanelButtonCapabilities
+#[derive(Clone, Copy, Debug, PartialEq, Default)]
+pub struct SetFrontPanelEnables {
+    /// Disable the standby (sleep) button.
+    pub disable_standby: bool,
+    /// Disable the diagnostic interrupt button.
+    pub disable_diagnostic_interrupt: bool,
+    /// Disable the reset button.
+    pub disable_reset: bool,
+    /// Disable the power off button.
+    pub disable_power_off: bool,
+}
+
+impl From<SetFrontPanelEnables> for Message {
+    fn from(value: SetFrontPanelEnables) -> Self {
+        let mut data = 0;
+
+        for (disable, bit) in [
+            (value.disable_standby, 0x08),
+            (value.disable_diagnostic_interrupt, 0x04),
+            (value.disable_reset, 0x02),
+            (value.disable_power_off, 0x01),
+        ] {
+            if disable {
+                data |= bit;
+            }
+        }
+
+        Message::new_request(NetFn::Chassis, 0x0A, vec![data])
+    }
+}
+
+impl IpmiCommand for SetFrontPanelEnables {
+    type Output = ();
+    type Error = NotEnoughData;
+
+    fn parse_success_response(_: &[u8]) -> Result<Self::Output, Self::Error> {
+        Ok(())
+    }
+}
@@ -0,0 +1,108 @@
+use crate::connection::{IpmiCommand, Message, NetFn, NotEnoughData};
+
+use super::PowerRestorePolicy;
+
+/// The Set Power Restore Policy command.
+///
+/// Reference: IPMI 2.0 Specification, Section 28.8, Table 28-8
+#[derive(Clone, Copy, Debug)]
+pub struct SetPowerRestorePolicy {
+    policy: Option<PowerRestorePolicy>,
+}
+
+impl SetPowerRestorePolicy {
+    /// Create a new Set Power Restore Policy command that configures `policy`.
+    ///
+    /// Returns `None` if `policy` is [`PowerRestorePolicy::Unknown`], which
+    /// can not be configured. Use [`SetPowerRestorePolicy::query`] to only
+    /// query the supported policies.
+    pub fn new(policy: PowerRestorePolicy) -> Option<Self> {
+        if policy == PowerRestorePolicy::Unknown {
+            return None;
+        }
+
+        Some(Self {
+            policy: Some(policy),
+        })
+    }
+
+    /// Create a new Set Power Restore Policy command that does not change
+    /// the current policy, and only queries the supported policies.
+    pub fn query() -> Self {
+        Self { policy: None }
+    }
+}
+
+impl From<SetPowerRestorePolicy> for Message {
+    fn from(value: SetPowerRestorePolicy) -> Self {
+        let policy = match value.policy {
+            Some(PowerRestorePolicy::AlwaysOff) => 0x00,
+            Some(PowerRestorePolicy::PreviousState) => 0x01,
+            Some(PowerRestorePolicy::AlwaysOn) => 0x02,
+            // `Unknown` is rejected by `SetPowerRestorePolicy::new`.
+            Some(PowerRestorePolicy::Unknown) | None => 0x03,
+        };
+
+        Message::new_request(NetFn::Chassis, 0x06, vec![policy])
+    }
+}
+
+impl IpmiCommand for SetPowerRestorePolicy {
+    type Output = SupportedPowerRestorePolicies;
+    type Error = NotEnoughData;
+
+    fn parse_success_response(data: &[u8]) -> Result<Self::Output, Self::Error> {
+        SupportedPowerRestorePolicies::parse(data).ok_or(NotEnoughData)
+    }
+}
+
+/// The power restore policies supported by a chassis.
+///
+/// Reference: IPMI 2.0 Specification, Table 28-8
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SupportedPowerRestorePolicies {
+    pub always_off: bool,
+    pub previous_state: bool,
+    pub always_on: bool,
+}
+
+impl SupportedPowerRestorePolicies {
+    /// Parse `SupportedPowerRestorePolicies` from IPMI response data.
+    pub fn parse(data: &[u8]) -> Option<Self> {
+        let value = *data.first()?;
+
+        Some(Self {
+            always_off: (value & 0x01) == 0x01,
+            previous_state: (value & 0x02) == 0x02,
+            always_on: (value & 0x04) == 0x04,
+        })
+    }
+
+    /// Whether `policy` is supported.
+    pub fn supports(&self, policy: PowerRestorePolicy) -> bool {
+        match policy {
+            PowerRestorePolicy::AlwaysOff => self.always_off,
+            PowerRestorePolicy::PreviousState => self.previous_state,
+            PowerRestorePolicy::AlwaysOn => self.always_on,
+            PowerRestorePolicy::Unknown => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reject_unknown_policy() {
+        assert!(SetPowerRestorePolicy::new(PowerRestorePolicy::Unknown).is_none());
+
+        let set: Message = SetPowerRestorePolicy::new(PowerRestorePolicy::AlwaysOn)
+            .unwrap()
+            .into();
+        assert_eq!(&[0x02], set.data());
+
+        let query: Message = SetPowerRestorePolicy::query().into();
+        assert_eq!(&[0x03], query.data());
+    }
+}
@@ -0,0 +1,60 @@
+use crate::connection::{IpmiCommand, Message, NetFn, NotEnoughData};
+
+use super::{BootOptionParameter, BootOptionParameterData};
+
+/// Set System Boot Options command.
+///
+/// Reference: IPMI 2.0 Specification, Section 28.12, Table 28-13.
+#[derive(Clone, Debug)]
+pub struct SetSystemBootOptions {
+    parameter: BootOptionParameter,
+    mark_invalid: bool,
+    data: Vec<u8>,
+}
+
+impl SetSystemBootOptions {
+    /// Create a new Set System Boot Options command.
+    pub fn new(parameter: BootOptionParameter, data: Vec<u8>) -> Self {
+        Self {
+            parameter,
+            mark_invalid: false,
+            data,
+        }
+    }
+
+    /// Create a Set System Boot Options command from typed parameter data.
+    pub fn from_data(parameter: BootOptionParameter, data: &BootOptionParameterData) -> Self {
+        Self::new(parameter, data.to_bytes())
+    }
+
+    /// Mark the parameter as invalid/locked when set to `true`.
+    pub fn mark_invalid(mut self, mark_invalid: bool) -> Self {
+        self.mark_invalid = mark_invalid;
+        self
+    }
+}
+
+impl From<SetSystemBootOptions> for Message {
+    fn from(value: SetSystemBootOptions) -> Self {
+        let parameter = value.parameter.value() & 0x7F;
+        let parameter = if value.mark_invalid {
+            parameter | 0x80
+        } else {
+            parameter
+        };
+
+        let mut payload = Vec::with_capacity(1 + value.data.len());
+        payload.push(parameter);
+        payload.extend_from_slice(&value.data);
+        Message::new_request(NetFn::Chassis, 0x08, payload)
+    }
+}
+
+impl IpmiCommand for SetSystemBootOptions {
+    type Output = ();
+    type Error = NotEnoughData;
+
+    fn parse_success_response(_: &[u8]) -> Result<Self::Output, Self::Error> {
+        Ok(())
+    }
+}
@@ -270,4 +270,13 @@ pub trait IpmiCommand: Into<Message> {
     fn target(&self) -> Option<(Address, Channel)> {
         None
     }
+
+    /// Get the intended target [`LogicalUnit`] for this command, on the
+    /// target returned by [`IpmiCommand::target`], or on the BMC if that
+    /// is `None`.
+    ///
+    /// The default implementation of this function returns [`LogicalUnit::Zero`].
+    fn target_lun(&self) -> LogicalUnit {
+        LogicalUnit::Zero
+    }
 }
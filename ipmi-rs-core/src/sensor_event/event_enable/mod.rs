@@ -0,0 +1,115 @@
+mod get;
+pub use get::GetSensorEventEnable;
+
+mod set;
+pub use set::{EventEnableChange, SetSensorEventEnable};
+
+use crate::storage::sdr::record::{DiscreteEventMask, ThresholdAssertEventMask};
+
+/// The event enables of a sensor.
+///
+/// The assertion and deassertion masks are raw, and should be interpreted
+/// as a [`ThresholdAssertEventMask`] for threshold-based sensors, or as a
+/// [`DiscreteEventMask`] for discrete sensors.
+///
+/// Reference: IPMI 2.0 Specification, Section 35.11, Table 35-11
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SensorEventEnable {
+    pub all_event_messages_enabled: bool,
+    pub scanning_enabled: bool,
+    pub assertions: u16,
+    pub deassertions: u16,
+}
+
+impl SensorEventEnable {
+    pub(crate) fn parse(data: &[u8]) -> Option<Self> {
+        let flags = *data.first()?;
+
+        // The event masks are optional, and absent if the sensor does
+        // not support per-event enables.
+        let mask = |idx: usize| {
+            let lsb = data.get(idx).copied().unwrap_or(0);
+            let msb = data.get(idx + 1).copied().unwrap_or(0);
+            u16::from_le_bytes([lsb, msb & 0x7F])
+        };
+
+        Some(Self {
+            all_event_messages_enabled: (flags & 0x80) == 0x80,
+            scanning_enabled: (flags & 0x40) == 0x40,
+            assertions: mask(1),
+            deassertions: mask(3),
+        })
+    }
+
+    /// The enabled assertion events of a threshold-based sensor.
+    pub fn threshold_assertions(&self) -> ThresholdAssertEventMask {
+        ThresholdAssertEventMask::from_bits_truncate(self.assertions)
+    }
+
+    /// The enabled deassertion events of a threshold-based sensor.
+    pub fn threshold_deassertions(&self) -> ThresholdAssertEventMask {
+        ThresholdAssertEventMask::from_bits_truncate(self.deassertions)
+    }
+
+    /// The enabled assertion events of a discrete sensor.
+    pub fn discrete_assertions(&self) -> DiscreteEventMask {
+        DiscreteEventMask::from_bits_truncate(self.assertions)
+    }
+
+    /// The enabled deassertion events of a discrete sensor.
+    pub fn discrete_deassertions(&self) -> DiscreteEventMask {
+        DiscreteEventMask::from_bits_truncate(self.deassertions)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        connection::{Address, Channel, Message},
+        storage::sdr::record::SensorNumber,
+    };
+    use nonmax::NonMaxU8;
+
+    #[test]
+    fn parse_event_enable() {
+        let enable = SensorEventEnable::parse(&[0xC0, 0x95, 0x0A, 0x00, 0x80]).unwrap();
+
+        assert!(enable.all_event_messages_enabled);
+        assert!(enable.scanning_enabled);
+        assert_eq!(
+            ThresholdAssertEventMask::LOWER_NON_CRITICAL_GOING_LOW
+                | ThresholdAssertEventMask::LOWER_CRITICAL_GOING_LOW
+                | ThresholdAssertEventMask::LOWER_NON_RECOVERABLE_GOING_LOW
+                | ThresholdAssertEventMask::UPPER_NON_CRITICAL_GOING_HIGH
+                | ThresholdAssertEventMask::UPPER_CRITICAL_GOING_HIGH
+                | ThresholdAssertEventMask::UPPER_NON_RECOVERABLE_GOING_HIGH,
+            enable.threshold_assertions()
+        );
+        assert!(enable.threshold_deassertions().is_empty());
+
+        let enable = SensorEventEnable::parse(&[0x40]).unwrap();
+        assert!(!enable.all_event_messages_enabled);
+        assert!(enable.discrete_assertions().is_empty());
+    }
+
+    #[test]
+    fn disable_discrete_events() {
+        let sensor_number = SensorNumber::new(NonMaxU8::new(0x42).unwrap());
+        let message: Message =
+            SetSensorEventEnable::new(sensor_number, Address(0x20), Channel::Primary, true, true)
+                .disable_discrete_events(
+                    DiscreteEventMask::STATE_1 | DiscreteEventMask::STATE_8,
+                    DiscreteEventMask::empty(),
+                )
+                .into();
+
+        assert_eq!(&[0x42, 0xE0, 0x02, 0x01, 0x00, 0x00], message.data());
+
+        let message: Message =
+            SetSensorEventEnable::new(sensor_number, Address(0x20), Channel::Primary, false, true)
+                .into();
+
+        assert_eq!(&[0x42, 0x40], message.data());
+    }
+}
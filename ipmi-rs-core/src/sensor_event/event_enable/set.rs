@@ -0,0 +1,169 @@
+use crate::{
+    connection::{Address, Channel, IpmiCommand, LogicalUnit, Message, NetFn, NotEnoughData},
+    storage::sdr::record::{DiscreteEventMask, SensorKey, SensorNumber, ThresholdAssertEventMask},
+};
+
+/// The change to apply to the selected per-event enables of a sensor.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum EventEnableChange {
+    /// Enable the selected events.
+    Enable,
+    /// Disable the selected events.
+    Disable,
+}
+
+/// The Set Sensor Event Enable command.
+///
+/// By default, only the event message and scanning enables of the sensor
+/// are changed. Individual events can be enabled or disabled with
+/// [`SetSensorEventEnable::enable_threshold_events`] and similar functions.
+///
+/// Reference: IPMI 2.0 Specification, Section 35.10, Table 35-10
+pub struct SetSensorEventEnable {
+    sensor_number: SensorNumber,
+    address: Address,
+    channel: Channel,
+    lun: LogicalUnit,
+    all_event_messages: bool,
+    scanning: bool,
+    events: Option<(EventEnableChange, u16, u16)>,
+}
+
+impl SetSensorEventEnable {
+    pub fn new(
+        sensor_number: SensorNumber,
+        address: Address,
+        channel: Channel,
+        all_event_messages: bool,
+        scanning: bool,
+    ) -> Self {
+        Self {
+            sensor_number,
+            address,
+            channel,
+            lun: LogicalUnit::Zero,
+            all_event_messages,
+            scanning,
+            events: None,
+        }
+    }
+
+    pub fn for_sensor_key(value: &SensorKey, all_event_messages: bool, scanning: bool) -> Self {
+        Self::new(
+            value.sensor_number,
+            Address(value.owner_id.into()),
+            value.owner_channel,
+            all_event_messages,
+            scanning,
+        )
+        .with_lun(value.owner_lun)
+    }
+
+    /// Send this command to the logical unit `lun` of its target.
+    pub fn with_lun(mut self, lun: LogicalUnit) -> Self {
+        self.lun = lun;
+        self
+    }
+
+    /// Enable the `assertions` and `deassertions` events of a threshold-based sensor.
+    pub fn enable_threshold_events(
+        self,
+        assertions: ThresholdAssertEventMask,
+        deassertions: ThresholdAssertEventMask,
+    ) -> Self {
+        self.with_events(
+            EventEnableChange::Enable,
+            assertions.bits(),
+            deassertions.bits(),
+        )
+    }
+
+    /// Disable the `assertions` and `deassertions` events of a threshold-based sensor.
+    pub fn disable_threshold_events(
+        self,
+        assertions: ThresholdAssertEventMask,
+        deassertions: ThresholdAssertEventMask,
+    ) -> Self {
+        self.with_events(
+            EventEnableChange::Disable,
+            assertions.bits(),
+            deassertions.bits(),
+        )
+    }
+
+    /// Enable the `assertions` and `deassertions` events of a discrete sensor.
+    pub fn enable_discrete_events(
+        self,
+        assertions: DiscreteEventMask,
+        deassertions: DiscreteEventMask,
+    ) -> Self {
+        self.with_events(
+            EventEnableChange::Enable,
+            assertions.bits(),
+            deassertions.bits(),
+        )
+    }
+
+    /// Disable the `assertions` and `deassertions` events of a discrete sensor.
+    pub fn disable_discrete_events(
+        self,
+        assertions: DiscreteEventMask,
+        deassertions: DiscreteEventMask,
+    ) -> Self {
+        self.with_events(
+            EventEnableChange::Disable,
+            assertions.bits(),
+            deassertions.bits(),
+        )
+    }
+
+    fn with_events(
+        mut self,
+        change: EventEnableChange,
+        assertions: u16,
+        deassertions: u16,
+    ) -> Self {
+        self.events = Some((change, assertions, deassertions));
+        self
+    }
+}
+
+impl From<SetSensorEventEnable> for Message {
+    fn from(value: SetSensorEventEnable) -> Self {
+        let change = match value.events {
+            None => 0b00,
+            Some((EventEnableChange::Enable, ..)) => 0b01,
+            Some((EventEnableChange::Disable, ..)) => 0b10,
+        };
+
+        let flags =
+            ((value.all_event_messages as u8) << 7) | ((value.scanning as u8) << 6) | (change << 4);
+
+        let mut data = vec![value.sensor_number.get(), flags];
+
+        if let Some((_, assertions, deassertions)) = value.events {
+            data.extend(assertions.to_le_bytes());
+            data.extend(deassertions.to_le_bytes());
+        }
+
+        Message::new_request(NetFn::SensorEvent, 0x28, data)
+    }
+}
+
+impl IpmiCommand for SetSensorEventEnable {
+    type Output = ();
+
+    type Error = NotEnoughData;
+
+    fn parse_success_response(_: &[u8]) -> Result<Self::Output, Self::Error> {
+        Ok(())
+    }
+
+    fn target(&self) -> Option<(Address, Channel)> {
+        Some((self.address, self.channel))
+    }
+
+    fn target_lun(&self) -> LogicalUnit {
+        self.lun
+    }
+}
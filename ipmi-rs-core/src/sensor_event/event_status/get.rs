@@ -0,0 +1,66 @@
+use crate::{
+    connection::{Address, Channel, IpmiCommand, LogicalUnit, Message, NetFn, NotEnoughData},
+    storage::sdr::record::{SensorKey, SensorNumber},
+};
+
+use super::SensorEventStatus;
+
+/// The Get Sensor Event Status command.
+///
+/// Reference: IPMI 2.0 Specification, Section 35.13, Table 35-13
+pub struct GetSensorEventStatus {
+    sensor_number: SensorNumber,
+    address: Address,
+    channel: Channel,
+    lun: LogicalUnit,
+}
+
+impl GetSensorEventStatus {
+    pub fn new(sensor_number: SensorNumber, address: Address, channel: Channel) -> Self {
+        Self {
+            sensor_number,
+            address,
+            channel,
+            lun: LogicalUnit::Zero,
+        }
+    }
+
+    pub fn for_sensor_key(value: &SensorKey) -> Self {
+        Self {
+            sensor_number: value.sensor_number,
+            address: Address(value.owner_id.into()),
+            channel: value.owner_channel,
+            lun: value.owner_lun,
+        }
+    }
+
+    /// Send this command to the logical unit `lun` of its target.
+    pub fn with_lun(mut self, lun: LogicalUnit) -> Self {
+        self.lun = lun;
+        self
+    }
+}
+
+impl From<GetSensorEventStatus> for Message {
+    fn from(value: GetSensorEventStatus) -> Self {
+        Message::new_request(NetFn::SensorEvent, 0x2B, vec![value.sensor_number.get()])
+    }
+}
+
+impl IpmiCommand for GetSensorEventStatus {
+    type Output = SensorEventStatus;
+
+    type Error = NotEnoughData;
+
+    fn parse_success_response(data: &[u8]) -> Result<Self::Output, Self::Error> {
+        SensorEventStatus::parse(data).ok_or(NotEnoughData)
+    }
+
+    fn target(&self) -> Option<(Address, Channel)> {
+        Some((self.address, self.channel))
+    }
+
+    fn target_lun(&self) -> LogicalUnit {
+        self.lun
+    }
+}
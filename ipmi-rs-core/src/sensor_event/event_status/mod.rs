@@ -0,0 +1,117 @@
+mod get;
+pub use get::GetSensorEventStatus;
+
+mod rearm;
+pub use rearm::RearmSensorEvents;
+
+use crate::storage::sdr::{
+    event_reading_type_code::EventReadingTypeCodes,
+    record::{DiscreteEventMask, ThresholdAssertEventMask},
+};
+
+/// The event status of a sensor.
+///
+/// The assertion and deassertion masks are raw, and can be decoded
+/// using the event/reading type code of the sensor with
+/// [`SensorEventStatus::events`].
+///
+/// Reference: IPMI 2.0 Specification, Section 35.13, Table 35-13
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SensorEventStatus {
+    pub all_event_messages_disabled: bool,
+    pub scanning_disabled: bool,
+    pub reading_or_state_unavailable: bool,
+    pub assertions: u16,
+    pub deassertions: u16,
+}
+
+/// The asserted and deasserted events of a sensor.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum EventStatus {
+    Threshold {
+        asserted: ThresholdAssertEventMask,
+        deasserted: ThresholdAssertEventMask,
+    },
+    Discrete {
+        asserted: DiscreteEventMask,
+        deasserted: DiscreteEventMask,
+    },
+}
+
+impl SensorEventStatus {
+    pub(crate) fn parse(data: &[u8]) -> Option<Self> {
+        let flags = *data.first()?;
+
+        // The event status bytes are optional, and absent if the sensor
+        // does not track the status of individual events.
+        let mask = |idx: usize| {
+            let lsb = data.get(idx).copied().unwrap_or(0);
+            let msb = data.get(idx + 1).copied().unwrap_or(0);
+            u16::from_le_bytes([lsb, msb & 0x7F])
+        };
+
+        Some(Self {
+            // Bit indicates that all event messages are enabled => must negate result
+            all_event_messages_disabled: (flags & 0x80) != 0x80,
+            // Bit indicates that sensor scanning is enabled => must negate result
+            scanning_disabled: (flags & 0x40) != 0x40,
+            reading_or_state_unavailable: (flags & 0x20) == 0x20,
+            assertions: mask(1),
+            deassertions: mask(3),
+        })
+    }
+
+    /// Decode the asserted and deasserted events of a sensor with
+    /// event/reading type code `codes`.
+    ///
+    /// Returns `None` if the event/reading type code is unspecified
+    /// or reserved.
+    pub fn events(&self, codes: &EventReadingTypeCodes) -> Option<EventStatus> {
+        match codes {
+            EventReadingTypeCodes::Threshold => Some(EventStatus::Threshold {
+                asserted: ThresholdAssertEventMask::from_bits_truncate(self.assertions),
+                deasserted: ThresholdAssertEventMask::from_bits_truncate(self.deassertions),
+            }),
+            EventReadingTypeCodes::DiscreteGeneric(_)
+            | EventReadingTypeCodes::SensorSpecific
+            | EventReadingTypeCodes::Oem(_) => Some(EventStatus::Discrete {
+                asserted: DiscreteEventMask::from_bits_truncate(self.assertions),
+                deasserted: DiscreteEventMask::from_bits_truncate(self.deassertions),
+            }),
+            EventReadingTypeCodes::Unspecified | EventReadingTypeCodes::Reserved(_) => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decode_event_status() {
+        let status = SensorEventStatus::parse(&[0xC0, 0x04, 0x00, 0x01, 0x00]).unwrap();
+
+        assert!(!status.all_event_messages_disabled);
+        assert!(!status.scanning_disabled);
+        assert_eq!(
+            Some(EventStatus::Threshold {
+                asserted: ThresholdAssertEventMask::LOWER_CRITICAL_GOING_LOW,
+                deasserted: ThresholdAssertEventMask::LOWER_NON_CRITICAL_GOING_LOW,
+            }),
+            status.events(&EventReadingTypeCodes::Threshold)
+        );
+        assert_eq!(
+            Some(EventStatus::Discrete {
+                asserted: DiscreteEventMask::STATE_2,
+                deasserted: DiscreteEventMask::STATE_0,
+            }),
+            status.events(&EventReadingTypeCodes::SensorSpecific)
+        );
+        assert_eq!(None, status.events(&EventReadingTypeCodes::Unspecified));
+
+        let status = SensorEventStatus::parse(&[0x20]).unwrap();
+        assert!(status.all_event_messages_disabled);
+        assert!(status.reading_or_state_unavailable);
+        assert_eq!(0, status.assertions);
+    }
+}
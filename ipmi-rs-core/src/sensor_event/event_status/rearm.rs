@@ -0,0 +1,100 @@
+use crate::{
+    connection::{Address, Channel, IpmiCommand, LogicalUnit, Message, NetFn, NotEnoughData},
+    storage::sdr::record::{DiscreteEventMask, SensorKey, SensorNumber, ThresholdAssertEventMask},
+};
+
+/// The Re-arm Sensor Events command.
+///
+/// By default, all events of the sensor are re-armed. Individual events
+/// can be selected with [`RearmSensorEvents::threshold_events`] or
+/// [`RearmSensorEvents::discrete_events`].
+///
+/// Reference: IPMI 2.0 Specification, Section 35.12, Table 35-12
+pub struct RearmSensorEvents {
+    sensor_number: SensorNumber,
+    address: Address,
+    channel: Channel,
+    lun: LogicalUnit,
+    events: Option<(u16, u16)>,
+}
+
+impl RearmSensorEvents {
+    pub fn new(sensor_number: SensorNumber, address: Address, channel: Channel) -> Self {
+        Self {
+            sensor_number,
+            address,
+            channel,
+            lun: LogicalUnit::Zero,
+            events: None,
+        }
+    }
+
+    pub fn for_sensor_key(value: &SensorKey) -> Self {
+        Self::new(
+            value.sensor_number,
+            Address(value.owner_id.into()),
+            value.owner_channel,
+        )
+        .with_lun(value.owner_lun)
+    }
+
+    /// Send this command to the logical unit `lun` of its target.
+    pub fn with_lun(mut self, lun: LogicalUnit) -> Self {
+        self.lun = lun;
+        self
+    }
+
+    /// Only re-arm the `assertions` and `deassertions` events of a threshold-based sensor.
+    pub fn threshold_events(
+        mut self,
+        assertions: ThresholdAssertEventMask,
+        deassertions: ThresholdAssertEventMask,
+    ) -> Self {
+        self.events = Some((assertions.bits(), deassertions.bits()));
+        self
+    }
+
+    /// Only re-arm the `assertions` and `deassertions` events of a discrete sensor.
+    pub fn discrete_events(
+        mut self,
+        assertions: DiscreteEventMask,
+        deassertions: DiscreteEventMask,
+    ) -> Self {
+        self.events = Some((assertions.bits(), deassertions.bits()));
+        self
+    }
+}
+
+impl From<RearmSensorEvents> for Message {
+    fn from(value: RearmSensorEvents) -> Self {
+        let mut data = vec![value.sensor_number.get()];
+
+        if let Some((assertions, deassertions)) = value.events {
+            data.push(0x80);
+            data.extend(assertions.to_le_bytes());
+            data.extend(deassertions.to_le_bytes());
+        } else {
+            data.push(0x00);
+        }
+
+        Message::new_request(NetFn::SensorEvent, 0x2A, data)
+    }
+}
+
+impl IpmiCommand for RearmSensorEvents {
+    type Output = ();
+
+    type Error = NotEnoughData;
+
+    fn parse_success_response(_: &[u8]) -> Result<Self::Output, Self::Error> {
+        Ok(())
+    }
+
+    fn target(&self) -> Option<(Address, Channel)> {
+        Some((self.address, self.channel))
+    }
+
+    fn target_lun(&self) -> LogicalUnit {
+        self.lun
+    }
+}
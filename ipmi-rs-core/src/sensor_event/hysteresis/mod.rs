@@ -0,0 +1,41 @@
+mod get;
+pub use get::GetSensorHysteresis;
+
+mod set;
+pub use set::SetSensorHysteresis;
+
+/// Raw hysteresis values of a threshold-based sensor.
+///
+/// The values are an amount of raw counts, and can be converted using
+/// the SDR of the sensor. A value of 0 indicates that no hysteresis is
+/// used.
+///
+/// Reference: IPMI 2.0 Specification, Section 35.7, Table 35-7
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SensorHysteresis {
+    pub positive_going: u8,
+    pub negative_going: u8,
+}
+
+impl SensorHysteresis {
+    pub(crate) fn parse(data: &[u8]) -> Option<Self> {
+        if data.len() < 2 {
+            return None;
+        }
+
+        Some(Self {
+            positive_going: data[0],
+            negative_going: data[1],
+        })
+    }
+}
+
+/// An error that occurs while creating a Set Sensor Hysteresis command.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum HysteresisError {
+    /// The hysteresis of the sensor is not settable.
+    NotSettable,
+    /// The value can not be represented as a raw hysteresis value of the
+    /// sensor.
+    OutOfRange,
+}
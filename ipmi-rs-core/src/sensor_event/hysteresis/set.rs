@@ -0,0 +1,124 @@
+use crate::{
+    connection::{Address, Channel, IpmiCommand, LogicalUnit, Message, NetFn, NotEnoughData},
+    storage::sdr::record::{
+        FullSensorRecord, HysteresisCapability, SensorKey, SensorNumber, WithSensorRecordCommon,
+    },
+};
+
+use super::{HysteresisError, SensorHysteresis};
+
+/// The Set Sensor Hysteresis command.
+///
+/// Reference: IPMI 2.0 Specification, Section 35.6, Table 35-6
+pub struct SetSensorHysteresis {
+    sensor_number: SensorNumber,
+    address: Address,
+    channel: Channel,
+    lun: LogicalUnit,
+    hysteresis: SensorHysteresis,
+}
+
+impl SetSensorHysteresis {
+    pub fn new(
+        sensor_number: SensorNumber,
+        address: Address,
+        channel: Channel,
+        hysteresis: SensorHysteresis,
+    ) -> Self {
+        Self {
+            sensor_number,
+            address,
+            channel,
+            lun: LogicalUnit::Zero,
+            hysteresis,
+        }
+    }
+
+    pub fn for_sensor_key(value: &SensorKey, hysteresis: SensorHysteresis) -> Self {
+        Self {
+            sensor_number: value.sensor_number,
+            address: Address(value.owner_id.into()),
+            channel: value.owner_channel,
+            lun: value.owner_lun,
+            hysteresis,
+        }
+    }
+
+    /// Send this command to the logical unit `lun` of its target.
+    pub fn with_lun(mut self, lun: LogicalUnit) -> Self {
+        self.lun = lun;
+        self
+    }
+
+    /// Create a new Set Sensor Hysteresis command that sets the raw
+    /// hysteresis values of `sensor`.
+    ///
+    /// An error is returned if the hysteresis of `sensor` is not settable.
+    pub fn for_sensor<S>(sensor: &S, hysteresis: SensorHysteresis) -> Result<Self, HysteresisError>
+    where
+        S: WithSensorRecordCommon,
+    {
+        if sensor.capabilities().hysteresis != HysteresisCapability::ReadableAndSettable {
+            return Err(HysteresisError::NotSettable);
+        }
+
+        Ok(Self::for_sensor_key(&sensor.common().key, hysteresis))
+    }
+
+    /// Create a new Set Sensor Hysteresis command that sets the hysteresis
+    /// of `sensor` to `positive_going` and `negative_going`, provided in
+    /// the units of the sensor.
+    ///
+    /// An error is returned if the hysteresis of `sensor` is not settable,
+    /// or if one of the values can not be represented by the sensor.
+    pub fn for_full_sensor(
+        sensor: &FullSensorRecord,
+        positive_going: f32,
+        negative_going: f32,
+    ) -> Result<Self, HysteresisError> {
+        let hysteresis = SensorHysteresis {
+            positive_going: sensor
+                .raw_hysteresis(positive_going)
+                .ok_or(HysteresisError::OutOfRange)?,
+            negative_going: sensor
+                .raw_hysteresis(negative_going)
+                .ok_or(HysteresisError::OutOfRange)?,
+        };
+
+        Self::for_sensor(sensor, hysteresis)
+    }
+}
+
+impl From<SetSensorHysteresis> for Message {
+    fn from(value: SetSensorHysteresis) -> Self {
+        // The second byte is the reserved hysteresis mask, which must be 0xFF.
+        Message::new_request(
+            NetFn::SensorEvent,
+            0x24,
+            vec![
+                value.sensor_number.get(),
+                0xFF,
+                value.hysteresis.positive_going,
+                value.hysteresis.negative_going,
+            ],
+        )
+    }
+}
+
+impl IpmiCommand for SetSensorHysteresis {
+    type Output = ();
+
+    type Error = NotEnoughData;
+
+    fn parse_success_response(_: &[u8]) -> Result<Self::Output, Self::Error> {
+        Ok(())
+    }
+
+    fn target(&self) -> Option<(Address, Channel)> {
+        Some((self.address, self.channel))
+    }
+
+    fn target_lun(&self) -> LogicalUnit {
+        self.lun
+    }
+}
@@ -1,2 +1,17 @@
 mod sensor_reading;
 pub use sensor_reading::*;
+
+mod reading_factors;
+pub use reading_factors::*;
+
+mod thresholds;
+pub use thresholds::*;
+
+mod hysteresis;
+pub use hysteresis::*;
+
+mod event_enable;
+pub use event_enable::*;
+
+mod event_status;
+pub use event_status::*;
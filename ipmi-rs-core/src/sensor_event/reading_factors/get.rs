@@ -0,0 +1,80 @@
+use crate::{
+    connection::{Address, Channel, IpmiCommand, LogicalUnit, Message, NetFn, NotEnoughData},
+    storage::sdr::record::{SensorKey, SensorNumber},
+};
+
+use super::SensorReadingFactors;
+
+/// The Get Sensor Reading Factors command.
+///
+/// Reference: IPMI 2.0 Specification, Section 35.5, Table 35-5
+pub struct GetSensorReadingFactors {
+    sensor_number: SensorNumber,
+    reading: u8,
+    address: Address,
+    channel: Channel,
+    lun: LogicalUnit,
+}
+
+impl GetSensorReadingFactors {
+    pub fn new(
+        sensor_number: SensorNumber,
+        reading: u8,
+        address: Address,
+        channel: Channel,
+    ) -> Self {
+        Self {
+            sensor_number,
+            reading,
+            address,
+            channel,
+            lun: LogicalUnit::Zero,
+        }
+    }
+
+    /// Get the conversion factors of the sensor with key `value` for the
+    /// raw reading `reading`.
+    pub fn for_sensor_key(value: &SensorKey, reading: u8) -> Self {
+        Self {
+            sensor_number: value.sensor_number,
+            reading,
+            address: Address(value.owner_id.into()),
+            channel: value.owner_channel,
+            lun: value.owner_lun,
+        }
+    }
+
+    /// Send this command to the logical unit `lun` of its target.
+    pub fn with_lun(mut self, lun: LogicalUnit) -> Self {
+        self.lun = lun;
+        self
+    }
+}
+
+impl From<GetSensorReadingFactors> for Message {
+    fn from(value: GetSensorReadingFactors) -> Self {
+        Message::new_request(
+            NetFn::SensorEvent,
+            0x23,
+            vec![value.sensor_number.get(), value.reading],
+        )
+    }
+}
+
+impl IpmiCommand for GetSensorReadingFactors {
+    type Output = SensorReadingFactors;
+
+    type Error = NotEnoughData;
+
+    fn parse_success_response(data: &[u8]) -> Result<Self::Output, Self::Error> {
+        SensorReadingFactors::parse(data).ok_or(NotEnoughData)
+    }
+
+    fn target(&self) -> Option<(Address, Channel)> {
+        Some((self.address, self.channel))
+    }
+
+    fn target_lun(&self) -> LogicalUnit {
+        self.lun
+    }
+}
@@ -0,0 +1,59 @@
+mod get;
+pub use get::GetSensorReadingFactors;
+
+use crate::storage::sdr::record::ConversionFactors;
+
+/// The conversion factors of a non-linear sensor for a specific reading.
+///
+/// Reference: IPMI 2.0 Specification, Section 35.5, Table 35-5
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SensorReadingFactors {
+    /// The next raw reading for which the conversion factors differ from
+    /// these factors.
+    pub next_reading: u8,
+    pub factors: ConversionFactors,
+}
+
+impl SensorReadingFactors {
+    pub(crate) fn parse(data: &[u8]) -> Option<Self> {
+        let next_reading = *data.first()?;
+        let factors = ConversionFactors::parse(data.get(1..)?)?;
+
+        Some(Self {
+            next_reading,
+            factors,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_reading_factors() {
+        // M = -2, tolerance = 3, B = -5, accuracy = 0x123, accuracy exponent = 2,
+        // result exponent = -1, B exponent = 1
+        let factors =
+            SensorReadingFactors::parse(&[0x10, 0xFE, 0xC3, 0xFB, 0xE3, 0x48, 0xF1]).unwrap();
+
+        assert_eq!(0x10, factors.next_reading);
+        assert_eq!(
+            ConversionFactors {
+                m: -2,
+                tolerance: 3,
+                b: -5,
+                accuracy: 0x123,
+                accuracy_exponent: 2,
+                result_exponent: -1,
+                b_exponent: 1,
+            },
+            factors.factors
+        );
+
+        // (-2 * 10 + -5 * 10^1) * 10^-1
+        assert_eq!(-7.0, factors.factors.apply(10.0));
+        assert_eq!(Some(10.0), factors.factors.invert(-7.0));
+        assert_eq!(0.3, factors.factors.tolerance());
+    }
+}
@@ -1,7 +1,12 @@
 mod get;
 pub use get::GetSensorReading;
 
-use crate::storage::sdr::event_reading_type_code::Threshold;
+use crate::storage::sdr::{
+    event_offset_description,
+    event_reading_type_code::{EventReadingTypeCodes, Threshold},
+    record::{DiscreteEventMask, SensorRecordCommon},
+    SensorType,
+};
 
 pub trait FromSensorReading {
     type Sensor;
@@ -16,7 +21,6 @@ pub struct RawSensorReading {
     scanning_disabled: bool,
     reading_or_state_unavailable: bool,
     offset_data_1: Option<u8>,
-    #[allow(unused)]
     offset_data_2: Option<u8>,
 }
 
@@ -76,9 +80,90 @@ impl FromSensorReading for ThresholdReading {
     }
 }
 
+/// An asserted state of a discrete sensor.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DiscreteState {
+    /// The event offset of the state.
+    pub offset: u8,
+    /// The description of the state, if it is known for the event/reading
+    /// type code and sensor type of the sensor.
+    pub description: Option<&'static str>,
+}
+
+/// A reading of a discrete, generic or sensor-specific sensor.
+///
+/// Reference: IPMI 2.0 Specification, Section 35.14, Table 35-15
+#[derive(Debug, Clone, PartialEq)]
+pub struct DiscreteReading {
+    pub all_event_messages_disabled: bool,
+    pub scanning_disabled: bool,
+    /// The raw state mask, or `None` if the state is unavailable.
+    pub states: Option<DiscreteEventMask>,
+    /// The asserted states.
+    pub asserted: Vec<DiscreteState>,
+}
+
+impl DiscreteReading {
+    /// Decode `reading` for a sensor with event/reading type code `codes`
+    /// and sensor type `sensor_type`.
+    pub fn new(
+        codes: EventReadingTypeCodes,
+        sensor_type: SensorType,
+        reading: &RawSensorReading,
+    ) -> Self {
+        let states = if reading.reading_or_state_unavailable {
+            None
+        } else {
+            reading.offset_data_1.map(|lsb| {
+                let msb = reading.offset_data_2.unwrap_or(0) & 0x7F;
+                DiscreteEventMask::from_bits_truncate(u16::from_le_bytes([lsb, msb]))
+            })
+        };
+
+        // Threshold comparison states have no description in the offset tables.
+        let event_type = match codes {
+            EventReadingTypeCodes::Threshold => None,
+            codes => Some(u8::from(codes)),
+        };
+
+        let asserted = states
+            .iter()
+            .flat_map(|states| states.offsets())
+            .map(|offset| DiscreteState {
+                offset,
+                description: event_type
+                    .and_then(|ty| event_offset_description(ty, sensor_type, offset)),
+            })
+            .collect();
+
+        Self {
+            all_event_messages_disabled: reading.all_event_messages_disabled,
+            scanning_disabled: reading.scanning_disabled,
+            states,
+            asserted,
+        }
+    }
+
+    /// Whether the state with event offset `offset` is asserted.
+    pub fn is_asserted(&self, offset: u8) -> bool {
+        self.asserted.iter().any(|state| state.offset == offset)
+    }
+}
+
+impl FromSensorReading for DiscreteReading {
+    type Sensor = SensorRecordCommon;
+
+    fn from(sensor: &Self::Sensor, reading: &RawSensorReading) -> Self {
+        Self::new(sensor.event_reading_type_code, sensor.ty, reading)
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use super::{RawSensorReading, ThresholdReading};
+    use super::{
+        DiscreteReading, DiscreteState, EventReadingTypeCodes, RawSensorReading, SensorType,
+        ThresholdReading,
+    };
 
     #[test]
     fn lower_critical_uses_bit_one() {
@@ -96,4 +181,51 @@ mod tests {
         assert!(!status.at_or_below_lower_critical);
         assert!(status.at_or_above_non_recoverable);
     }
+
+    #[test]
+    fn discrete_power_supply() {
+        // Presence detected and predictive failure.
+        let raw_reading = RawSensorReading::parse(&[0x00, 0xC0, 0x05, 0x80]).unwrap();
+        let reading = DiscreteReading::new(
+            EventReadingTypeCodes::SensorSpecific,
+            SensorType::PowerSupply,
+            &raw_reading,
+        );
+
+        assert_eq!(
+            vec![
+                DiscreteState {
+                    offset: 0,
+                    description: Some("Presence detected"),
+                },
+                DiscreteState {
+                    offset: 2,
+                    description: Some("Predictive Failure"),
+                },
+            ],
+            reading.asserted
+        );
+        assert!(!reading.is_asserted(1));
+
+        let raw_reading = RawSensorReading::parse(&[0x00, 0xC0, 0x02, 0x80]).unwrap();
+        let reading = DiscreteReading::new(
+            EventReadingTypeCodes::DiscreteGeneric(0x08),
+            SensorType::DriveSlotBay,
+            &raw_reading,
+        );
+
+        assert_eq!(
+            Some("Device Inserted / Device Present"),
+            reading.asserted[0].description
+        );
+
+        let raw_reading = RawSensorReading::parse(&[0x00, 0xE0, 0x02]).unwrap();
+        let reading = DiscreteReading::new(
+            EventReadingTypeCodes::SensorSpecific,
+            SensorType::PowerSupply,
+            &raw_reading,
+        );
+        assert_eq!(None, reading.states);
+        assert!(reading.asserted.is_empty());
+    }
 }
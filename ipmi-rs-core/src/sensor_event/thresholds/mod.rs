@@ -0,0 +1,126 @@
+mod get;
+pub use get::GetSensorThresholds;
+
+mod set;
+pub use set::SetSensorThresholds;
+
+use crate::storage::sdr::record::ThresholdKind;
+
+/// Raw threshold values of a sensor.
+///
+/// The values are encoded in the same way as the readings of the
+/// sensor, and can be converted using the SDR of the sensor.
+///
+/// Reference: IPMI 2.0 Specification, Section 35.9, Table 35-9
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct RawThresholds {
+    pub lower_non_critical: Option<u8>,
+    pub lower_critical: Option<u8>,
+    pub lower_non_recoverable: Option<u8>,
+    pub upper_non_critical: Option<u8>,
+    pub upper_critical: Option<u8>,
+    pub upper_non_recoverable: Option<u8>,
+}
+
+impl RawThresholds {
+    /// Get the raw value of the threshold of kind `kind`, if it is present.
+    pub fn get(&self, kind: ThresholdKind) -> Option<u8> {
+        *self.value(kind)
+    }
+
+    /// Set the raw value of the threshold of kind `kind`.
+    pub fn set(&mut self, kind: ThresholdKind, value: Option<u8>) {
+        *self.value_mut(kind) = value;
+    }
+
+    fn value(&self, kind: ThresholdKind) -> &Option<u8> {
+        match kind {
+            ThresholdKind::LowerNonCritical => &self.lower_non_critical,
+            ThresholdKind::LowerCritical => &self.lower_critical,
+            ThresholdKind::LowerNonRecoverable => &self.lower_non_recoverable,
+            ThresholdKind::UpperNonCritical => &self.upper_non_critical,
+            ThresholdKind::UpperCritical => &self.upper_critical,
+            ThresholdKind::UpperNonRecoverable => &self.upper_non_recoverable,
+        }
+    }
+
+    fn value_mut(&mut self, kind: ThresholdKind) -> &mut Option<u8> {
+        match kind {
+            ThresholdKind::LowerNonCritical => &mut self.lower_non_critical,
+            ThresholdKind::LowerCritical => &mut self.lower_critical,
+            ThresholdKind::LowerNonRecoverable => &mut self.lower_non_recoverable,
+            ThresholdKind::UpperNonCritical => &mut self.upper_non_critical,
+            ThresholdKind::UpperCritical => &mut self.upper_critical,
+            ThresholdKind::UpperNonRecoverable => &mut self.upper_non_recoverable,
+        }
+    }
+
+    /// Parse `RawThresholds` from a threshold mask followed by the
+    /// threshold values, ordered from lower non-critical to upper
+    /// non-recoverable.
+    pub(crate) fn parse(data: &[u8]) -> Option<Self> {
+        if data.len() < 7 {
+            return None;
+        }
+
+        let mask = data[0];
+        let mut thresholds = Self::default();
+
+        for (bit, kind) in ThresholdKind::variants().enumerate() {
+            if (mask >> bit) & 0x1 == 0x1 {
+                thresholds.set(kind, Some(data[1 + bit]));
+            }
+        }
+
+        Some(thresholds)
+    }
+
+    /// Encode these thresholds as a threshold mask followed by the
+    /// threshold values, ordered from lower non-critical to upper
+    /// non-recoverable.
+    pub(crate) fn to_bytes(self) -> [u8; 7] {
+        let mut data = [0u8; 7];
+
+        for (bit, kind) in ThresholdKind::variants().enumerate() {
+            if let Some(value) = self.get(kind) {
+                data[0] |= 1 << bit;
+                data[1 + bit] = value;
+            }
+        }
+
+        data
+    }
+}
+
+/// An error that occurs while converting threshold values to their
+/// raw representation.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ThresholdError {
+    /// The threshold is not settable for this sensor.
+    NotSettable(ThresholdKind),
+    /// The value can not be represented as a raw value of this sensor.
+    OutOfRange(ThresholdKind),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_and_encode() {
+        let data = [0x15, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06];
+        let thresholds = RawThresholds::parse(&data).unwrap();
+
+        assert_eq!(Some(0x01), thresholds.lower_non_critical);
+        assert_eq!(None, thresholds.lower_critical);
+        assert_eq!(Some(0x03), thresholds.lower_non_recoverable);
+        assert_eq!(None, thresholds.upper_non_critical);
+        assert_eq!(Some(0x05), thresholds.upper_critical);
+        assert_eq!(None, thresholds.upper_non_recoverable);
+
+        assert_eq!(
+            [0x15, 0x01, 0x00, 0x03, 0x00, 0x05, 0x00],
+            thresholds.to_bytes()
+        );
+    }
+}
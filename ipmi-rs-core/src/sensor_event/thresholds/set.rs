@@ -0,0 +1,112 @@
+use crate::{
+    connection::{Address, Channel, IpmiCommand, LogicalUnit, Message, NetFn, NotEnoughData},
+    storage::sdr::record::{
+        FullSensorRecord, InstancedSensor, SensorKey, SensorNumber, ThresholdKind,
+        WithSensorRecordCommon,
+    },
+};
+
+use super::{RawThresholds, ThresholdError};
+
+/// The Set Sensor Thresholds command.
+///
+/// Only the thresholds that are present in the provided [`RawThresholds`]
+/// are changed.
+///
+/// Reference: IPMI 2.0 Specification, Section 35.8, Table 35-8
+pub struct SetSensorThresholds {
+    sensor_number: SensorNumber,
+    address: Address,
+    channel: Channel,
+    lun: LogicalUnit,
+    thresholds: RawThresholds,
+}
+
+impl SetSensorThresholds {
+    pub fn new(
+        sensor_number: SensorNumber,
+        address: Address,
+        channel: Channel,
+        thresholds: RawThresholds,
+    ) -> Self {
+        Self {
+            sensor_number,
+            address,
+            channel,
+            lun: LogicalUnit::Zero,
+            thresholds,
+        }
+    }
+
+    pub fn for_sensor_key(value: &SensorKey, thresholds: RawThresholds) -> Self {
+        Self {
+            sensor_number: value.sensor_number,
+            address: Address(value.owner_id.into()),
+            channel: value.owner_channel,
+            lun: value.owner_lun,
+            thresholds,
+        }
+    }
+
+    /// Send this command to the logical unit `lun` of its target.
+    pub fn with_lun(mut self, lun: LogicalUnit) -> Self {
+        self.lun = lun;
+        self
+    }
+
+    /// Create a new Set Sensor Thresholds command that sets the thresholds
+    /// in `values` for `sensor`.
+    ///
+    /// The values are provided in the units of the sensor, and are converted
+    /// to raw values using the conversion factors of `sensor`. An error is
+    /// returned if one of the thresholds is not settable, or if its value
+    /// can not be represented by the sensor.
+    pub fn for_sensor(
+        sensor: &FullSensorRecord,
+        values: &[(ThresholdKind, f32)],
+    ) -> Result<Self, ThresholdError> {
+        let access = &sensor.capabilities().threshold_access;
+        let mut thresholds = RawThresholds::default();
+
+        for (kind, value) in values.iter().copied() {
+            if !access.settable(kind) {
+                return Err(ThresholdError::NotSettable(kind));
+            }
+
+            let raw = sensor
+                .raw_value(value)
+                .ok_or(ThresholdError::OutOfRange(kind))?;
+
+            thresholds.set(kind, Some(raw));
+        }
+
+        Ok(Self::for_sensor_key(sensor.key_data(), thresholds))
+    }
+}
+
+impl From<SetSensorThresholds> for Message {
+    fn from(value: SetSensorThresholds) -> Self {
+        let mut data = vec![value.sensor_number.get()];
+        data.extend(value.thresholds.to_bytes());
+
+        Message::new_request(NetFn::SensorEvent, 0x26, data)
+    }
+}
+
+impl IpmiCommand for SetSensorThresholds {
+    type Output = ();
+
+    type Error = NotEnoughData;
+
+    fn parse_success_response(_: &[u8]) -> Result<Self::Output, Self::Error> {
+        Ok(())
+    }
+
+    fn target(&self) -> Option<(Address, Channel)> {
+        Some((self.address, self.channel))
+    }
+
+    fn target_lun(&self) -> LogicalUnit {
+        self.lun
+    }
+}
@@ -0,0 +1,276 @@
+use crate::storage::Timestamp;
+
+use super::{
+    checksum, checksum_ok,
+    field::{FruField, END_OF_FIELDS},
+    FruArea, FruEncodeError, FruParseError,
+};
+
+/// The unix timestamp of 1996-01-01 00:00 UTC, the epoch of the
+/// board manufacturing date.
+pub(crate) const BOARD_DATE_EPOCH: u32 = 820_454_400;
+
+/// Validate the version, length and checksum of the info area at `offset`,
+/// and return the data of the area.
+fn area_data(data: &[u8], offset: u16, area: FruArea) -> Result<&[u8], FruParseError> {
+    let offset = offset as usize;
+    let header = data
+        .get(offset..offset + 2)
+        .ok_or(FruParseError::AreaOutOfBounds(area))?;
+
+    let version = header[0] & 0x0F;
+    if version != 1 {
+        return Err(FruParseError::UnsupportedVersion { area, version });
+    }
+
+    let len = header[1] as usize * 8;
+    let area_data = data
+        .get(offset..offset + len)
+        .ok_or(FruParseError::AreaOutOfBounds(area))?;
+
+    if !checksum_ok(area_data) {
+        return Err(FruParseError::InvalidChecksum(area));
+    }
+
+    Ok(area_data)
+}
+
+/// Encode an info area consisting of `prefix` (the area header, without
+/// the format version and length) followed by `fields`.
+///
+/// The area length, end-of-fields marker, padding and checksum are
+/// computed automatically.
+fn encode_area<'a>(
+    area: FruArea,
+    prefix: &[u8],
+    fields: impl IntoIterator<Item = &'a FruField>,
+) -> Result<Vec<u8>, FruEncodeError> {
+    let mut data = vec![0x01, 0x00];
+    data.extend_from_slice(prefix);
+
+    for field in fields {
+        data.extend(field.to_bytes(area)?);
+    }
+
+    data.push(END_OF_FIELDS);
+
+    // Pad so that the area, including the checksum, is a multiple of 8 bytes.
+    data.resize((data.len() + 1).next_multiple_of(8) - 1, 0);
+
+    let len = (data.len() + 1) / 8;
+    data[1] = u8::try_from(len).map_err(|_| FruEncodeError::AreaTooLarge(area))?;
+    data.push(checksum(&data));
+
+    Ok(data)
+}
+
+/// Reads consecutive type/length encoded fields from an area.
+struct FieldReader<'a> {
+    data: &'a [u8],
+    area: FruArea,
+}
+
+impl<'a> FieldReader<'a> {
+    fn new(data: &'a [u8], area: FruArea) -> Self {
+        Self { data, area }
+    }
+
+    /// Read a mandatory field.
+    fn field(&mut self) -> Result<FruField, FruParseError> {
+        self.next()?.ok_or(FruParseError::MissingField(self.area))
+    }
+
+    /// Read the next field, returning `None` if the end-of-fields
+    /// marker has been reached.
+    fn next(&mut self) -> Result<Option<FruField>, FruParseError> {
+        match FruField::parse(self.data, self.area)? {
+            Some((field, len)) => {
+                self.data = &self.data[len..];
+                Ok(Some(field))
+            }
+            None => Ok(None),
+        }
+    }
+
+    /// Read all remaining (custom) fields.
+    fn custom_fields(mut self) -> Result<Vec<FruField>, FruParseError> {
+        let mut fields = Vec::new();
+        while let Some(field) = self.next()? {
+            fields.push(field);
+        }
+        Ok(fields)
+    }
+}
+
+/// The chassis info area.
+///
+/// Reference: FRU Information Storage Definition, Section 10
+#[derive(Clone, Debug, PartialEq)]
+pub struct ChassisInfo {
+    /// The chassis type, as defined by the SMBIOS specification.
+    pub chassis_type: u8,
+    pub part_number: FruField,
+    pub serial_number: FruField,
+    pub custom_fields: Vec<FruField>,
+}
+
+impl ChassisInfo {
+    /// Parse the chassis info area located at `offset` in `data`.
+    pub fn parse(data: &[u8], offset: u16) -> Result<Self, FruParseError> {
+        let area = FruArea::Chassis;
+        let data = area_data(data, offset, area)?;
+
+        let chassis_type = *data.get(2).ok_or(FruParseError::MissingField(area))?;
+        let mut fields = FieldReader::new(&data[3..], area);
+
+        Ok(Self {
+            chassis_type,
+            part_number: fields.field()?,
+            serial_number: fields.field()?,
+            custom_fields: fields.custom_fields()?,
+        })
+    }
+
+    /// Encode this chassis info area.
+    pub fn to_bytes(&self) -> Result<Vec<u8>, FruEncodeError> {
+        let fields = [&self.part_number, &self.serial_number];
+
+        encode_area(
+            FruArea::Chassis,
+            &[self.chassis_type],
+            fields.into_iter().chain(&self.custom_fields),
+        )
+    }
+}
+
+/// The board info area.
+///
+/// Reference: FRU Information Storage Definition, Section 11
+#[derive(Clone, Debug, PartialEq)]
+pub struct BoardInfo {
+    pub language_code: u8,
+    /// The manufacturing date and time of the board, if specified.
+    pub manufacture_date: Option<Timestamp>,
+    pub manufacturer: FruField,
+    pub product_name: FruField,
+    pub serial_number: FruField,
+    pub part_number: FruField,
+    pub fru_file_id: FruField,
+    pub custom_fields: Vec<FruField>,
+}
+
+impl BoardInfo {
+    /// Parse the board info area located at `offset` in `data`.
+    pub fn parse(data: &[u8], offset: u16) -> Result<Self, FruParseError> {
+        let area = FruArea::Board;
+        let data = area_data(data, offset, area)?;
+
+        if data.len() < 6 {
+            return Err(FruParseError::MissingField(area));
+        }
+
+        let language_code = data[2];
+        let minutes = u32::from_le_bytes([data[3], data[4], data[5], 0]);
+        let manufacture_date = if minutes == 0 {
+            None
+        } else {
+            Some(Timestamp::from(BOARD_DATE_EPOCH + minutes * 60))
+        };
+
+        let mut fields = FieldReader::new(&data[6..], area);
+
+        Ok(Self {
+            language_code,
+            manufacture_date,
+            manufacturer: fields.field()?,
+            product_name: fields.field()?,
+            serial_number: fields.field()?,
+            part_number: fields.field()?,
+            fru_file_id: fields.field()?,
+            custom_fields: fields.custom_fields()?,
+        })
+    }
+
+    /// Encode this board info area.
+    pub fn to_bytes(&self) -> Result<Vec<u8>, FruEncodeError> {
+        let minutes = self
+            .manufacture_date
+            .map(|v| u32::from(v).saturating_sub(BOARD_DATE_EPOCH) / 60)
+            .unwrap_or(0)
+            .min(0x00FF_FFFF);
+
+        let [m0, m1, m2, _] = minutes.to_le_bytes();
+
+        let fields = [
+            &self.manufacturer,
+            &self.product_name,
+            &self.serial_number,
+            &self.part_number,
+            &self.fru_file_id,
+        ];
+
+        encode_area(
+            FruArea::Board,
+            &[self.language_code, m0, m1, m2],
+            fields.into_iter().chain(&self.custom_fields),
+        )
+    }
+}
+
+/// The product info area.
+///
+/// Reference: FRU Information Storage Definition, Section 12
+#[derive(Clone, Debug, PartialEq)]
+pub struct ProductInfo {
+    pub language_code: u8,
+    pub manufacturer: FruField,
+    pub product_name: FruField,
+    pub part_number: FruField,
+    pub version: FruField,
+    pub serial_number: FruField,
+    pub asset_tag: FruField,
+    pub fru_file_id: FruField,
+    pub custom_fields: Vec<FruField>,
+}
+
+impl ProductInfo {
+    /// Parse the product info area located at `offset` in `data`.
+    pub fn parse(data: &[u8], offset: u16) -> Result<Self, FruParseError> {
+        let area = FruArea::Product;
+        let data = area_data(data, offset, area)?;
+
+        let language_code = *data.get(2).ok_or(FruParseError::MissingField(area))?;
+        let mut fields = FieldReader::new(&data[3..], area);
+
+        Ok(Self {
+            language_code,
+            manufacturer: fields.field()?,
+            product_name: fields.field()?,
+            part_number: fields.field()?,
+            version: fields.field()?,
+            serial_number: fields.field()?,
+            asset_tag: fields.field()?,
+            fru_file_id: fields.field()?,
+            custom_fields: fields.custom_fields()?,
+        })
+    }
+
+    /// Encode this product info area.
+    pub fn to_bytes(&self) -> Result<Vec<u8>, FruEncodeError> {
+        let fields = [
+            &self.manufacturer,
+            &self.product_name,
+            &self.part_number,
+            &self.version,
+            &self.serial_number,
+            &self.asset_tag,
+            &self.fru_file_id,
+        ];
+
+        encode_area(
+            FruArea::Product,
+            &[self.language_code],
+            fields.into_iter().chain(&self.custom_fields),
+        )
+    }
+}
@@ -0,0 +1,235 @@
+use super::{FruArea, FruEncodeError, FruParseError};
+
+/// The type/length byte that marks the end of the fields in an area.
+pub(crate) const END_OF_FIELDS: u8 = 0xC1;
+
+/// A type/length encoded FRU field.
+///
+/// Reference: FRU Information Storage Definition, Section 13
+#[derive(Clone, Debug, PartialEq)]
+pub enum FruField {
+    /// Binary or unspecified data.
+    Binary(Vec<u8>),
+    /// BCD plus encoded data (digits, space, dash and period).
+    BcdPlus(String),
+    /// 6-bit packed ASCII data.
+    SixBitAscii(String),
+    /// 8-bit text, interpreted as ISO 8859-1 (Latin-1).
+    Text(String),
+}
+
+impl FruField {
+    /// Parse a type/length encoded field from the start of `data`.
+    ///
+    /// Returns `Ok(None)` if `data` starts with the end-of-fields marker, and
+    /// otherwise the parsed field and the amount of bytes it occupied.
+    pub(crate) fn parse(
+        data: &[u8],
+        area: FruArea,
+    ) -> Result<Option<(Self, usize)>, FruParseError> {
+        let type_length = *data.first().ok_or(FruParseError::AreaOutOfBounds(area))?;
+
+        if type_length == END_OF_FIELDS {
+            return Ok(None);
+        }
+
+        let len = (type_length & 0x3F) as usize;
+        let value = data
+            .get(1..1 + len)
+            .ok_or(FruParseError::AreaOutOfBounds(area))?;
+
+        let field = match type_length >> 6 {
+            0b00 => Self::Binary(value.to_vec()),
+            0b01 => Self::BcdPlus(decode_bcd_plus(value)),
+            0b10 => Self::SixBitAscii(decode_six_bit_ascii(value)),
+            _ => Self::Text(value.iter().map(|v| *v as char).collect()),
+        };
+
+        Ok(Some((field, 1 + len)))
+    }
+
+    /// Encode this field as a type/length byte followed by its data.
+    pub(crate) fn to_bytes(&self, area: FruArea) -> Result<Vec<u8>, FruEncodeError> {
+        let (field_type, data) = match self {
+            FruField::Binary(data) => (0b00, data.clone()),
+            FruField::BcdPlus(v) => (0b01, encode_bcd_plus(v, area)?),
+            FruField::SixBitAscii(v) => (0b10, encode_six_bit_ascii(v, area)?),
+            FruField::Text(v) => {
+                // A type/length byte of 0xC1 is the end-of-fields marker,
+                // so single character text can not be encoded.
+                if v.chars().count() == 1 {
+                    return Err(FruEncodeError::InvalidField(area));
+                }
+
+                let data = v
+                    .chars()
+                    .map(|c| u8::try_from(c).map_err(|_| FruEncodeError::InvalidField(area)))
+                    .collect::<Result<_, _>>()?;
+
+                (0b11, data)
+            }
+        };
+
+        if data.len() > 0x3F {
+            return Err(FruEncodeError::FieldTooLong(area));
+        }
+
+        let mut bytes = Vec::with_capacity(1 + data.len());
+        bytes.push((field_type << 6) | data.len() as u8);
+        bytes.extend(data);
+        Ok(bytes)
+    }
+
+    /// Get the value of this field as a string, if it is a textual field.
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            FruField::Binary(_) => None,
+            FruField::BcdPlus(v) | FruField::SixBitAscii(v) | FruField::Text(v) => Some(v),
+        }
+    }
+
+    /// Whether this field contains no data.
+    pub fn is_empty(&self) -> bool {
+        match self {
+            FruField::Binary(v) => v.is_empty(),
+            FruField::BcdPlus(v) | FruField::SixBitAscii(v) | FruField::Text(v) => v.is_empty(),
+        }
+    }
+}
+
+impl core::fmt::Display for FruField {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            FruField::Binary(data) => {
+                for byte in data {
+                    write!(f, "{byte:02X}")?;
+                }
+                Ok(())
+            }
+            FruField::BcdPlus(v) | FruField::SixBitAscii(v) | FruField::Text(v) => {
+                write!(f, "{v}")
+            }
+        }
+    }
+}
+
+fn decode_bcd_plus(data: &[u8]) -> String {
+    const CHARS: &[u8; 16] = b"0123456789 -.???";
+
+    data.iter()
+        .flat_map(|v| [v >> 4, v & 0x0F])
+        .map(|v| CHARS[v as usize] as char)
+        .collect()
+}
+
+fn encode_bcd_plus(value: &str, area: FruArea) -> Result<Vec<u8>, FruEncodeError> {
+    let nibbles = value
+        .chars()
+        .map(|c| match c {
+            '0'..='9' => Ok(c as u8 - b'0'),
+            ' ' => Ok(0xA),
+            '-' => Ok(0xB),
+            '.' => Ok(0xC),
+            _ => Err(FruEncodeError::InvalidField(area)),
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    // Pad an odd amount of characters with a space.
+    Ok(nibbles
+        .chunks(2)
+        .map(|v| (v[0] << 4) | v.get(1).copied().unwrap_or(0xA))
+        .collect())
+}
+
+fn encode_six_bit_ascii(value: &str, area: FruArea) -> Result<Vec<u8>, FruEncodeError> {
+    let chars = value
+        .chars()
+        .map(|c| match c {
+            ' '..='_' => Ok(c as u8 - 0x20),
+            _ => Err(FruEncodeError::InvalidField(area)),
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let mut data = vec![0u8; (chars.len() * 6).div_ceil(8)];
+
+    for (i, value) in chars.into_iter().enumerate() {
+        let bit = i * 6;
+        let shifted = (value as u16) << (bit % 8);
+        data[bit / 8] |= shifted as u8;
+        if let Some(next) = data.get_mut(bit / 8 + 1) {
+            *next |= (shifted >> 8) as u8;
+        }
+    }
+
+    Ok(data)
+}
+
+fn decode_six_bit_ascii(data: &[u8]) -> String {
+    let chars = data.len() * 8 / 6;
+
+    (0..chars)
+        .map(|i| {
+            let bit = i * 6;
+            let low = data[bit / 8] as u16;
+            let high = data.get(bit / 8 + 1).copied().unwrap_or(0) as u16;
+            let value = ((low | (high << 8)) >> (bit % 8)) & 0x3F;
+            (value as u8 + 0x20) as char
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn six_bit_ascii() {
+        let (field, len) = FruField::parse(&[0x83, 0x29, 0xDC, 0xA6], FruArea::Board)
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(4, len);
+        assert_eq!(FruField::SixBitAscii("IPMI".into()), field);
+    }
+
+    #[test]
+    fn bcd_plus() {
+        let (field, _) = FruField::parse(&[0x42, 0x12, 0xAB], FruArea::Board)
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(Some("12 -"), field.as_str());
+    }
+
+    #[test]
+    fn encode_fields() {
+        let encode = |field: FruField| field.to_bytes(FruArea::Board);
+
+        assert_eq!(
+            Ok(vec![0x83, 0x29, 0xDC, 0xA6]),
+            encode(FruField::SixBitAscii("IPMI".into()))
+        );
+        assert_eq!(
+            Ok(vec![0x42, 0x12, 0xAB]),
+            encode(FruField::BcdPlus("12 -".into()))
+        );
+        assert_eq!(Ok(vec![0xC0]), encode(FruField::Text("".into())));
+        assert_eq!(
+            Err(FruEncodeError::InvalidField(FruArea::Board)),
+            encode(FruField::Text("A".into()))
+        );
+        assert_eq!(
+            Err(FruEncodeError::FieldTooLong(FruArea::Board)),
+            encode(FruField::Text("A".repeat(64)))
+        );
+    }
+
+    #[test]
+    fn end_of_fields() {
+        assert_eq!(Ok(None), FruField::parse(&[0xC1], FruArea::Board));
+        assert_eq!(
+            Err(FruParseError::AreaOutOfBounds(FruArea::Board)),
+            FruField::parse(&[0xC4, b'a'], FruArea::Board)
+        );
+    }
+}
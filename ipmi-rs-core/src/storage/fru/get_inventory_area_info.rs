@@ -0,0 +1,99 @@
+use crate::connection::{
+    Address, Channel, IpmiCommand, LogicalUnit, Message, NetFn, NotEnoughData,
+};
+
+/// The Get FRU Inventory Area Info command.
+///
+/// Reference: IPMI 2.0 Specification, Section 34.1, Table 34-2
+#[derive(Clone, Copy, Debug)]
+pub struct GetFruInventoryAreaInfo {
+    device_id: u8,
+    target: Option<(Address, Channel)>,
+    lun: LogicalUnit,
+}
+
+impl GetFruInventoryAreaInfo {
+    /// Create a new Get FRU Inventory Area Info command for the FRU device
+    /// with ID `device_id`.
+    pub fn new(device_id: u8) -> Self {
+        Self {
+            device_id,
+            target: None,
+            lun: LogicalUnit::Zero,
+        }
+    }
+
+    /// Send this command to the management controller at `address` on
+    /// `channel` instead of the BMC.
+    pub fn with_target(mut self, address: Address, channel: Channel) -> Self {
+        self.target = Some((address, channel));
+        self
+    }
+
+    /// Send this command to the logical unit `lun` of its target.
+    pub fn with_lun(mut self, lun: LogicalUnit) -> Self {
+        self.lun = lun;
+        self
+    }
+}
+
+impl From<GetFruInventoryAreaInfo> for Message {
+    fn from(value: GetFruInventoryAreaInfo) -> Self {
+        Message::new_request(NetFn::Storage, 0x10, vec![value.device_id])
+    }
+}
+
+impl IpmiCommand for GetFruInventoryAreaInfo {
+    type Output = FruInventoryAreaInfo;
+    type Error = NotEnoughData;
+
+    fn parse_success_response(data: &[u8]) -> Result<Self::Output, Self::Error> {
+        FruInventoryAreaInfo::parse(data).ok_or(NotEnoughData)
+    }
+
+    fn target(&self) -> Option<(Address, Channel)> {
+        self.target
+    }
+
+    fn target_lun(&self) -> LogicalUnit {
+        self.lun
+    }
+}
+
+/// The unit in which a FRU inventory device is accessed.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum FruAccess {
+    /// The device is accessed by bytes.
+    Bytes,
+    /// The device is accessed by words: offsets and counts
+    /// are expressed in 16-bit words.
+    Words,
+}
+
+/// Information about a FRU inventory area.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct FruInventoryAreaInfo {
+    /// The size of the FRU inventory area, in bytes.
+    pub size: u16,
+    pub access: FruAccess,
+}
+
+impl FruInventoryAreaInfo {
+    /// Parse `FruInventoryAreaInfo` from IPMI response data.
+    pub fn parse(data: &[u8]) -> Option<Self> {
+        if data.len() < 3 {
+            return None;
+        }
+
+        let access = if (data[2] & 0x01) == 0x01 {
+            FruAccess::Words
+        } else {
+            FruAccess::Bytes
+        };
+
+        Some(Self {
+            size: u16::from_le_bytes([data[0], data[1]]),
+            access,
+        })
+    }
+}
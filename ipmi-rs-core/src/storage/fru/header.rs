@@ -0,0 +1,78 @@
+use super::{checksum_ok, FruArea, FruParseError};
+
+/// The FRU common header.
+///
+/// All offsets are in bytes from the start of the FRU inventory data,
+/// and are `None` if the area is not present.
+///
+/// Reference: FRU Information Storage Definition, Section 8
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct CommonHeader {
+    pub format_version: u8,
+    pub internal_use_offset: Option<u16>,
+    pub chassis_info_offset: Option<u16>,
+    pub board_info_offset: Option<u16>,
+    pub product_info_offset: Option<u16>,
+    pub multi_record_offset: Option<u16>,
+}
+
+impl CommonHeader {
+    /// The size of the common header, in bytes.
+    pub const SIZE: usize = 8;
+
+    /// Parse the common header from the start of `data`.
+    pub fn parse(data: &[u8]) -> Result<Self, FruParseError> {
+        let data = data.get(..Self::SIZE).ok_or(FruParseError::NotEnoughData)?;
+
+        let format_version = data[0] & 0x0F;
+        if format_version != 1 {
+            return Err(FruParseError::UnsupportedVersion {
+                area: FruArea::CommonHeader,
+                version: format_version,
+            });
+        }
+
+        if !checksum_ok(data) {
+            return Err(FruParseError::InvalidChecksum(FruArea::CommonHeader));
+        }
+
+        let offset = |v: u8| if v == 0 { None } else { Some(v as u16 * 8) };
+
+        Ok(Self {
+            format_version,
+            internal_use_offset: offset(data[1]),
+            chassis_info_offset: offset(data[2]),
+            board_info_offset: offset(data[3]),
+            product_info_offset: offset(data[4]),
+            multi_record_offset: offset(data[5]),
+        })
+    }
+
+    /// The offsets of all areas that are present.
+    fn offsets(&self) -> impl Iterator<Item = u16> {
+        [
+            self.internal_use_offset,
+            self.chassis_info_offset,
+            self.board_info_offset,
+            self.product_info_offset,
+            self.multi_record_offset,
+        ]
+        .into_iter()
+        .flatten()
+    }
+
+    /// The amount of bytes available to the area starting at `offset`
+    /// before the next area starts, given that the FRU inventory data
+    /// is `size` bytes long.
+    pub fn space_available(&self, offset: u16, size: usize) -> usize {
+        let end = self
+            .offsets()
+            .filter(|v| *v > offset)
+            .map(|v| v as usize)
+            .min()
+            .unwrap_or(size)
+            .min(size);
+
+        end.saturating_sub(offset as usize)
+    }
+}
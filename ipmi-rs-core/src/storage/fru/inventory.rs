@@ -0,0 +1,233 @@
+use super::{
+    BoardInfo, ChassisInfo, CommonHeader, FruArea, FruEncodeError, FruParseError, MultiRecord,
+    MultiRecordIter, ProductInfo,
+};
+
+/// Parsed FRU inventory data.
+///
+/// Reference: FRU Information Storage Definition, Section 8
+#[derive(Clone, Debug, PartialEq)]
+pub struct FruInventory {
+    pub header: CommonHeader,
+    pub chassis: Option<ChassisInfo>,
+    pub board: Option<BoardInfo>,
+    pub product: Option<ProductInfo>,
+    pub multi_records: Vec<MultiRecord>,
+}
+
+impl FruInventory {
+    /// Parse FRU inventory data, as read from the start of a FRU device.
+    pub fn parse(data: &[u8]) -> Result<Self, FruParseError> {
+        let header = CommonHeader::parse(data)?;
+
+        let chassis = header
+            .chassis_info_offset
+            .map(|offset| ChassisInfo::parse(data, offset))
+            .transpose()?;
+
+        let board = header
+            .board_info_offset
+            .map(|offset| BoardInfo::parse(data, offset))
+            .transpose()?;
+
+        let product = header
+            .product_info_offset
+            .map(|offset| ProductInfo::parse(data, offset))
+            .transpose()?;
+
+        let multi_records = header
+            .multi_record_offset
+            .map(|offset| MultiRecordIter::new(data, offset).collect())
+            .transpose()?
+            .unwrap_or_default();
+
+        Ok(Self {
+            header,
+            chassis,
+            board,
+            product,
+            multi_records,
+        })
+    }
+
+    /// Encode the chassis, board and product info areas of this inventory
+    /// and write them into `data`, the FRU inventory data that this
+    /// inventory was parsed from.
+    ///
+    /// The areas are written at the offsets described by the common header.
+    /// If any area would overflow into the next area, an error is returned
+    /// and `data` is left unmodified.
+    pub fn update(&self, data: &mut [u8]) -> Result<(), FruEncodeError> {
+        let areas = [
+            (
+                FruArea::Chassis,
+                self.header.chassis_info_offset,
+                self.chassis.as_ref().map(ChassisInfo::to_bytes),
+            ),
+            (
+                FruArea::Board,
+                self.header.board_info_offset,
+                self.board.as_ref().map(BoardInfo::to_bytes),
+            ),
+            (
+                FruArea::Product,
+                self.header.product_info_offset,
+                self.product.as_ref().map(ProductInfo::to_bytes),
+            ),
+        ];
+
+        let mut encoded = Vec::new();
+
+        for (area, offset, bytes) in areas {
+            let (Some(offset), Some(bytes)) = (offset, bytes) else {
+                continue;
+            };
+
+            let bytes = bytes?;
+            let available = self.header.space_available(offset, data.len());
+
+            if bytes.len() > available {
+                return Err(FruEncodeError::AreaOverflow {
+                    area,
+                    available,
+                    required: bytes.len(),
+                });
+            }
+
+            encoded.push((offset as usize, bytes));
+        }
+
+        for (offset, bytes) in encoded {
+            data[offset..offset + bytes.len()].copy_from_slice(&bytes);
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::{
+        fru::{FruArea, FruField},
+        Timestamp,
+    };
+
+    fn checksum(data: &[u8]) -> u8 {
+        data.iter().fold(0u8, |acc, v| acc.wrapping_sub(*v))
+    }
+
+    fn area(mut data: Vec<u8>) -> Vec<u8> {
+        data.resize((data.len() + 1).next_multiple_of(8) - 1, 0);
+        data[1] = ((data.len() + 1) / 8) as u8;
+        data.push(checksum(&data));
+        data
+    }
+
+    fn text(value: &str) -> Vec<u8> {
+        let mut data = vec![0xC0 | value.len() as u8];
+        data.extend_from_slice(value.as_bytes());
+        data
+    }
+
+    fn inventory() -> Vec<u8> {
+        let mut board = vec![0x01, 0x00, 0x19, 0x01, 0x00, 0x00];
+        for field in ["ACME", "Board", "SN123", "PN-1", "", "custom"] {
+            board.extend(text(field));
+        }
+        board.push(0xC1);
+
+        let mut product = vec![0x01, 0x00, 0x19];
+        for field in ["ACME", "Server", "MODEL-1", "A0", "SN456", "asset", ""] {
+            product.extend(text(field));
+        }
+        product.push(0xC1);
+
+        let board = area(board);
+        let product = area(product);
+
+        let board_offset = 1u8;
+        let product_offset = board_offset + (board.len() / 8) as u8;
+        let mut header = vec![0x01, 0x00, 0x00, board_offset, product_offset, 0x00, 0x00];
+        header.push(checksum(&header));
+
+        [header, board, product].concat()
+    }
+
+    #[test]
+    fn parse_inventory() {
+        let inventory = FruInventory::parse(&inventory()).unwrap();
+
+        assert_eq!(None, inventory.chassis);
+
+        let board = inventory.board.unwrap();
+        assert_eq!(Some(Timestamp::from(820_454_460)), board.manufacture_date);
+        assert_eq!(Some("SN123"), board.serial_number.as_str());
+        assert_eq!(Some("PN-1"), board.part_number.as_str());
+        assert!(board.fru_file_id.is_empty());
+        assert_eq!(vec![FruField::Text("custom".into())], board.custom_fields);
+
+        let product = inventory.product.unwrap();
+        assert_eq!(Some("Server"), product.product_name.as_str());
+        assert_eq!(Some("SN456"), product.serial_number.as_str());
+        assert!(product.custom_fields.is_empty());
+    }
+
+    #[test]
+    fn update_round_trip() {
+        let original = inventory();
+        let mut data = original.clone();
+
+        FruInventory::parse(&data)
+            .unwrap()
+            .update(&mut data)
+            .unwrap();
+        assert_eq!(original, data);
+
+        let mut inventory = FruInventory::parse(&data).unwrap();
+        inventory.product.as_mut().unwrap().asset_tag = FruField::Text("RMA-1234".into());
+        inventory.update(&mut data).unwrap();
+
+        let updated = FruInventory::parse(&data).unwrap();
+        assert_eq!(
+            Some("RMA-1234"),
+            updated.product.unwrap().asset_tag.as_str()
+        );
+        assert_eq!(inventory.board, updated.board);
+    }
+
+    #[test]
+    fn update_refuses_overflow() {
+        let mut data = inventory();
+        let mut inventory = FruInventory::parse(&data).unwrap();
+
+        inventory
+            .board
+            .as_mut()
+            .unwrap()
+            .custom_fields
+            .push(FruField::Text("a longer custom field".into()));
+
+        let original = data.clone();
+        assert_eq!(
+            Err(FruEncodeError::AreaOverflow {
+                area: FruArea::Board,
+                available: 40,
+                required: 64,
+            }),
+            inventory.update(&mut data)
+        );
+        assert_eq!(original, data);
+    }
+
+    #[test]
+    fn invalid_checksum() {
+        let mut data = inventory();
+        data[10] ^= 0xFF;
+
+        assert_eq!(
+            Err(FruParseError::InvalidChecksum(FruArea::Board)),
+            FruInventory::parse(&data)
+        );
+    }
+}
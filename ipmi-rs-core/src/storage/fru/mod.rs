@@ -0,0 +1,98 @@
+//! Definitions for FRU inventory devices and FRU inventory data.
+//!
+//! Reference: IPMI 2.0 Specification, Section 34 and the
+//! Platform Management FRU Information Storage Definition v1.0.
+
+mod get_inventory_area_info;
+pub use get_inventory_area_info::{FruAccess, FruInventoryAreaInfo, GetFruInventoryAreaInfo};
+
+mod read_data;
+pub use read_data::{FruData, ReadFruData};
+
+mod write_data;
+pub use write_data::WriteFruData;
+
+mod field;
+pub use field::FruField;
+
+mod header;
+pub use header::CommonHeader;
+
+mod area;
+pub use area::{BoardInfo, ChassisInfo, ProductInfo};
+
+mod multi_record;
+pub use multi_record::{
+    CompatibilityRecord, DcLoad, DcOutput, ManagementAccess, MultiRecord, MultiRecordIter,
+    PowerSupplyInformation,
+};
+
+mod inventory;
+pub use inventory::FruInventory;
+
+/// An area of FRU inventory data.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum FruArea {
+    CommonHeader,
+    InternalUse,
+    Chassis,
+    Board,
+    Product,
+    MultiRecord,
+}
+
+impl core::fmt::Display for FruArea {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            FruArea::CommonHeader => write!(f, "Common header"),
+            FruArea::InternalUse => write!(f, "Internal use area"),
+            FruArea::Chassis => write!(f, "Chassis info area"),
+            FruArea::Board => write!(f, "Board info area"),
+            FruArea::Product => write!(f, "Product info area"),
+            FruArea::MultiRecord => write!(f, "MultiRecord area"),
+        }
+    }
+}
+
+/// An error that occured while parsing FRU inventory data.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum FruParseError {
+    /// Not enough data was provided.
+    NotEnoughData,
+    /// The format version of an area is not supported.
+    UnsupportedVersion { area: FruArea, version: u8 },
+    /// The checksum of an area is incorrect.
+    InvalidChecksum(FruArea),
+    /// An area extends beyond the end of the FRU inventory data.
+    AreaOutOfBounds(FruArea),
+    /// An area ended before all of its mandatory fields were present.
+    MissingField(FruArea),
+}
+
+/// An error that occured while encoding FRU inventory data.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum FruEncodeError {
+    /// A field is longer than the 63 bytes that can be encoded.
+    FieldTooLong(FruArea),
+    /// A field contains data that can not be represented in its encoding.
+    InvalidField(FruArea),
+    /// An area is larger than the maximum area size of 2040 bytes.
+    AreaTooLarge(FruArea),
+    /// An encoded area would overflow into the next area, or past the end of
+    /// the FRU inventory data.
+    AreaOverflow {
+        area: FruArea,
+        available: usize,
+        required: usize,
+    },
+}
+
+/// Calculate the checksum that makes `data` sum to zero (modulo 256).
+pub(crate) fn checksum(data: &[u8]) -> u8 {
+    data.iter().fold(0u8, |acc, v| acc.wrapping_sub(*v))
+}
+
+/// Verify that `data` sums to zero (modulo 256).
+pub(crate) fn checksum_ok(data: &[u8]) -> bool {
+    data.iter().fold(0u8, |acc, v| acc.wrapping_add(*v)) == 0
+}
@@ -0,0 +1,481 @@
+use super::{checksum_ok, FruArea, FruParseError};
+
+/// The size of a MultiRecord header, in bytes.
+const HEADER_SIZE: usize = 5;
+
+/// A record from the MultiRecord area.
+///
+/// Reference: FRU Information Storage Definition, Section 16 and 18
+#[derive(Clone, Debug, PartialEq)]
+pub enum MultiRecord {
+    PowerSupply(PowerSupplyInformation),
+    DcOutput(DcOutput),
+    DcLoad(DcLoad),
+    ManagementAccess(ManagementAccess),
+    BaseCompatibility(CompatibilityRecord),
+    ExtendedCompatibility(CompatibilityRecord),
+    /// An OEM or otherwise unknown record.
+    ///
+    /// `manufacturer_id` is only present for OEM records (record types
+    /// `0xC0` through `0xFF`), in which case `data` contains the data
+    /// following the manufacturer ID.
+    Other {
+        record_type: u8,
+        manufacturer_id: Option<u32>,
+        data: Vec<u8>,
+    },
+}
+
+impl MultiRecord {
+    /// Parse the data of a record with type `record_type`.
+    pub fn parse(record_type: u8, data: &[u8]) -> Result<Self, FruParseError> {
+        let value = match record_type {
+            0x00 => Self::PowerSupply(PowerSupplyInformation::parse(data)?),
+            0x01 => Self::DcOutput(DcOutput::parse(data)?),
+            0x02 => Self::DcLoad(DcLoad::parse(data)?),
+            0x03 => Self::ManagementAccess(ManagementAccess::parse(data)?),
+            0x04 => Self::BaseCompatibility(CompatibilityRecord::parse(data)?),
+            0x05 => Self::ExtendedCompatibility(CompatibilityRecord::parse(data)?),
+            0xC0..=0xFF => {
+                let id = data
+                    .get(..3)
+                    .ok_or(FruParseError::MissingField(FruArea::MultiRecord))?;
+
+                Self::Other {
+                    record_type,
+                    manufacturer_id: Some(manufacturer_id(id)),
+                    data: data[3..].to_vec(),
+                }
+            }
+            _ => Self::Other {
+                record_type,
+                manufacturer_id: None,
+                data: data.to_vec(),
+            },
+        };
+
+        Ok(value)
+    }
+}
+
+/// An iterator over the records in a MultiRecord area.
+///
+/// Iteration stops after the record marked as the end of
+/// the list, or after the first error.
+pub struct MultiRecordIter<'a> {
+    data: &'a [u8],
+    offset: usize,
+    done: bool,
+}
+
+impl<'a> MultiRecordIter<'a> {
+    /// Create an iterator over the MultiRecord area at `offset` in the FRU
+    /// inventory data `data`.
+    pub fn new(data: &'a [u8], offset: u16) -> Self {
+        Self {
+            data,
+            offset: offset as usize,
+            done: false,
+        }
+    }
+
+    fn next_record(&mut self) -> Result<MultiRecord, FruParseError> {
+        let area = FruArea::MultiRecord;
+
+        let header = self
+            .data
+            .get(self.offset..self.offset + HEADER_SIZE)
+            .ok_or(FruParseError::AreaOutOfBounds(area))?;
+
+        if !checksum_ok(header) {
+            return Err(FruParseError::InvalidChecksum(area));
+        }
+
+        let record_type = header[0];
+        let end_of_list = (header[1] & 0x80) == 0x80;
+        let version = header[1] & 0x0F;
+        let len = header[2] as usize;
+        let record_checksum = header[3];
+
+        if version != 2 {
+            return Err(FruParseError::UnsupportedVersion { area, version });
+        }
+
+        let start = self.offset + HEADER_SIZE;
+        let data = self
+            .data
+            .get(start..start + len)
+            .ok_or(FruParseError::AreaOutOfBounds(area))?;
+
+        let sum = data
+            .iter()
+            .fold(record_checksum, |acc, v| acc.wrapping_add(*v));
+        if sum != 0 {
+            return Err(FruParseError::InvalidChecksum(area));
+        }
+
+        self.offset = start + len;
+        self.done = end_of_list;
+
+        MultiRecord::parse(record_type, data)
+    }
+}
+
+impl Iterator for MultiRecordIter<'_> {
+    type Item = Result<MultiRecord, FruParseError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        let record = self.next_record();
+
+        if record.is_err() {
+            self.done = true;
+        }
+
+        Some(record)
+    }
+}
+
+fn manufacturer_id(data: &[u8]) -> u32 {
+    u32::from_le_bytes([data[0], data[1], data[2], 0])
+}
+
+fn u16_at(data: &[u8], offset: usize) -> u16 {
+    u16::from_le_bytes([data[offset], data[offset + 1]])
+}
+
+fn i16_at(data: &[u8], offset: usize) -> i16 {
+    i16::from_le_bytes([data[offset], data[offset + 1]])
+}
+
+fn require(data: &[u8], len: usize) -> Result<(), FruParseError> {
+    if data.len() < len {
+        Err(FruParseError::MissingField(FruArea::MultiRecord))
+    } else {
+        Ok(())
+    }
+}
+
+/// Power supply information.
+///
+/// Reference: FRU Information Storage Definition, Section 18.1
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PowerSupplyInformation {
+    /// The overall capacity, in watts.
+    pub overall_capacity: u16,
+    /// The peak VA, if specified.
+    pub peak_va: Option<u16>,
+    /// The inrush current, in amperes.
+    pub inrush_current: u8,
+    /// The inrush interval, in milliseconds.
+    pub inrush_interval: u8,
+    /// The low end of input voltage range 1, in millivolts.
+    pub input_voltage_range_1_low: u32,
+    /// The high end of input voltage range 1, in millivolts.
+    pub input_voltage_range_1_high: u32,
+    /// The low end of input voltage range 2, in millivolts.
+    pub input_voltage_range_2_low: u32,
+    /// The high end of input voltage range 2, in millivolts.
+    pub input_voltage_range_2_high: u32,
+    /// The low end of the input frequency range, in hertz.
+    pub input_frequency_low: u8,
+    /// The high end of the input frequency range, in hertz.
+    pub input_frequency_high: u8,
+    /// The AC dropout tolerance, in milliseconds.
+    pub ac_dropout_tolerance: u8,
+    pub tachometer_pulses_predictive_fail: bool,
+    pub power_factor_correction: bool,
+    pub autoswitch: bool,
+    pub hot_swap_support: bool,
+    pub predictive_fail_support: bool,
+    /// The hold-up time, in seconds.
+    pub hold_up_time: u8,
+    /// The peak capacity, in watts.
+    pub peak_capacity: u16,
+    /// The voltages (as defined by the specification) that make
+    /// up the combined wattage.
+    pub combined_voltages: (u8, u8),
+    /// The total combined wattage, in watts.
+    pub total_combined_wattage: u16,
+    /// The lower threshold of the predictive fail tachometer, in RPS.
+    pub predictive_fail_tachometer_threshold: u8,
+}
+
+impl PowerSupplyInformation {
+    fn parse(data: &[u8]) -> Result<Self, FruParseError> {
+        require(data, 24)?;
+
+        let peak_va = u16_at(data, 2);
+        let millivolts = |offset| u16_at(data, offset) as u32 * 10;
+        let peak_wattage = u16_at(data, 18);
+
+        Ok(Self {
+            overall_capacity: u16_at(data, 0) & 0x0FFF,
+            peak_va: (peak_va != 0xFFFF).then_some(peak_va),
+            inrush_current: data[4],
+            inrush_interval: data[5],
+            input_voltage_range_1_low: millivolts(6),
+            input_voltage_range_1_high: millivolts(8),
+            input_voltage_range_2_low: millivolts(10),
+            input_voltage_range_2_high: millivolts(12),
+            input_frequency_low: data[14],
+            input_frequency_high: data[15],
+            ac_dropout_tolerance: data[16],
+            tachometer_pulses_predictive_fail: (data[17] & 0x10) == 0x10,
+            power_factor_correction: (data[17] & 0x02) == 0x02,
+            autoswitch: (data[17] & 0x04) == 0x04,
+            hot_swap_support: (data[17] & 0x08) == 0x08,
+            predictive_fail_support: (data[17] & 0x01) == 0x01,
+            hold_up_time: (peak_wattage >> 12) as u8,
+            peak_capacity: peak_wattage & 0x0FFF,
+            combined_voltages: (data[20] >> 4, data[20] & 0x0F),
+            total_combined_wattage: u16_at(data, 21),
+            predictive_fail_tachometer_threshold: data[23],
+        })
+    }
+}
+
+/// A DC output record.
+///
+/// Reference: FRU Information Storage Definition, Section 18.2
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct DcOutput {
+    /// The output is on standby.
+    pub standby: bool,
+    pub output_number: u8,
+    /// The nominal voltage, in millivolts.
+    pub nominal_voltage: i32,
+    /// The maximum negative voltage deviation, in millivolts.
+    pub max_negative_deviation: i32,
+    /// The maximum positive voltage deviation, in millivolts.
+    pub max_positive_deviation: i32,
+    /// The ripple and noise (peak to peak, 10 Hz to 30 MHz), in millivolts.
+    pub ripple_and_noise: u16,
+    /// The minimum current draw, in milliamperes.
+    pub min_current_draw: u16,
+    /// The maximum current draw, in milliamperes.
+    pub max_current_draw: u16,
+}
+
+impl DcOutput {
+    fn parse(data: &[u8]) -> Result<Self, FruParseError> {
+        require(data, 13)?;
+
+        Ok(Self {
+            standby: (data[0] & 0x80) == 0x80,
+            output_number: data[0] & 0x0F,
+            nominal_voltage: i16_at(data, 1) as i32 * 10,
+            max_negative_deviation: i16_at(data, 3) as i32 * 10,
+            max_positive_deviation: i16_at(data, 5) as i32 * 10,
+            ripple_and_noise: u16_at(data, 7),
+            min_current_draw: u16_at(data, 9),
+            max_current_draw: u16_at(data, 11),
+        })
+    }
+}
+
+/// A DC load record.
+///
+/// Reference: FRU Information Storage Definition, Section 18.3
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct DcLoad {
+    pub output_number: u8,
+    /// The nominal voltage, in millivolts.
+    pub nominal_voltage: i32,
+    /// The minimum specified voltage, in millivolts.
+    pub min_voltage: i32,
+    /// The maximum specified voltage, in millivolts.
+    pub max_voltage: i32,
+    /// The ripple and noise (peak to peak, 10 Hz to 30 MHz), in millivolts.
+    pub ripple_and_noise: u16,
+    /// The minimum current load, in milliamperes.
+    pub min_current_load: u16,
+    /// The maximum current load, in milliamperes.
+    pub max_current_load: u16,
+}
+
+impl DcLoad {
+    fn parse(data: &[u8]) -> Result<Self, FruParseError> {
+        require(data, 13)?;
+
+        Ok(Self {
+            output_number: data[0] & 0x0F,
+            nominal_voltage: i16_at(data, 1) as i32 * 10,
+            min_voltage: i16_at(data, 3) as i32 * 10,
+            max_voltage: i16_at(data, 5) as i32 * 10,
+            ripple_and_noise: u16_at(data, 7),
+            min_current_load: u16_at(data, 9),
+            max_current_load: u16_at(data, 11),
+        })
+    }
+}
+
+/// A management access record.
+///
+/// Reference: FRU Information Storage Definition, Section 18.4
+#[derive(Clone, Debug, PartialEq)]
+pub enum ManagementAccess {
+    SystemManagementUrl(String),
+    SystemName(String),
+    SystemPingAddress(String),
+    ComponentManagementUrl(String),
+    ComponentName(String),
+    ComponentPingAddress(String),
+    SystemUniqueId([u8; 16]),
+    Unknown { sub_record_type: u8, data: Vec<u8> },
+}
+
+impl ManagementAccess {
+    fn parse(data: &[u8]) -> Result<Self, FruParseError> {
+        require(data, 1)?;
+
+        let sub_record_type = data[0];
+        let data = &data[1..];
+        let text = || data.iter().map(|v| *v as char).collect::<String>();
+
+        let value = match sub_record_type {
+            0x01 => Self::SystemManagementUrl(text()),
+            0x02 => Self::SystemName(text()),
+            0x03 => Self::SystemPingAddress(text()),
+            0x04 => Self::ComponentManagementUrl(text()),
+            0x05 => Self::ComponentName(text()),
+            0x06 => Self::ComponentPingAddress(text()),
+            0x07 => {
+                let id = data
+                    .get(..16)
+                    .ok_or(FruParseError::MissingField(FruArea::MultiRecord))?;
+                Self::SystemUniqueId(id.try_into().unwrap())
+            }
+            _ => Self::Unknown {
+                sub_record_type,
+                data: data.to_vec(),
+            },
+        };
+
+        Ok(value)
+    }
+}
+
+/// A base or extended compatibility record.
+///
+/// Reference: FRU Information Storage Definition, Section 18.5 and 18.6
+#[derive(Clone, Debug, PartialEq)]
+pub struct CompatibilityRecord {
+    pub manufacturer_id: u32,
+    pub entity_id: u8,
+    pub compatibility_base: u8,
+    pub compatibility_code_start: u8,
+    pub code_range_mask: Vec<u8>,
+}
+
+impl CompatibilityRecord {
+    fn parse(data: &[u8]) -> Result<Self, FruParseError> {
+        require(data, 6)?;
+
+        Ok(Self {
+            manufacturer_id: manufacturer_id(data),
+            entity_id: data[3],
+            compatibility_base: data[4],
+            compatibility_code_start: data[5],
+            code_range_mask: data[6..].to_vec(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(record_type: u8, end_of_list: bool, data: &[u8]) -> Vec<u8> {
+        let checksum = |data: &[u8]| data.iter().fold(0u8, |acc, v| acc.wrapping_sub(*v));
+
+        let format = if end_of_list { 0x82 } else { 0x02 };
+        let mut header = vec![record_type, format, data.len() as u8, checksum(data)];
+        header.push(checksum(&header));
+
+        [header, data.to_vec()].concat()
+    }
+
+    #[test]
+    fn iterate_records() {
+        let dc_output = [
+            0x81, 0xF4, 0x01, 0x19, 0x00, 0x19, 0x00, 0x32, 0x00, 0x00, 0x00, 0x10, 0x27,
+        ];
+        let oem = [0x57, 0x01, 0x00, 0xAA, 0xBB];
+
+        let data = [
+            vec![0xFF; 8],
+            record(0x01, false, &dc_output),
+            record(0xC2, true, &oem),
+            record(0x02, true, &[0; 13]),
+        ]
+        .concat();
+
+        let records: Vec<_> = MultiRecordIter::new(&data, 8)
+            .collect::<Result<_, _>>()
+            .unwrap();
+
+        assert_eq!(2, records.len());
+
+        let MultiRecord::DcOutput(output) = &records[0] else {
+            panic!("Expected DC output record, got {:?}", records[0]);
+        };
+
+        assert!(output.standby);
+        assert_eq!(1, output.output_number);
+        assert_eq!(5000, output.nominal_voltage);
+        assert_eq!(10_000, output.max_current_draw);
+
+        assert_eq!(
+            MultiRecord::Other {
+                record_type: 0xC2,
+                manufacturer_id: Some(0x157),
+                data: vec![0xAA, 0xBB],
+            },
+            records[1]
+        );
+    }
+
+    #[test]
+    fn power_supply_flags() {
+        let flags = |bit: u8| {
+            let mut data = [0u8; 24];
+            data[17] = bit;
+
+            let MultiRecord::PowerSupply(info) = MultiRecord::parse(0x00, &data).unwrap() else {
+                panic!("Expected power supply record");
+            };
+
+            [
+                info.tachometer_pulses_predictive_fail,
+                info.hot_swap_support,
+                info.autoswitch,
+                info.power_factor_correction,
+                info.predictive_fail_support,
+            ]
+        };
+
+        assert_eq!([true, false, false, false, false], flags(0x10));
+        assert_eq!([false, true, false, false, false], flags(0x08));
+        assert_eq!([false, false, true, false, false], flags(0x04));
+        assert_eq!([false, false, false, true, false], flags(0x02));
+        assert_eq!([false, false, false, false, true], flags(0x01));
+    }
+
+    #[test]
+    fn invalid_record_checksum() {
+        let mut data = record(0x02, true, &[0; 13]);
+        data[7] = 0x01;
+
+        let mut iter = MultiRecordIter::new(&data, 0);
+
+        assert_eq!(
+            Some(Err(FruParseError::InvalidChecksum(FruArea::MultiRecord))),
+            iter.next()
+        );
+        assert_eq!(None, iter.next());
+    }
+}
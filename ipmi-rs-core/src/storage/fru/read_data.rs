@@ -0,0 +1,135 @@
+use crate::connection::{
+    Address, Channel, IpmiCommand, LogicalUnit, Message, NetFn, NotEnoughData,
+};
+
+use super::FruAccess;
+
+/// The Read FRU Data command.
+///
+/// `offset` and `count` are expressed in the access unit of the
+/// FRU device (see [`FruAccess`]).
+///
+/// Reference: IPMI 2.0 Specification, Section 34.2, Table 34-3
+///
+/// [`FruAccess`]: super::FruAccess
+#[derive(Clone, Copy, Debug)]
+pub struct ReadFruData {
+    device_id: u8,
+    offset: u16,
+    count: u8,
+    target: Option<(Address, Channel)>,
+    lun: LogicalUnit,
+}
+
+impl ReadFruData {
+    /// Create a new Read FRU Data command that reads `count` units
+    /// at `offset` of the FRU device with ID `device_id`.
+    pub fn new(device_id: u8, offset: u16, count: u8) -> Self {
+        Self {
+            device_id,
+            offset,
+            count,
+            target: None,
+            lun: LogicalUnit::Zero,
+        }
+    }
+
+    /// Send this command to the management controller at `address` on
+    /// `channel` instead of the BMC.
+    pub fn with_target(mut self, address: Address, channel: Channel) -> Self {
+        self.target = Some((address, channel));
+        self
+    }
+
+    /// Send this command to the logical unit `lun` of its target.
+    pub fn with_lun(mut self, lun: LogicalUnit) -> Self {
+        self.lun = lun;
+        self
+    }
+}
+
+impl From<ReadFruData> for Message {
+    fn from(value: ReadFruData) -> Self {
+        let [offset_lsb, offset_msb] = value.offset.to_le_bytes();
+
+        Message::new_request(
+            NetFn::Storage,
+            0x11,
+            vec![value.device_id, offset_lsb, offset_msb, value.count],
+        )
+    }
+}
+
+impl IpmiCommand for ReadFruData {
+    type Output = FruData;
+    type Error = NotEnoughData;
+
+    fn parse_success_response(data: &[u8]) -> Result<Self::Output, Self::Error> {
+        let Some((&count, data)) = data.split_first() else {
+            return Err(NotEnoughData);
+        };
+
+        if count == 0 || count as usize > data.len() {
+            return Err(NotEnoughData);
+        }
+
+        Ok(FruData {
+            count,
+            data: data.to_vec(),
+        })
+    }
+
+    fn target(&self) -> Option<(Address, Channel)> {
+        self.target
+    }
+
+    fn target_lun(&self) -> LogicalUnit {
+        self.lun
+    }
+}
+
+/// The data returned by a [`ReadFruData`] command.
+#[derive(Clone, Debug, PartialEq)]
+pub struct FruData {
+    /// The amount of units that were read, in the access unit of
+    /// the FRU device.
+    pub count: u8,
+    /// The data that was returned, which may contain more bytes than
+    /// were read.
+    pub data: Vec<u8>,
+}
+
+impl FruData {
+    /// The bytes that were read from a FRU device that is accessed by
+    /// `access`.
+    ///
+    /// Returns `None` if the response contains less than `count` units.
+    pub fn bytes(&self, access: FruAccess) -> Option<&[u8]> {
+        let len = match access {
+            FruAccess::Bytes => self.count as usize,
+            FruAccess::Words => self.count as usize * 2,
+        };
+
+        self.data.get(..len)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_count() {
+        let data = ReadFruData::parse_success_response(&[0x02, 0x01, 0x02, 0x03, 0x04]).unwrap();
+
+        assert_eq!(Some(&[0x01, 0x02][..]), data.bytes(FruAccess::Bytes));
+        assert_eq!(
+            Some(&[0x01, 0x02, 0x03, 0x04][..]),
+            data.bytes(FruAccess::Words)
+        );
+
+        assert!(ReadFruData::parse_success_response(&[]).is_err());
+        assert!(ReadFruData::parse_success_response(&[0x00, 0x01]).is_err());
+        assert!(ReadFruData::parse_success_response(&[0x03, 0x01, 0x02]).is_err());
+    }
+}
@@ -0,0 +1,75 @@
+use crate::connection::{
+    Address, Channel, IpmiCommand, LogicalUnit, Message, NetFn, NotEnoughData,
+};
+
+/// The Write FRU Data command.
+///
+/// `offset` is expressed in the access unit of the FRU device (see [`FruAccess`]).
+/// The output of this command is the amount of units that were written.
+///
+/// Reference: IPMI 2.0 Specification, Section 34.3, Table 34-4
+///
+/// [`FruAccess`]: super::FruAccess
+#[derive(Clone, Debug)]
+pub struct WriteFruData {
+    device_id: u8,
+    offset: u16,
+    data: Vec<u8>,
+    target: Option<(Address, Channel)>,
+    lun: LogicalUnit,
+}
+
+impl WriteFruData {
+    /// Create a new Write FRU Data command that writes `data` at
+    /// `offset` of the FRU device with ID `device_id`.
+    pub fn new(device_id: u8, offset: u16, data: Vec<u8>) -> Self {
+        Self {
+            device_id,
+            offset,
+            data,
+            target: None,
+            lun: LogicalUnit::Zero,
+        }
+    }
+
+    /// Send this command to the management controller at `address` on
+    /// `channel` instead of the BMC.
+    pub fn with_target(mut self, address: Address, channel: Channel) -> Self {
+        self.target = Some((address, channel));
+        self
+    }
+
+    /// Send this command to the logical unit `lun` of its target.
+    pub fn with_lun(mut self, lun: LogicalUnit) -> Self {
+        self.lun = lun;
+        self
+    }
+}
+
+impl From<WriteFruData> for Message {
+    fn from(value: WriteFruData) -> Self {
+        let mut payload = Vec::with_capacity(3 + value.data.len());
+        payload.push(value.device_id);
+        payload.extend_from_slice(&value.offset.to_le_bytes());
+        payload.extend_from_slice(&value.data);
+
+        Message::new_request(NetFn::Storage, 0x12, payload)
+    }
+}
+
+impl IpmiCommand for WriteFruData {
+    type Output = u8;
+    type Error = NotEnoughData;
+
+    fn parse_success_response(data: &[u8]) -> Result<Self::Output, Self::Error> {
+        data.first().copied().ok_or(NotEnoughData)
+    }
+
+    fn target(&self) -> Option<(Address, Channel)> {
+        self.target
+    }
+
+    fn target_lun(&self) -> LogicalUnit {
+        self.lun
+    }
+}
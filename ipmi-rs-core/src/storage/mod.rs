@@ -1,3 +1,5 @@
+pub mod fru;
+
 pub mod sel;
 
 use std::num::NonZeroU16;
@@ -35,6 +37,12 @@ impl From<u32> for Timestamp {
     }
 }
 
+impl From<Timestamp> for u32 {
+    fn from(value: Timestamp) -> Self {
+        value.0
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct AllocInfo {
     pub num_alloc_units: Option<NonZeroU16>,
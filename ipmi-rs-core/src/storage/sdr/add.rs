@@ -0,0 +1,137 @@
+//! Add SDR and Partial Add SDR Commands
+//!
+//! Reference: IPMI 2.0 Specification, Section 33.13 "Add SDR Command" and
+//! Section 33.14 "Partial Add SDR Command"
+
+use std::num::NonZeroU16;
+
+use crate::connection::{IpmiCommand, Message, NetFn, NotEnoughData};
+
+use super::RecordId;
+
+fn parse_record_id(data: &[u8]) -> Result<RecordId, NotEnoughData> {
+    if data.len() < 2 {
+        return Err(NotEnoughData);
+    }
+
+    Ok(RecordId::new_raw(u16::from_le_bytes([data[0], data[1]])))
+}
+
+/// Add SDR command.
+///
+/// Adds a record to the SDR repository. The record ID in the header of the
+/// record is ignored, and the ID assigned to the record by the SDR
+/// repository is returned.
+///
+/// Reference: IPMI 2.0 Specification, Section 33.13, Table 33-6
+pub struct AddSdr {
+    record: Vec<u8>,
+}
+
+impl AddSdr {
+    /// Create a new Add SDR command for the raw `record`, including its header.
+    pub fn new(record: Vec<u8>) -> Self {
+        Self { record }
+    }
+}
+
+impl From<AddSdr> for Message {
+    fn from(value: AddSdr) -> Self {
+        Message::new_request(NetFn::Storage, 0x24, value.record)
+    }
+}
+
+impl IpmiCommand for AddSdr {
+    type Output = RecordId;
+    type Error = NotEnoughData;
+
+    fn parse_success_response(data: &[u8]) -> Result<Self::Output, Self::Error> {
+        parse_record_id(data)
+    }
+}
+
+/// Partial Add SDR command.
+///
+/// Adds a record that does not fit in a single message to the SDR
+/// repository, in parts. The first part must contain at least the header
+/// of the record, and is written with record ID [`RecordId::FIRST`]. The
+/// remaining parts are written with the record ID returned for the first
+/// part. The record is added to the SDR repository once the last part has
+/// been written.
+///
+/// Support for this command is indicated by [`Operation::PartialAdd`](super::SdrOperation::PartialAdd).
+///
+/// Reference: IPMI 2.0 Specification, Section 33.14, Table 33-7
+pub struct PartialAddSdr {
+    reservation_id: NonZeroU16,
+    record_id: RecordId,
+    offset: u8,
+    last: bool,
+    data: Vec<u8>,
+}
+
+impl PartialAddSdr {
+    /// Create a new Partial Add SDR command that writes `data` at `offset`
+    /// into the record with ID `record_id`.
+    ///
+    /// `last` indicates that `data` is the last part of the record.
+    ///
+    /// `offset` is a single byte, so no part of a record can start beyond
+    /// byte 255. The part that starts at or below offset 255 and would be
+    /// followed by a part beyond it must contain the remainder of the record.
+    pub fn new(
+        reservation_id: NonZeroU16,
+        record_id: RecordId,
+        offset: u8,
+        last: bool,
+        data: Vec<u8>,
+    ) -> Self {
+        Self {
+            reservation_id,
+            record_id,
+            offset,
+            last,
+            data,
+        }
+    }
+}
+
+impl From<PartialAddSdr> for Message {
+    fn from(value: PartialAddSdr) -> Self {
+        let mut data = Vec::with_capacity(6 + value.data.len());
+
+        data.extend_from_slice(&value.reservation_id.get().to_le_bytes());
+        data.extend_from_slice(&value.record_id.value().to_le_bytes());
+        data.push(value.offset);
+        data.push(if value.last { 0x01 } else { 0x00 });
+        data.extend_from_slice(&value.data);
+
+        Message::new_request(NetFn::Storage, 0x25, data)
+    }
+}
+
+impl IpmiCommand for PartialAddSdr {
+    type Output = RecordId;
+    type Error = NotEnoughData;
+
+    fn parse_success_response(data: &[u8]) -> Result<Self::Output, Self::Error> {
+        parse_record_id(data)
+    }
+}
+
+#[test]
+fn partial_add_request() {
+    let command = PartialAddSdr::new(
+        NonZeroU16::new(0x1234).unwrap(),
+        RecordId::new_raw(0x0042),
+        0x10,
+        true,
+        vec![0xAA, 0xBB],
+    );
+
+    let message: Message = command.into();
+    assert_eq!(
+        &[0x34, 0x12, 0x42, 0x00, 0x10, 0x01, 0xAA, 0xBB],
+        message.data()
+    );
+}
@@ -0,0 +1,181 @@
+use crate::storage::Timestamp;
+
+use super::{Record, RecordParseError, SdrRepositoryInfo};
+
+/// The magic bytes at the start of a serialized [`SdrCache`].
+const MAGIC: [u8; 8] = *b"IPMI-SDR";
+
+/// The size of the header of a serialized [`SdrCache`], in bytes.
+const HEADER_SIZE: usize = 21;
+
+/// An error that occured while parsing a serialized [`SdrCache`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SdrCacheError {
+    /// The data does not start with the expected magic bytes.
+    InvalidMagic,
+    /// The format version of the data is not supported.
+    UnsupportedVersion(u8),
+    /// Not enough data was provided.
+    NotEnoughData,
+}
+
+/// A copy of the raw records in an SDR repository, along with the
+/// repository state they were read at.
+///
+/// The cache is valid for as long as the most recent addition and erase
+/// timestamps, and the record count, of the SDR repository are unchanged.
+///
+/// The serialized format consists of the magic bytes `IPMI-SDR`, a format
+/// version byte, the most recent addition and erase timestamps (`u32`), the
+/// record count reported by the repository (`u16`), the amount of cached
+/// records (`u16`), and the raw records, including their headers. All
+/// integers are little-endian.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SdrCache {
+    pub most_recent_addition: Timestamp,
+    pub most_recent_erase: Timestamp,
+    pub record_count: u16,
+    /// The raw records, including their headers.
+    pub records: Vec<Vec<u8>>,
+}
+
+impl SdrCache {
+    /// The current version of the serialized format.
+    pub const VERSION: u8 = 1;
+
+    /// Create a new, empty cache for the SDR repository described by `info`.
+    pub fn new(info: &SdrRepositoryInfo) -> Self {
+        Self {
+            most_recent_addition: info.most_recent_addition,
+            most_recent_erase: info.most_recent_erase,
+            record_count: info.record_count,
+            records: Vec::new(),
+        }
+    }
+
+    /// Whether this cache holds the contents of the SDR repository
+    /// described by `info`.
+    pub fn is_valid_for(&self, info: &SdrRepositoryInfo) -> bool {
+        self.most_recent_addition == info.most_recent_addition
+            && self.most_recent_erase == info.most_recent_erase
+            && self.record_count == info.record_count
+    }
+
+    /// Parse the cached records.
+    pub fn records(&self) -> impl Iterator<Item = Result<Record, RecordParseError>> + '_ {
+        self.records.iter().map(|v| Record::parse(v))
+    }
+
+    /// Parse a serialized cache.
+    pub fn parse(data: &[u8]) -> Result<Self, SdrCacheError> {
+        if data.len() < MAGIC.len() + 1 {
+            return Err(SdrCacheError::NotEnoughData);
+        }
+
+        if data[..MAGIC.len()] != MAGIC {
+            return Err(SdrCacheError::InvalidMagic);
+        }
+
+        let version = data[MAGIC.len()];
+        if version != Self::VERSION {
+            return Err(SdrCacheError::UnsupportedVersion(version));
+        }
+
+        if data.len() < HEADER_SIZE {
+            return Err(SdrCacheError::NotEnoughData);
+        }
+
+        let u32_at = |offset: usize| {
+            u32::from_le_bytes([
+                data[offset],
+                data[offset + 1],
+                data[offset + 2],
+                data[offset + 3],
+            ])
+        };
+
+        let most_recent_addition = Timestamp::from(u32_at(9));
+        let most_recent_erase = Timestamp::from(u32_at(13));
+        let record_count = u16::from_le_bytes([data[17], data[18]]);
+        let cached_records = u16::from_le_bytes([data[19], data[20]]);
+
+        let mut records = Vec::with_capacity(cached_records as usize);
+        let mut data = &data[HEADER_SIZE..];
+
+        for _ in 0..cached_records {
+            let (record, rest) = Record::split_raw(data).ok_or(SdrCacheError::NotEnoughData)?;
+
+            records.push(record.to_vec());
+            data = rest;
+        }
+
+        Ok(Self {
+            most_recent_addition,
+            most_recent_erase,
+            record_count,
+            records,
+        })
+    }
+
+    /// Serialize this cache.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut data =
+            Vec::with_capacity(HEADER_SIZE + self.records.iter().map(Vec::len).sum::<usize>());
+
+        data.extend_from_slice(&MAGIC);
+        data.push(Self::VERSION);
+        data.extend_from_slice(&u32::from(self.most_recent_addition).to_le_bytes());
+        data.extend_from_slice(&u32::from(self.most_recent_erase).to_le_bytes());
+        data.extend_from_slice(&self.record_count.to_le_bytes());
+        data.extend_from_slice(&(self.records.len() as u16).to_le_bytes());
+
+        for record in &self.records {
+            data.extend_from_slice(record);
+        }
+
+        data
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cache() -> SdrCache {
+        SdrCache {
+            most_recent_addition: Timestamp::from(0x6500_0000),
+            most_recent_erase: Timestamp::from(0x6400_0000),
+            record_count: 2,
+            records: vec![
+                vec![0x01, 0x00, 0x51, 0xC0, 0x04, 0x57, 0x01, 0x00, 0xAA],
+                vec![0x02, 0x00, 0x51, 0xC0, 0x03, 0x57, 0x01, 0x00],
+            ],
+        }
+    }
+
+    #[test]
+    fn round_trip() {
+        let cache = cache();
+        let data = cache.to_bytes();
+
+        assert_eq!(Ok(cache), SdrCache::parse(&data));
+        assert_eq!(
+            Err(SdrCacheError::NotEnoughData),
+            SdrCache::parse(&data[..data.len() - 1])
+        );
+    }
+
+    #[test]
+    fn reject_unknown_format() {
+        let mut data = cache().to_bytes();
+
+        data[8] = SdrCache::VERSION + 1;
+        assert_eq!(
+            Err(SdrCacheError::UnsupportedVersion(SdrCache::VERSION + 1)),
+            SdrCache::parse(&data)
+        );
+
+        data[0] = 0;
+        assert_eq!(Err(SdrCacheError::InvalidMagic), SdrCache::parse(&data));
+    }
+}
@@ -0,0 +1,70 @@
+//! Clear SDR Repository Command
+//!
+//! Reference: IPMI 2.0 Specification, Section 33.16 "Clear SDR Repository Command"
+
+use std::num::NonZeroU16;
+
+use crate::connection::{IpmiCommand, Message, NetFn, NotEnoughData};
+use crate::storage::sel::ErasureProgress;
+
+/// Clear SDR Repository command.
+///
+/// This command is used to erase all records in the SDR repository. Like
+/// [`ClearSel`](crate::storage::sel::ClearSel), clearing is a two-step
+/// process: the erase is initiated, after which its status can be polled
+/// until it has completed. The Reservation ID should be `None` if the SDR
+/// repository does not support reservations.
+///
+/// Reference: IPMI 2.0 Specification, Section 33.16, Table 33-9
+pub struct ClearSdrRepository {
+    reservation_id: Option<NonZeroU16>,
+    initiate: bool,
+}
+
+impl ClearSdrRepository {
+    /// Create a new ClearSdrRepository command to initiate erasure.
+    pub fn initiate(reservation_id: Option<NonZeroU16>) -> Self {
+        Self {
+            reservation_id,
+            initiate: true,
+        }
+    }
+
+    /// Create a new ClearSdrRepository command to get erasure status.
+    pub fn get_status(reservation_id: Option<NonZeroU16>) -> Self {
+        Self {
+            reservation_id,
+            initiate: false,
+        }
+    }
+}
+
+impl IpmiCommand for ClearSdrRepository {
+    type Output = ErasureProgress;
+    type Error = NotEnoughData;
+
+    fn parse_success_response(data: &[u8]) -> Result<Self::Output, Self::Error> {
+        if data.is_empty() {
+            return Err(NotEnoughData);
+        }
+
+        Ok(if data[0] & 0x0F == 0x01 {
+            ErasureProgress::Completed
+        } else {
+            ErasureProgress::InProgress
+        })
+    }
+}
+
+impl From<ClearSdrRepository> for Message {
+    fn from(value: ClearSdrRepository) -> Self {
+        let mut data = vec![0u8; 6];
+        data[0..2].copy_from_slice(&value.reservation_id.map_or(0, |id| id.get()).to_le_bytes());
+        data[2] = 0x43; // 'C'
+        data[3] = 0x4C; // 'L'
+        data[4] = 0x52; // 'R'
+        data[5] = if value.initiate { 0xAA } else { 0x00 };
+
+        Message::new_request(NetFn::Storage, 0x27, data)
+    }
+}
@@ -0,0 +1,56 @@
+//! Delete SDR Command
+//!
+//! Reference: IPMI 2.0 Specification, Section 33.15 "Delete SDR Command"
+
+use std::num::NonZeroU16;
+
+use crate::connection::{IpmiCommand, Message, NetFn, NotEnoughData};
+
+use super::RecordId;
+
+/// Delete SDR command.
+///
+/// Deletes the record with the given record ID from the SDR repository,
+/// and returns the ID of the deleted record. The Reservation ID should be
+/// `None` if the SDR repository does not support reservations.
+///
+/// Support for this command is indicated by [`Operation::Delete`](super::SdrOperation::Delete).
+///
+/// Reference: IPMI 2.0 Specification, Section 33.15, Table 33-8
+pub struct DeleteSdr {
+    reservation_id: Option<NonZeroU16>,
+    record_id: RecordId,
+}
+
+impl DeleteSdr {
+    pub fn new(reservation_id: Option<NonZeroU16>, record_id: RecordId) -> Self {
+        Self {
+            reservation_id,
+            record_id,
+        }
+    }
+}
+
+impl From<DeleteSdr> for Message {
+    fn from(value: DeleteSdr) -> Self {
+        let mut data = vec![0u8; 4];
+
+        data[0..2].copy_from_slice(&value.reservation_id.map_or(0, |id| id.get()).to_le_bytes());
+        data[2..4].copy_from_slice(&value.record_id.value().to_le_bytes());
+
+        Message::new_request(NetFn::Storage, 0x26, data)
+    }
+}
+
+impl IpmiCommand for DeleteSdr {
+    type Output = RecordId;
+    type Error = NotEnoughData;
+
+    fn parse_success_response(data: &[u8]) -> Result<Self::Output, Self::Error> {
+        if data.len() < 2 {
+            return Err(NotEnoughData);
+        }
+
+        Ok(RecordId::new_raw(u16::from_le_bytes([data[0], data[1]])))
+    }
+}
@@ -0,0 +1,60 @@
+//! SDR dump files.
+//!
+//! An SDR dump, such as one created using `ipmitool sdr dump`, consists of
+//! the raw records of an SDR repository, including their headers, without
+//! any additional framing.
+
+use super::{Record, RecordEncodeError, RecordParseError};
+
+/// An iterator over the records in an SDR dump.
+///
+/// Created using [`read_dump`].
+#[derive(Debug, Clone)]
+pub struct DumpRecords<'a> {
+    data: &'a [u8],
+}
+
+impl Iterator for DumpRecords<'_> {
+    type Item = Result<Record, RecordParseError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.data.is_empty() {
+            return None;
+        }
+
+        let Some((record, rest)) = Record::split_raw(self.data) else {
+            // The dump is truncated: there are no more records to read.
+            self.data = &[];
+            return Some(Err(RecordParseError::NotEnoughData));
+        };
+
+        self.data = rest;
+
+        Some(Record::parse(record))
+    }
+}
+
+/// Read the records in the SDR dump `data`.
+///
+/// Records that cannot be parsed yield an error, after which the
+/// remaining records are still read. A truncated record at the end of
+/// `data` yields [`RecordParseError::NotEnoughData`].
+pub fn read_dump(data: &[u8]) -> DumpRecords<'_> {
+    DumpRecords { data }
+}
+
+/// Write `records` as an SDR dump that can be read using [`read_dump`],
+/// or by `ipmitool -S <file>`.
+///
+/// The records are encoded using [`Record::encode`].
+pub fn write_dump<'a>(
+    records: impl IntoIterator<Item = &'a Record>,
+) -> Result<Vec<u8>, RecordEncodeError> {
+    let mut data = Vec::new();
+
+    for record in records {
+        data.extend(record.encode()?);
+    }
+
+    Ok(data)
+}
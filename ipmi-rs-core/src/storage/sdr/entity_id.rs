@@ -0,0 +1,192 @@
+//! Entity ID Codes
+//!
+//! Reference: IPMI 2.0 Specification, Table 43-13 "Entity ID Codes"
+
+use core::fmt;
+
+macro_rules ! entity_id {
+    {
+        pub enum EntityId {
+            $(
+                $(#[doc = $doc:literal])?
+                $name:ident = $value:literal => $display:literal,
+            )*
+            [$reserved_range:pat],
+            [$chassis_specific_range:pat],
+            [$board_set_specific_range:pat],
+            [$oem_range:pat],
+        }
+    } => {
+        /// Entity ID codes as defined in IPMI 2.0 Specification, Table 43-13.
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+        pub enum EntityId {
+            $(
+                $(#[doc = $doc])?
+                $name,
+            )*
+            /// Reserved entity ID
+            Reserved(u8),
+            /// Chassis-specific entity ID (0x90-0xAF)
+            ChassisSpecific(u8),
+            /// Board-set specific entity ID (0xB0-0xCF)
+            BoardSetSpecific(u8),
+            /// OEM system integrator defined entity ID (0xD0-0xFF)
+            Oem(u8),
+        }
+
+        impl From<u8> for EntityId {
+            fn from(value: u8) -> Self {
+                match value {
+                    $($value => Self::$name,)*
+                    $reserved_range => Self::Reserved(value),
+                    $chassis_specific_range => Self::ChassisSpecific(value),
+                    $board_set_specific_range => Self::BoardSetSpecific(value),
+                    $oem_range => Self::Oem(value),
+                }
+            }
+        }
+
+        impl From<EntityId> for u8 {
+            fn from(value: EntityId) -> u8 {
+                match value {
+                    $(EntityId::$name => $value,)*
+                    EntityId::Reserved(v)
+                    | EntityId::ChassisSpecific(v)
+                    | EntityId::BoardSetSpecific(v)
+                    | EntityId::Oem(v) => v,
+                }
+            }
+        }
+
+        impl TryFrom<&str> for EntityId {
+            type Error = ();
+
+            fn try_from(input: &str) -> Result<Self, Self::Error> {
+                let to_lower = input.to_ascii_lowercase();
+
+                $(
+                    if stringify!($name).to_ascii_lowercase() == to_lower {
+                        return Ok(EntityId::$name);
+                    }
+                )*
+
+                Err(())
+            }
+        }
+
+        impl fmt::Display for EntityId {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                match self {
+                    $(EntityId::$name => write!(f, $display),)*
+                    EntityId::Reserved(v) => write!(f, "Reserved (0x{:02X})", v),
+                    EntityId::ChassisSpecific(v) => write!(f, "Chassis-specific (0x{:02X})", v),
+                    EntityId::BoardSetSpecific(v) => write!(f, "Board-set specific (0x{:02X})", v),
+                    EntityId::Oem(v) => write!(f, "OEM (0x{:02X})", v),
+                }
+            }
+        }
+    }
+}
+
+entity_id! {
+    pub enum EntityId {
+        Unspecified = 0x00 => "Unspecified",
+        Other = 0x01 => "Other",
+        Unknown = 0x02 => "Unknown",
+        Processor = 0x03 => "Processor",
+        Disk = 0x04 => "Disk or Disk Bay",
+        PeripheralBay = 0x05 => "Peripheral Bay",
+        SystemManagementModule = 0x06 => "System Management Module",
+        SystemBoard = 0x07 => "System Board",
+        MemoryModule = 0x08 => "Memory Module",
+        ProcessorModule = 0x09 => "Processor Module",
+        PowerSupply = 0x0A => "Power Supply",
+        AddInCard = 0x0B => "Add-in Card",
+        FrontPanelBoard = 0x0C => "Front Panel Board",
+        BackPanelBoard = 0x0D => "Back Panel Board",
+        PowerSystemBoard = 0x0E => "Power System Board",
+        DriveBackplane = 0x0F => "Drive Backplane",
+        SystemInternalExpansionBoard = 0x10 => "System Internal Expansion Board",
+        OtherSystemBoard = 0x11 => "Other System Board",
+        ProcessorBoard = 0x12 => "Processor Board",
+        /// A power unit or power domain, usually used for redundant power supplies.
+        PowerUnit = 0x13 => "Power Unit",
+        PowerModule = 0x14 => "Power Module",
+        PowerManagement = 0x15 => "Power Management",
+        ChassisBackPanelBoard = 0x16 => "Chassis Back Panel Board",
+        SystemChassis = 0x17 => "System Chassis",
+        SubChassis = 0x18 => "Sub-Chassis",
+        OtherChassisBoard = 0x19 => "Other Chassis Board",
+        DiskDriveBay = 0x1A => "Disk Drive Bay",
+        PeripheralBay2 = 0x1B => "Peripheral Bay",
+        DeviceBay = 0x1C => "Device Bay",
+        Fan = 0x1D => "Fan Device",
+        /// A cooling unit or cooling domain, usually used for redundant fans.
+        CoolingUnit = 0x1E => "Cooling Unit",
+        CableOrInterconnect = 0x1F => "Cable/Interconnect",
+        MemoryDevice = 0x20 => "Memory Device",
+        SystemManagementSoftware = 0x21 => "System Management Software",
+        SystemFirmware = 0x22 => "System Firmware",
+        OperatingSystem = 0x23 => "Operating System",
+        SystemBus = 0x24 => "System Bus",
+        Group = 0x25 => "Group",
+        RemoteManagementCommunicationDevice = 0x26 => "Remote Management Communication Device",
+        ExternalEnvironment = 0x27 => "External Environment",
+        Battery = 0x28 => "Battery",
+        ProcessingBlade = 0x29 => "Processing Blade",
+        ConnectivitySwitch = 0x2A => "Connectivity Switch",
+        ProcessorMemoryModule = 0x2B => "Processor/Memory Module",
+        IoModule = 0x2C => "I/O Module",
+        ProcessorIoModule = 0x2D => "Processor/IO Module",
+        ManagementControllerFirmware = 0x2E => "Management Controller Firmware",
+        IpmiChannel = 0x2F => "IPMI Channel",
+        PciBus = 0x30 => "PCI Bus",
+        PciExpressBus = 0x31 => "PCI Express Bus",
+        ScsiBus = 0x32 => "SCSI Bus (parallel)",
+        SataSasBus = 0x33 => "SATA/SAS Bus",
+        FrontSideBus = 0x34 => "Processor/Front-side Bus",
+        RealTimeClock = 0x35 => "Real Time Clock",
+        AirInlet = 0x37 => "Air Inlet",
+        /// Air inlet, as used by DCMI. Equivalent to [`EntityId::AirInlet`].
+        DcmiAirInlet = 0x40 => "Air Inlet",
+        /// Processor, as used by DCMI. Equivalent to [`EntityId::Processor`].
+        DcmiProcessor = 0x41 => "Processor",
+        /// Baseboard, as used by DCMI. Equivalent to [`EntityId::SystemBoard`].
+        DcmiBaseboard = 0x42 => "Baseboard",
+        [0x36 | 0x38..=0x3F | 0x43..=0x8F],
+        [0x90..=0xAF],
+        [0xB0..=0xCF],
+        [0xD0..=0xFF],
+    }
+}
+
+impl EntityId {
+    /// Get the entity ID that this entity ID is equivalent to.
+    ///
+    /// The DCMI entity IDs are mapped to their IPMI equivalents, so that
+    /// entities of the same kind can be compared.
+    pub fn canonical(&self) -> Self {
+        match self {
+            EntityId::DcmiAirInlet => EntityId::AirInlet,
+            EntityId::DcmiProcessor => EntityId::Processor,
+            EntityId::DcmiBaseboard => EntityId::SystemBoard,
+            EntityId::PeripheralBay2 => EntityId::PeripheralBay,
+            other => *other,
+        }
+    }
+}
+
+#[test]
+fn entity_id_round_trip() {
+    for value in 0..=0xFF {
+        assert_eq!(value, u8::from(EntityId::from(value)));
+    }
+
+    assert_eq!(EntityId::PowerSupply, EntityId::from(0x0A));
+    assert_eq!(EntityId::ChassisSpecific(0x90), EntityId::from(0x90));
+    assert_eq!(EntityId::BoardSetSpecific(0xC0), EntityId::from(0xC0));
+    assert_eq!(EntityId::Oem(0xD0), EntityId::from(0xD0));
+    assert_eq!(EntityId::Reserved(0x36), EntityId::from(0x36));
+    assert_eq!(EntityId::Processor, EntityId::DcmiProcessor.canonical());
+    assert_eq!("Power Supply", EntityId::PowerSupply.to_string());
+}
@@ -0,0 +1,311 @@
+use std::collections::BTreeMap;
+
+use super::record::{
+    Entity, EntityAssociationRecord, EntityDevice, EntityInstance, IdentifiableSensor,
+    InstancedSensor, Record, RecordContents, SensorKey, SharedSensor,
+};
+use super::EntityId;
+
+/// An entity in an [`EntityTree`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct EntityNode {
+    pub entity: Entity,
+    /// The entities that contain this entity.
+    pub parents: Vec<Entity>,
+    /// The entities contained by this entity.
+    pub children: Vec<Entity>,
+    /// The sensors that monitor this entity.
+    pub sensors: Vec<SensorKey>,
+}
+
+impl EntityNode {
+    fn new(entity: Entity) -> Self {
+        Self {
+            entity,
+            parents: Vec::new(),
+            children: Vec::new(),
+            sensors: Vec::new(),
+        }
+    }
+}
+
+/// The containment hierarchy of the entities described by the records in
+/// an SDR repository, and the sensors that monitor those entities.
+///
+/// The hierarchy is described by Entity Association and Device-relative
+/// Entity Association records. An entity may be contained by more than
+/// one entity, for instance a power supply that is part of both the
+/// system chassis and a power unit redundancy group.
+#[derive(Debug, Clone, Default)]
+pub struct EntityTree {
+    nodes: BTreeMap<Entity, EntityNode>,
+}
+
+impl EntityTree {
+    /// Build an entity tree from the `records` of the SDR repository of
+    /// the BMC.
+    ///
+    /// Device-relative entity instances in Entity Association records are
+    /// relative to the BMC.
+    pub fn new<'a>(records: impl IntoIterator<Item = &'a Record>) -> Self {
+        let bmc = EntityDevice {
+            address: 0x20 >> 1,
+            channel: 0,
+        };
+
+        Self::for_device(records, bmc)
+    }
+
+    /// Build an entity tree from `records` provided by the management
+    /// controller `device`, such as its device SDRs.
+    ///
+    /// Device-relative entity instances in Entity Association records are
+    /// relative to `device`.
+    pub fn for_device<'a>(
+        records: impl IntoIterator<Item = &'a Record>,
+        device: EntityDevice,
+    ) -> Self {
+        let mut tree = Self::default();
+
+        for record in records {
+            match &record.contents {
+                RecordContents::EntityAssociation(association) => {
+                    let association = association.clone().relative_to(device);
+                    tree.add_association(&association);
+                }
+                RecordContents::DeviceRelativeEntityAssociation(association) => {
+                    tree.add_association(association);
+                }
+                RecordContents::FullSensor(full) => {
+                    tree.add_sensor(full.entity_id(), full.key_data(), full.entity_instance());
+                }
+                RecordContents::CompactSensor(compact) => {
+                    tree.add_shared_sensors(compact.entity_id(), compact.shared_sensors());
+                }
+                RecordContents::EventOnlySensor(event) => {
+                    tree.add_shared_sensors(event.entity_id, event.shared_sensors());
+                }
+                _ => {}
+            }
+        }
+
+        tree
+    }
+
+    fn node(&mut self, entity: Entity) -> &mut EntityNode {
+        self.nodes
+            .entry(entity)
+            .or_insert_with(|| EntityNode::new(entity))
+    }
+
+    fn add_association(&mut self, association: &EntityAssociationRecord) {
+        for contained in association.contained_entities() {
+            self.add_child(association.container, contained);
+        }
+    }
+
+    fn add_child(&mut self, parent: Entity, child: Entity) {
+        let parent_node = self.node(parent);
+        if !parent_node.children.contains(&child) {
+            parent_node.children.push(child);
+        }
+
+        let child_node = self.node(child);
+        if !child_node.parents.contains(&parent) {
+            child_node.parents.push(parent);
+        }
+    }
+
+    fn add_shared_sensors(&mut self, entity_id: EntityId, sensors: Vec<SharedSensor>) {
+        for sensor in sensors {
+            self.add_sensor(entity_id, &sensor.key, &sensor.entity_instance);
+        }
+    }
+
+    fn add_sensor(&mut self, entity_id: EntityId, key: &SensorKey, instance: &EntityInstance) {
+        let entity = Entity::device_relative(
+            entity_id,
+            u8::from(*instance),
+            u8::from(key.owner_id) >> 1,
+            key.owner_channel.value(),
+        );
+
+        self.node(entity).sensors.push(*key);
+    }
+
+    /// Get the node for `entity`.
+    pub fn get(&self, entity: &Entity) -> Option<&EntityNode> {
+        self.nodes.get(entity)
+    }
+
+    /// All entities in this tree.
+    pub fn nodes(&self) -> impl Iterator<Item = &EntityNode> {
+        self.nodes.values()
+    }
+
+    /// The entities that are not contained by any other entity.
+    pub fn roots(&self) -> impl Iterator<Item = &EntityNode> {
+        self.nodes().filter(|v| v.parents.is_empty())
+    }
+
+    /// The entities directly contained by `entity`.
+    pub fn children(&self, entity: &Entity) -> impl Iterator<Item = &EntityNode> {
+        self.get(entity)
+            .into_iter()
+            .flat_map(|v| v.children.iter())
+            .filter_map(|v| self.get(v))
+    }
+
+    /// All entities contained by `entity`, directly or indirectly, in
+    /// depth-first order.
+    pub fn descendants(&self, entity: &Entity) -> Vec<&EntityNode> {
+        let mut descendants: Vec<&EntityNode> = Vec::new();
+        let mut stack: Vec<_> = self.children(entity).collect();
+        stack.reverse();
+
+        while let Some(node) = stack.pop() {
+            // Malformed repositories may describe cycles, or the same
+            // entity in several containers.
+            if node.entity == *entity || descendants.iter().any(|v| v.entity == node.entity) {
+                continue;
+            }
+
+            descendants.push(node);
+
+            let mut children: Vec<_> = self.children(&node.entity).collect();
+            children.reverse();
+            stack.extend(children);
+        }
+
+        descendants
+    }
+
+    /// The sensors that monitor `entity` or any of the entities that
+    /// it contains.
+    pub fn sensors_within(&self, entity: &Entity) -> Vec<SensorKey> {
+        self.get(entity)
+            .into_iter()
+            .chain(self.descendants(entity))
+            .flat_map(|v| v.sensors.iter().copied())
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::sdr::EntityId;
+
+    fn record(ty: u8, data: &[u8]) -> Record {
+        let mut bytes = vec![0x01, 0x00, 0x51, ty, data.len() as u8];
+        bytes.extend_from_slice(data);
+        Record::parse(&bytes).unwrap()
+    }
+
+    fn compact_sensor(number: u8, entity_id: u8, entity_instance: u8) -> Record {
+        let mut data = vec![0x20, 0x00, number, entity_id, entity_instance];
+        data.extend_from_slice(&[0; 21]);
+        data.push(0xC1);
+        data.push(b'A');
+        record(0x02, &data)
+    }
+
+    #[test]
+    fn build_tree() {
+        let records = [
+            // System board 1 contains processor 1 and processor 2.
+            record(
+                0x08,
+                &[
+                    0x07, 0x01, 0x00, 0x03, 0x01, 0x03, 0x02, 0x00, 0x00, 0x00, 0x00,
+                ],
+            ),
+            // Power unit 1 contains power supplies 1 and 2.
+            record(
+                0x08,
+                &[
+                    0x13, 0x81, 0x80, 0x0A, 0x01, 0x0A, 0x02, 0x00, 0x00, 0x00, 0x00,
+                ],
+            ),
+            // System chassis 1 contains system board 1.
+            record(
+                0x08,
+                &[
+                    0x17, 0x01, 0x00, 0x07, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                ],
+            ),
+            compact_sensor(0x10, 0x03, 0x01),
+            compact_sensor(0x11, 0x03, 0x02),
+            compact_sensor(0x12, 0x0A, 0x02),
+            compact_sensor(0x13, 0x07, 0x01),
+        ];
+
+        let tree = EntityTree::new(&records);
+
+        let chassis = Entity::new(EntityId::SystemChassis, 0x01);
+        let board = Entity::new(EntityId::SystemBoard, 0x01);
+        let power_unit = Entity::new(EntityId::PowerUnit, 0x01);
+
+        let roots: Vec<_> = tree.roots().map(|v| v.entity).collect();
+        assert_eq!(vec![power_unit, chassis], roots);
+
+        let children: Vec<_> = tree.children(&board).map(|v| v.entity).collect();
+        assert_eq!(
+            vec![
+                Entity::new(EntityId::Processor, 0x01),
+                Entity::new(EntityId::Processor, 0x02)
+            ],
+            children
+        );
+
+        assert_eq!(3, tree.descendants(&chassis).len());
+        assert_eq!(vec![board], tree.get(&children[0]).unwrap().parents);
+
+        let sensors: Vec<_> = tree
+            .sensors_within(&chassis)
+            .iter()
+            .map(|v| v.sensor_number.get())
+            .collect();
+        assert_eq!(vec![0x13, 0x10, 0x11], sensors);
+
+        let sensors = tree.sensors_within(&power_unit);
+        assert_eq!(1, sensors.len());
+        assert_eq!(0x12, sensors[0].sensor_number.get());
+    }
+
+    #[test]
+    fn device_relative_instances() {
+        let records = [
+            // System board 1 contains processor 0x60, relative to the BMC.
+            record(
+                0x08,
+                &[
+                    0x07, 0x01, 0x00, 0x03, 0x60, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                ],
+            ),
+            // System board 2 contains processor 0x61, relative to 0x41 on channel 1.
+            record(
+                0x09,
+                &[
+                    0x07, 0x02, 0x20, 0x00, 0x00, 0x82, 0x10, 0x03, 0x61, 0x00, 0x00, 0x00, 0x00,
+                    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                ],
+            ),
+            compact_sensor(0x10, 0x03, 0x60),
+        ];
+
+        let tree = EntityTree::new(&records);
+
+        let board = Entity::new(EntityId::SystemBoard, 0x01);
+        let sensors = tree.sensors_within(&board);
+        assert_eq!(1, sensors.len());
+        assert_eq!(0x10, sensors[0].sensor_number.get());
+
+        let board = Entity::new(EntityId::SystemBoard, 0x02);
+        let children: Vec<_> = tree.children(&board).map(|v| v.entity).collect();
+        assert_eq!(
+            vec![Entity::device_relative(EntityId::Processor, 0x61, 0x41, 1)],
+            children
+        );
+    }
+}
@@ -545,6 +545,28 @@ pub fn decode_event(
     }
 }
 
+/// Get the human-readable description of an event offset.
+///
+/// # Arguments
+/// * `event_type` - The event/reading type code
+/// * `sensor_type` - The sensor type
+/// * `offset` - The event offset (bits \[3:0\])
+pub fn event_offset_description(
+    event_type: u8,
+    sensor_type: SensorType,
+    offset: u8,
+) -> Option<&'static str> {
+    let event_type_code = event_type & 0x7F;
+
+    if event_type_code == 0x6F {
+        sensor_event_offset_desc(sensor_type, offset)
+    } else if (0x01..=0x0C).contains(&event_type_code) {
+        generic_event_offset_desc(event_type_code, offset)
+    } else {
+        None
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -114,3 +114,16 @@ event_reading_code!(
     RedundancyState => 0x0B,
     AcpiDevicePowerState => 0x0C,
 );
+
+impl From<EventReadingTypeCodes> for u8 {
+    fn from(value: EventReadingTypeCodes) -> Self {
+        match value {
+            EventReadingTypeCodes::Unspecified => 0x00,
+            EventReadingTypeCodes::Threshold => 0x01,
+            EventReadingTypeCodes::DiscreteGeneric(v) => v,
+            EventReadingTypeCodes::SensorSpecific => 0x6F,
+            EventReadingTypeCodes::Oem(v) => v,
+            EventReadingTypeCodes::Reserved(v) => v,
+        }
+    }
+}
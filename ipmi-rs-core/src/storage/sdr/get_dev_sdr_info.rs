@@ -30,6 +30,7 @@ impl FromOpValue for NumberOfSdrs {
     }
 }
 
+/// Reference: IPMI 2.0 Specification, Section 35.2, Table 35-2
 #[derive(Debug, Clone)]
 pub struct DeviceSdrInfo<T> {
     pub operation_value: T,
@@ -38,6 +39,10 @@ pub struct DeviceSdrInfo<T> {
     pub lun_1_has_sensors: bool,
     pub lun_2_has_sensors: bool,
     pub lun_3_has_sensors: bool,
+    /// The sensor population change indicator, a timestamp or counter that
+    /// changes whenever the sensor population of the device changes.
+    ///
+    /// Only present for devices with a dynamic sensor population.
     pub sensor_population_epoch: Option<u32>,
 }
 
@@ -108,7 +113,7 @@ impl From<GetDeviceSdrInfo<SdrCount>> for Message {
 
 impl From<GetDeviceSdrInfo<SensorCount>> for Message {
     fn from(_: GetDeviceSdrInfo<SensorCount>) -> Self {
-        Message::new_request(NetFn::SensorEvent, 0x20, vec![0x01])
+        Message::new_request(NetFn::SensorEvent, 0x20, vec![0x00])
     }
 }
 
@@ -131,3 +136,33 @@ impl IpmiCommand for GetDeviceSdrInfo<SensorCount> {
         DeviceSdrInfo::parse(data).ok_or(NotEnoughData)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn operation() {
+        let sdrs: Message = GetDeviceSdrInfo::new(SdrCount).into();
+        let sensors: Message = GetDeviceSdrInfo::new(SensorCount).into();
+
+        assert_eq!(&[0x01], sdrs.data());
+        assert_eq!(&[0x00], sensors.data());
+    }
+
+    #[test]
+    fn parse_dynamic_population() {
+        let info = GetDeviceSdrInfo::<SdrCount>::parse_success_response(&[
+            0x0A, 0x85, 0x78, 0x56, 0x34, 0x12,
+        ])
+        .unwrap();
+
+        assert_eq!(NumberOfSdrs(10), info.operation_value);
+        assert!(info.lun_has_sensors(LogicalUnit::Zero));
+        assert!(!info.lun_has_sensors(LogicalUnit::One));
+        assert!(info.lun_has_sensors(LogicalUnit::Two));
+        assert_eq!(Some(0x1234_5678), info.sensor_population_epoch);
+
+        assert!(GetDeviceSdrInfo::<SdrCount>::parse_success_response(&[0x0A, 0x85]).is_err());
+    }
+}
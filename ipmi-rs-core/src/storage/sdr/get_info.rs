@@ -74,6 +74,10 @@ pub struct RepositoryInfo {
 
 impl RepositoryInfo {
     pub fn parse(v: &[u8]) -> Option<Self> {
+        if v.len() < 14 {
+            return None;
+        }
+
         let version_minor = (v[0] & 0xF0) >> 4;
         let version_major = v[0] & 0x0F;
         let record_count = u16::from_le_bytes([v[1], v[2]]);
@@ -82,7 +86,7 @@ impl RepositoryInfo {
         let most_recent_erase = Timestamp::from(u32::from_le_bytes([v[9], v[10], v[11], v[12]]));
         let overflow = (v[13] & 0x80) == 0x80;
 
-        let modality = v[13] & 0x60 >> 5;
+        let modality = (v[13] & 0x60) >> 5;
         let modality = match modality {
             0b00 => Operation::ModalityUnspecified,
             0b01 => Operation::NonModalUpdate,
@@ -119,4 +123,46 @@ impl RepositoryInfo {
             supported_ops: ops,
         })
     }
+
+    /// Whether the SDR repository supports `operation`.
+    ///
+    /// Repositories that support both modal and non-modal updates support
+    /// [`Operation::ModalUpdate`] and [`Operation::NonModalUpdate`].
+    pub fn supports(&self, operation: Operation) -> bool {
+        self.supported_ops.iter().any(|op| {
+            *op == operation
+                || (*op == Operation::NonModalAndModalUpdate
+                    && matches!(
+                        operation,
+                        Operation::ModalUpdate | Operation::NonModalUpdate
+                    ))
+        })
+    }
+
+    /// Whether the SDR repository must be put in update mode, using
+    /// [`EnterSdrRepositoryUpdateMode`](super::EnterSdrRepositoryUpdateMode),
+    /// before it can be modified.
+    pub fn requires_update_mode(&self) -> bool {
+        self.supported_ops.contains(&Operation::ModalUpdate)
+    }
+}
+
+#[test]
+fn parse_repository_info() {
+    let data = [
+        0x51, 0x2A, 0x00, 0x00, 0x10, 0x00, 0x00, 0x00, 0x65, 0x00, 0x00, 0x00, 0x64, 0x4E,
+    ];
+
+    let info = RepositoryInfo::parse(&data).unwrap();
+    assert_eq!(42, info.record_count);
+    assert_eq!(FreeSpace::AtLeast { bytes: 0x1000 }, info.free_space);
+    assert!(info.requires_update_mode());
+    assert!(info.supports(Operation::ModalUpdate));
+    assert!(!info.supports(Operation::NonModalUpdate));
+    assert!(info.supports(Operation::Reserve));
+    assert!(info.supports(Operation::PartialAdd));
+    assert!(info.supports(Operation::Delete));
+    assert!(!info.supports(Operation::GetAllocInfo));
+
+    assert!(RepositoryInfo::parse(&data[..13]).is_none());
 }
@@ -2,17 +2,40 @@ use std::num::NonZeroU16;
 
 use nonmax::NonMaxU8;
 
-use crate::connection::{IpmiCommand, Message, NetFn};
+use crate::connection::{IpmiCommand, Message, NetFn, NotEnoughData};
 
 use super::{Record, RecordId, RecordParseError};
 
+/// The set of SDRs that an SDR command operates on.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum SdrSource {
+    /// The SDR repository, accessed using the Get SDR and Reserve SDR
+    /// Repository commands.
+    ///
+    /// Reference: IPMI 2.0 Specification, Section 33
+    #[default]
+    Repository,
+    /// The device SDRs of a management controller, accessed using the Get
+    /// Device SDR and Reserve Device SDR Repository commands.
+    ///
+    /// Device SDRs are read per LUN: a command returns the SDRs of the
+    /// LUN that it is addressed to.
+    ///
+    /// Reference: IPMI 2.0 Specification, Section 35
+    Device,
+}
+
 /// Get a device SDR.
 ///
 /// This command must be used in accordance with the IPMI spec, i.e.
 /// all SDRs must be obtained sequentially. It is recommended that you use
 /// the function `Ipmi::sdrs` in the `ipmi-rs` function for this.
+///
+/// By default, this command reads from the SDR repository. Use
+/// [`GetDeviceSdr::with_source`] to read device SDRs instead.
 #[derive(Debug, Clone, Copy)]
 pub struct GetDeviceSdr {
+    source: SdrSource,
     reservation_id: Option<NonZeroU16>,
     record_id: RecordId,
     offset: u8,
@@ -22,6 +45,7 @@ pub struct GetDeviceSdr {
 impl GetDeviceSdr {
     pub fn new(reservation_id: Option<NonZeroU16>, record_id: RecordId) -> Self {
         Self {
+            source: SdrSource::Repository,
             reservation_id,
             record_id,
             // Always read all bytes
@@ -29,6 +53,34 @@ impl GetDeviceSdr {
             bytes_to_read: None,
         }
     }
+
+    /// Read the record from `source` instead of the SDR repository.
+    pub fn with_source(mut self, source: SdrSource) -> Self {
+        self.source = source;
+        self
+    }
+
+    /// Create a command that reads `bytes_to_read` bytes of the record with
+    /// ID `record_id`, starting at `offset`.
+    ///
+    /// A reservation ID obtained with
+    /// [`ReserveSdrRepository`](super::ReserveSdrRepository), or
+    /// [`ReserveDeviceSdrRepository`](super::ReserveDeviceSdrRepository) for
+    /// device SDRs, is required for reads with an offset other than 0.
+    pub fn partial(
+        reservation_id: Option<NonZeroU16>,
+        record_id: RecordId,
+        offset: u8,
+        bytes_to_read: NonMaxU8,
+    ) -> PartialGetDeviceSdr {
+        PartialGetDeviceSdr(Self {
+            source: SdrSource::Repository,
+            reservation_id,
+            record_id,
+            offset,
+            bytes_to_read: Some(bytes_to_read),
+        })
+    }
 }
 
 impl From<GetDeviceSdr> for Message {
@@ -47,7 +99,10 @@ impl From<GetDeviceSdr> for Message {
         data[4] = value.offset;
         data[5] = value.bytes_to_read.map(|v| v.get()).unwrap_or(0xFF);
 
-        Message::new_request(NetFn::Storage, 0x23, data)
+        match value.source {
+            SdrSource::Repository => Message::new_request(NetFn::Storage, 0x23, data),
+            SdrSource::Device => Message::new_request(NetFn::SensorEvent, 0x21, data),
+        }
     }
 }
 
@@ -69,16 +124,65 @@ impl IpmiCommand for GetDeviceSdr {
     }
 }
 
+/// A Get SDR command that reads part of a record.
+///
+/// Created using [`GetDeviceSdr::partial`].
+#[derive(Debug, Clone, Copy)]
+pub struct PartialGetDeviceSdr(GetDeviceSdr);
+
+impl PartialGetDeviceSdr {
+    /// Read the record from `source` instead of the SDR repository.
+    pub fn with_source(self, source: SdrSource) -> Self {
+        Self(self.0.with_source(source))
+    }
+}
+
+impl From<PartialGetDeviceSdr> for Message {
+    fn from(value: PartialGetDeviceSdr) -> Self {
+        value.0.into()
+    }
+}
+
+impl IpmiCommand for PartialGetDeviceSdr {
+    type Output = PartialRecord;
+
+    type Error = NotEnoughData;
+
+    fn parse_success_response(data: &[u8]) -> Result<Self::Output, Self::Error> {
+        if data.len() < 2 {
+            return Err(NotEnoughData);
+        }
+
+        Ok(PartialRecord {
+            next_entry: RecordId::new_raw(u16::from_le_bytes([data[0], data[1]])),
+            data: data[2..].to_vec(),
+        })
+    }
+}
+
+/// Part of the data of a record.
+#[derive(Debug, Clone)]
+pub struct PartialRecord {
+    pub next_entry: RecordId,
+    pub data: Vec<u8>,
+}
+
 #[derive(Debug, Clone)]
 pub struct RecordInfo {
     pub next_entry: RecordId,
     pub record: Record,
+    /// The raw data of `record`, including its header.
+    pub data: Vec<u8>,
 }
 
 impl RecordInfo {
     pub fn parse(data: &[u8]) -> Result<Self, RecordParseError> {
         let next_entry = RecordId::new_raw(u16::from_le_bytes([data[0], data[1]]));
         let data = &data[2..];
-        Record::parse(data).map(|record| Self { next_entry, record })
+        Record::parse(data).map(|record| Self {
+            next_entry,
+            record,
+            data: data.to_vec(),
+        })
     }
 }
@@ -0,0 +1,61 @@
+//! Run Initialization Agent Command
+//!
+//! Reference: IPMI 2.0 Specification, Section 33.21 "Run Initialization Agent Command"
+
+use crate::connection::{IpmiCommand, Message, NetFn, NotEnoughData};
+
+/// The progress of the initialization agent.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum InitializationProgress {
+    /// Initialization is in progress
+    InProgress,
+    /// Initialization has completed
+    Completed,
+}
+
+/// Run Initialization Agent command.
+///
+/// The initialization agent configures the sensors in the system, such as
+/// their thresholds and event generation, according to the records in the
+/// SDR repository. It is usually run after the SDR repository has been
+/// modified.
+///
+/// Reference: IPMI 2.0 Specification, Section 33.21, Table 33-14
+pub struct RunInitializationAgent {
+    run: bool,
+}
+
+impl RunInitializationAgent {
+    /// Create a new RunInitializationAgent command that runs the initialization agent.
+    pub fn run() -> Self {
+        Self { run: true }
+    }
+
+    /// Create a new RunInitializationAgent command that gets the initialization status.
+    pub fn get_status() -> Self {
+        Self { run: false }
+    }
+}
+
+impl From<RunInitializationAgent> for Message {
+    fn from(value: RunInitializationAgent) -> Self {
+        Message::new_request(NetFn::Storage, 0x2C, vec![value.run as u8])
+    }
+}
+
+impl IpmiCommand for RunInitializationAgent {
+    type Output = InitializationProgress;
+    type Error = NotEnoughData;
+
+    fn parse_success_response(data: &[u8]) -> Result<Self::Output, Self::Error> {
+        if data.is_empty() {
+            return Err(NotEnoughData);
+        }
+
+        Ok(if data[0] & 0x01 == 0x01 {
+            InitializationProgress::Completed
+        } else {
+            InitializationProgress::InProgress
+        })
+    }
+}
@@ -5,7 +5,36 @@ mod get_sdr;
 pub use get_sdr::{GetDeviceSdr, RecordInfo as SdrRecordInfo, *};
 
 pub mod record;
-pub use record::{ParseError as RecordParseError, Record};
+pub use record::{EncodeError as RecordEncodeError, ParseError as RecordParseError, Record};
+
+mod entity_tree;
+pub use entity_tree::{EntityNode, EntityTree};
+
+mod cache;
+pub use cache::{SdrCache, SdrCacheError};
+
+pub mod dump;
+
+mod reserve;
+pub use reserve::{ReserveDeviceSdrRepository, ReserveSdrRepository};
+
+mod add;
+pub use add::{AddSdr, PartialAddSdr};
+
+mod delete;
+pub use delete::DeleteSdr;
+
+mod clear;
+pub use clear::ClearSdrRepository;
+
+mod time;
+pub use time::{GetSdrRepositoryTime, SetSdrRepositoryTime};
+
+mod update_mode;
+pub use update_mode::{EnterSdrRepositoryUpdateMode, ExitSdrRepositoryUpdateMode};
+
+mod init_agent;
+pub use init_agent::{InitializationProgress, RunInitializationAgent};
 
 mod get_info;
 pub use get_info::{
@@ -21,8 +50,11 @@ pub mod event_reading_type_code;
 mod sensor_type;
 pub use sensor_type::SensorType;
 
+mod entity_id;
+pub use entity_id::EntityId;
+
 mod event_offset;
-pub use event_offset::decode_event;
+pub use event_offset::{decode_event, event_offset_description};
 
 mod event_data;
 pub use event_data::{EventData, EventData2Type, EventData3Type};
@@ -30,6 +62,9 @@ pub use event_data::{EventData, EventData2Type, EventData3Type};
 mod units;
 pub use units::Unit;
 
+mod quantity;
+pub use quantity::{Dimension, Quantity, SiQuantity};
+
 #[derive(Debug, Copy, Clone, PartialEq)]
 pub struct RecordId(u16);
 
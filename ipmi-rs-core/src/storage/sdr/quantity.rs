@@ -0,0 +1,456 @@
+use super::{
+    record::{ModifierUnit, SensorUnits},
+    units::IsPlural,
+    Unit,
+};
+
+/// The dimension of a quantity, expressed as the exponents of the
+/// SI base units.
+///
+/// Information is not an SI quantity, but is given its own dimension
+/// (with the bit as base unit) so that data sizes and rates can be
+/// compared.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Hash)]
+pub struct Dimension {
+    pub kilogram: i8,
+    pub meter: i8,
+    pub second: i8,
+    pub ampere: i8,
+    pub kelvin: i8,
+    pub mole: i8,
+    pub candela: i8,
+    pub bit: i8,
+}
+
+impl Dimension {
+    /// The dimension of a dimensionless quantity.
+    pub const DIMENSIONLESS: Self = Self::new([0, 0, 0, 0, 0, 0, 0, 0]);
+
+    const fn new([kilogram, meter, second, ampere, kelvin, mole, candela, bit]: [i8; 8]) -> Self {
+        Self {
+            kilogram,
+            meter,
+            second,
+            ampere,
+            kelvin,
+            mole,
+            candela,
+            bit,
+        }
+    }
+
+    fn exponents(&self) -> [(&'static str, i8); 8] {
+        [
+            ("kg", self.kilogram),
+            ("m", self.meter),
+            ("s", self.second),
+            ("A", self.ampere),
+            ("K", self.kelvin),
+            ("mol", self.mole),
+            ("cd", self.candela),
+            ("bit", self.bit),
+        ]
+    }
+
+    fn combine(self, other: Self, sign: i8) -> Self {
+        let [a, b] = [self, other].map(|v| v.exponents().map(|(_, e)| e));
+        let mut exponents = [0; 8];
+        for (i, exponent) in exponents.iter_mut().enumerate() {
+            *exponent = a[i] + sign * b[i];
+        }
+        Self::new(exponents)
+    }
+
+    /// Whether this dimension is dimensionless.
+    pub fn is_dimensionless(&self) -> bool {
+        self == &Self::DIMENSIONLESS
+    }
+}
+
+impl core::ops::Mul for Dimension {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        self.combine(rhs, 1)
+    }
+}
+
+impl core::ops::Div for Dimension {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self {
+        self.combine(rhs, -1)
+    }
+}
+
+impl core::fmt::Display for Dimension {
+    /// Formats the dimension as a product of base units, such as
+    /// `kg m^2 s^-3`. Dimensionless quantities are formatted as `1`.
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        if self.is_dimensionless() {
+            return write!(f, "1");
+        }
+
+        let mut first = true;
+        for (unit, exponent) in self.exponents() {
+            if exponent == 0 {
+                continue;
+            }
+
+            if !first {
+                write!(f, " ")?;
+            }
+            first = false;
+
+            if exponent == 1 {
+                write!(f, "{unit}")?;
+            } else {
+                write!(f, "{unit}^{exponent}")?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// The conversion of a unit to SI base units: `si = value * scale + offset`.
+struct SiUnit {
+    scale: f64,
+    offset: f64,
+    dimension: Dimension,
+}
+
+impl SiUnit {
+    const fn new(scale: f64, dimension: [i8; 8]) -> Self {
+        Self {
+            scale,
+            offset: 0.0,
+            dimension: Dimension::new(dimension),
+        }
+    }
+}
+
+impl Unit {
+    /// The conversion of this unit to SI base units, if it has one.
+    ///
+    /// Revolutions and cycles are treated as dimensionless counts, so that
+    /// revolutions per minute can be compared with hertz.
+    fn si(&self) -> Option<SiUnit> {
+        // Exponents of kg, m, s, A, K, mol, cd and bit.
+        const NONE: [i8; 8] = [0, 0, 0, 0, 0, 0, 0, 0];
+        const KELVIN: [i8; 8] = [0, 0, 0, 0, 1, 0, 0, 0];
+        const SECOND: [i8; 8] = [0, 0, 1, 0, 0, 0, 0, 0];
+        const HERTZ: [i8; 8] = [0, 0, -1, 0, 0, 0, 0, 0];
+        const METER: [i8; 8] = [0, 1, 0, 0, 0, 0, 0, 0];
+        const VOLUME: [i8; 8] = [0, 3, 0, 0, 0, 0, 0, 0];
+        const KILOGRAM: [i8; 8] = [1, 0, 0, 0, 0, 0, 0, 0];
+        const WATT: [i8; 8] = [1, 2, -3, 0, 0, 0, 0, 0];
+        const JOULE: [i8; 8] = [1, 2, -2, 0, 0, 0, 0, 0];
+        const PASCAL: [i8; 8] = [1, -1, -2, 0, 0, 0, 0, 0];
+        const LUMINANCE: [i8; 8] = [0, -2, 0, 0, 0, 0, 1, 0];
+        const HENRY: [i8; 8] = [1, 2, -2, -2, 0, 0, 0, 0];
+        const FARAD: [i8; 8] = [-1, -2, 4, 2, 0, 0, 0, 0];
+        const DOSE: [i8; 8] = [0, 2, -2, 0, 0, 0, 0, 0];
+        const BIT: [i8; 8] = [0, 0, 0, 0, 0, 0, 0, 1];
+
+        let unit = match self {
+            Unit::DegreesCelsius => SiUnit {
+                offset: 273.15,
+                ..SiUnit::new(1.0, KELVIN)
+            },
+            Unit::DegreesFahrenheit => SiUnit {
+                offset: 459.67 * 5.0 / 9.0,
+                ..SiUnit::new(5.0 / 9.0, KELVIN)
+            },
+            Unit::DegreesKelvin | Unit::ColorTemperatureDegreesKelvin => SiUnit::new(1.0, KELVIN),
+            Unit::Volt => SiUnit::new(1.0, [1, 2, -3, -1, 0, 0, 0, 0]),
+            Unit::Amp => SiUnit::new(1.0, [0, 0, 0, 1, 0, 0, 0, 0]),
+            Unit::Watt | Unit::VoltAmpere => SiUnit::new(1.0, WATT),
+            Unit::Joule => SiUnit::new(1.0, JOULE),
+            Unit::Coulomb => SiUnit::new(1.0, [0, 0, 1, 1, 0, 0, 0, 0]),
+            Unit::Nit | Unit::Lux => SiUnit::new(1.0, LUMINANCE),
+            Unit::Lumen | Unit::Candela => SiUnit::new(1.0, [0, 0, 0, 0, 0, 0, 1, 0]),
+            Unit::KiloPascal => SiUnit::new(1e3, PASCAL),
+            Unit::PoundsPerSquareInch => SiUnit::new(6_894.757_293_168, PASCAL),
+            Unit::Newton => SiUnit::new(1.0, [1, 1, -2, 0, 0, 0, 0, 0]),
+            Unit::CubicFeetPerMinute => {
+                SiUnit::new(0.028_316_846_592 / 60.0, [0, 3, -1, 0, 0, 0, 0, 0])
+            }
+            Unit::RevolutionsPerMinute => SiUnit::new(1.0 / 60.0, HERTZ),
+            Unit::Hertz | Unit::Becquerel => SiUnit::new(1.0, HERTZ),
+            Unit::Microsecond => SiUnit::new(1e-6, SECOND),
+            Unit::Millisecond => SiUnit::new(1e-3, SECOND),
+            Unit::Second => SiUnit::new(1.0, SECOND),
+            Unit::Minute => SiUnit::new(60.0, SECOND),
+            Unit::Hour => SiUnit::new(3_600.0, SECOND),
+            Unit::Day => SiUnit::new(86_400.0, SECOND),
+            Unit::Week => SiUnit::new(604_800.0, SECOND),
+            Unit::Mil => SiUnit::new(2.54e-5, METER),
+            Unit::Inch => SiUnit::new(0.0254, METER),
+            Unit::Foot => SiUnit::new(0.3048, METER),
+            Unit::Millimeter => SiUnit::new(1e-3, METER),
+            Unit::Centimeter => SiUnit::new(1e-2, METER),
+            Unit::Meter => SiUnit::new(1.0, METER),
+            Unit::CubicInch => SiUnit::new(1.638_706_4e-5, VOLUME),
+            Unit::CubicFoot => SiUnit::new(0.028_316_846_592, VOLUME),
+            Unit::CubicCentimeter => SiUnit::new(1e-6, VOLUME),
+            Unit::CubicMeter => SiUnit::new(1.0, VOLUME),
+            Unit::Liter => SiUnit::new(1e-3, VOLUME),
+            Unit::FluidOunce => SiUnit::new(2.957_352_956_25e-5, VOLUME),
+            Unit::Radian | Unit::Steradian | Unit::Revolution | Unit::Cycle => {
+                SiUnit::new(1.0, NONE)
+            }
+            Unit::Gravity => SiUnit::new(9.806_65, [0, 1, -2, 0, 0, 0, 0, 0]),
+            Unit::Gram => SiUnit::new(1e-3, KILOGRAM),
+            Unit::Ounce => SiUnit::new(0.028_349_523_125, KILOGRAM),
+            Unit::Pound => SiUnit::new(0.453_592_37, KILOGRAM),
+            Unit::FootPound => SiUnit::new(1.355_817_948_331_400_4, JOULE),
+            Unit::OunceInch => SiUnit::new(0.007_061_551_833_333_333, JOULE),
+            Unit::Gauss => SiUnit::new(1e-4, [1, 0, -2, -1, 0, 0, 0, 0]),
+            Unit::Gilbert => SiUnit::new(
+                10.0 / (4.0 * core::f64::consts::PI),
+                [0, 0, 0, 1, 0, 0, 0, 0],
+            ),
+            Unit::Henry => SiUnit::new(1.0, HENRY),
+            Unit::Millihenry => SiUnit::new(1e-3, HENRY),
+            Unit::Farad => SiUnit::new(1.0, FARAD),
+            Unit::Microfarad => SiUnit::new(1e-6, FARAD),
+            Unit::Ohm => SiUnit::new(1.0, [1, 2, -3, -2, 0, 0, 0, 0]),
+            Unit::Siemens => SiUnit::new(1.0, [-1, -2, 3, 2, 0, 0, 0, 0]),
+            Unit::Mole => SiUnit::new(1.0, [0, 0, 0, 0, 0, 1, 0, 0]),
+            Unit::PartsPerMillion => SiUnit::new(1e-6, NONE),
+            Unit::Gray | Unit::Sievert => SiUnit::new(1.0, DOSE),
+            Unit::Bit => SiUnit::new(1.0, BIT),
+            Unit::Kilobit => SiUnit::new(1e3, BIT),
+            Unit::Megabit => SiUnit::new(1e6, BIT),
+            Unit::Gigabit => SiUnit::new(1e9, BIT),
+            Unit::Byte => SiUnit::new(8.0, BIT),
+            Unit::Kilobyte => SiUnit::new(8e3, BIT),
+            Unit::Megabyte => SiUnit::new(8e6, BIT),
+            Unit::Gigabyte => SiUnit::new(8e9, BIT),
+            Unit::Hit
+            | Unit::Miss
+            | Unit::Retry
+            | Unit::Reset
+            | Unit::OverrunOrUnderflow
+            | Unit::Underrun
+            | Unit::Collision
+            | Unit::Packet
+            | Unit::Message
+            | Unit::Character
+            | Unit::Error
+            | Unit::CorrectableError
+            | Unit::UncorrectableError
+            | Unit::FatalError => SiUnit::new(1.0, NONE),
+            // Decibels are logarithmic, and the size of words and cache lines
+            // is not defined.
+            Unit::Decibel
+            | Unit::AWeightedDecibel
+            | Unit::CWeightedDecibel
+            | Unit::Word
+            | Unit::DoubleWord
+            | Unit::QuadWord
+            | Unit::CacheLine
+            | Unit::Unspecified
+            | Unit::Unknown(_) => return None,
+        };
+
+        Some(unit)
+    }
+}
+
+/// A sensor reading together with its units.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Quantity {
+    pub value: f32,
+    pub units: SensorUnits,
+}
+
+impl Quantity {
+    pub fn new(value: f32, units: SensorUnits) -> Self {
+        Self { value, units }
+    }
+
+    /// Convert this quantity to SI base units.
+    ///
+    /// Returns `None` if the base unit or modifier unit has no SI
+    /// equivalent, such as decibels or unspecified units.
+    ///
+    /// Percentages are converted to a dimensionless fraction. Offsets of
+    /// temperature scales are only applied if the base unit is not
+    /// combined with a modifier unit or rate, as such a value describes a
+    /// temperature difference.
+    pub fn to_si(&self) -> Option<SiQuantity> {
+        let value = self.value as f64;
+
+        if self.units.is_percentage {
+            return Some(SiQuantity {
+                value: value / 100.0,
+                dimension: Dimension::DIMENSIONLESS,
+            });
+        }
+
+        let base = self.units.base_unit.si()?;
+        let mut value = value * base.scale;
+        let mut dimension = base.dimension;
+
+        match self.units.modifier {
+            Some(ModifierUnit::BaseUnitMulByModifier(unit)) => {
+                let modifier = unit.si()?;
+                value *= modifier.scale;
+                dimension = dimension * modifier.dimension;
+            }
+            Some(ModifierUnit::BasUnitDivByModifier(unit)) => {
+                let modifier = unit.si()?;
+                value /= modifier.scale;
+                dimension = dimension / modifier.dimension;
+            }
+            None => {}
+        }
+
+        if let Some(rate) = self.units.rate {
+            value /= rate.seconds();
+            dimension = dimension / Dimension::new([0, 0, 1, 0, 0, 0, 0, 0]);
+        }
+
+        if self.units.modifier.is_none() && self.units.rate.is_none() {
+            value += base.offset;
+        }
+
+        Some(SiQuantity { value, dimension })
+    }
+}
+
+impl core::fmt::Display for Quantity {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "{:.2} {}",
+            self.value,
+            self.units.display_str(true, self.value.is_plural())
+        )
+    }
+}
+
+/// A quantity expressed in SI base units.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SiQuantity {
+    pub value: f64,
+    pub dimension: Dimension,
+}
+
+impl core::fmt::Display for SiQuantity {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        if self.dimension.is_dimensionless() {
+            write!(f, "{}", self.value)
+        } else {
+            write!(f, "{} {}", self.value, self.dimension)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::sdr::record::{RateUnit, Value};
+
+    fn units(
+        base_unit: Unit,
+        modifier: Option<ModifierUnit>,
+        rate: Option<RateUnit>,
+    ) -> SensorUnits {
+        SensorUnits {
+            rate,
+            modifier,
+            is_percentage: false,
+            base_unit,
+        }
+    }
+
+    #[test]
+    fn display_composed_units() {
+        let watt_hours = units(
+            Unit::Watt,
+            Some(ModifierUnit::BaseUnitMulByModifier(Unit::Hour)),
+            None,
+        );
+        assert_eq!("12.00 W*h", Value::new(watt_hours, 12.0).display(true));
+        assert_eq!(
+            "12.00 Watts * Hour",
+            Value::new(watt_hours, 12.0).display(false)
+        );
+
+        let cfm = units(Unit::CubicFeetPerMinute, None, Some(RateUnit::Minute));
+        assert_eq!("1.00 cfm/min", Value::new(cfm, 1.0).display(true));
+        assert_eq!(
+            "1.00 Cubic Foot per Minute per Minute",
+            Value::new(cfm, 1.0).display(false)
+        );
+
+        let per_volt = units(
+            Unit::Amp,
+            Some(ModifierUnit::BasUnitDivByModifier(Unit::Volt)),
+            Some(RateUnit::Second),
+        );
+        assert_eq!("2.50 A/V/s", Value::new(per_volt, 2.5).display(true));
+
+        let rpm = units(Unit::RevolutionsPerMinute, None, None);
+        assert_eq!("5280.00 rpm", Value::new(rpm, 5280.0).display(true));
+    }
+
+    #[test]
+    fn to_si() {
+        let si = |value, units| Quantity::new(value, units).to_si().unwrap();
+
+        let celsius = si(25.0, units(Unit::DegreesCelsius, None, None));
+        assert!((celsius.value - 298.15).abs() < 1e-9);
+        assert_eq!("K", celsius.dimension.to_string());
+
+        let fahrenheit = si(32.0, units(Unit::DegreesFahrenheit, None, None));
+        assert!((fahrenheit.value - 273.15).abs() < 1e-9);
+
+        let watt_hours = si(
+            2.0,
+            units(
+                Unit::Watt,
+                Some(ModifierUnit::BaseUnitMulByModifier(Unit::Hour)),
+                None,
+            ),
+        );
+        assert_eq!(7200.0, watt_hours.value);
+        assert_eq!(Unit::Joule.si().unwrap().dimension, watt_hours.dimension);
+        assert_eq!("kg m^2 s^-2", watt_hours.dimension.to_string());
+
+        let rpm = si(120.0, units(Unit::RevolutionsPerMinute, None, None));
+        assert_eq!(2.0, rpm.value);
+        assert_eq!(Unit::Hertz.si().unwrap().dimension, rpm.dimension);
+
+        let bytes = si(
+            1.0,
+            units(Unit::Kilobyte, None, Some(RateUnit::Millisecond)),
+        );
+        assert_eq!(8e6, bytes.value);
+        assert_eq!("s^-1 bit", bytes.dimension.to_string());
+
+        let mut percentage = units(Unit::Unspecified, None, None);
+        percentage.is_percentage = true;
+        assert_eq!(0.5, si(50.0, percentage).value);
+
+        assert_eq!(
+            None,
+            Quantity::new(3.0, units(Unit::Decibel, None, None)).to_si()
+        );
+        assert_eq!(
+            None,
+            Quantity::new(
+                3.0,
+                units(
+                    Unit::Watt,
+                    Some(ModifierUnit::BaseUnitMulByModifier(Unit::Unspecified)),
+                    None
+                )
+            )
+            .to_si()
+        );
+    }
+}
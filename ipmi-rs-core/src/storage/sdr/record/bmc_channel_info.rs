@@ -0,0 +1,155 @@
+use crate::{app::ChannelProtocolType, connection::LogicalUnit};
+
+use super::ParseError;
+
+/// The interrupt used by a system interface.
+///
+/// Reference: IPMI 2.0 Specification, Section 43.11, Table 43-11
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SystemInterrupt {
+    Irq(u8),
+    /// PCI interrupt A through D, as 0 through 3.
+    Pci(u8),
+    Smi,
+    Sci,
+    /// System interrupt 0 through 63.
+    System(u8),
+    /// Assigned by ACPI, Plug and Play BIOS, or another mechanism.
+    Assigned,
+    None,
+    Reserved(u8),
+}
+
+impl From<u8> for SystemInterrupt {
+    fn from(value: u8) -> Self {
+        match value {
+            0x00..=0x0F => Self::Irq(value),
+            0x10..=0x13 => Self::Pci(value - 0x10),
+            0x14 => Self::Smi,
+            0x15 => Self::Sci,
+            0x20..=0x5F => Self::System(value - 0x20),
+            0x60 => Self::Assigned,
+            0xFF => Self::None,
+            _ => Self::Reserved(value),
+        }
+    }
+}
+
+impl From<SystemInterrupt> for u8 {
+    fn from(value: SystemInterrupt) -> Self {
+        match value {
+            SystemInterrupt::Irq(irq) => irq,
+            SystemInterrupt::Pci(pci) => 0x10 + pci,
+            SystemInterrupt::Smi => 0x14,
+            SystemInterrupt::Sci => 0x15,
+            SystemInterrupt::System(system) => 0x20 + system,
+            SystemInterrupt::Assigned => 0x60,
+            SystemInterrupt::None => 0xFF,
+            SystemInterrupt::Reserved(value) => value,
+        }
+    }
+}
+
+/// The information about a single message channel of the BMC.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MessageChannelInfo {
+    /// Whether the channel supports transmitting messages, or only
+    /// receive message queue access.
+    pub transmit_supported: bool,
+    pub receive_lun: LogicalUnit,
+    pub protocol: ChannelProtocolType,
+}
+
+impl From<u8> for MessageChannelInfo {
+    fn from(value: u8) -> Self {
+        Self {
+            transmit_supported: (value & 0x80) == 0x80,
+            receive_lun: LogicalUnit::from_low_bits(value >> 4),
+            protocol: ChannelProtocolType::from(value & 0x0F),
+        }
+    }
+}
+
+impl From<MessageChannelInfo> for u8 {
+    fn from(value: MessageChannelInfo) -> Self {
+        ((value.transmit_supported as u8) << 7)
+            | (value.receive_lun.value() << 4)
+            | (u8::from(value.protocol) & 0x0F)
+    }
+}
+
+/// A BMC Message Channel Info record, which describes the message channels
+/// 0 through 7 of the BMC.
+///
+/// Reference: IPMI 2.0 Specification, Section 43.11, Table 43-11
+#[derive(Debug, Clone, PartialEq)]
+pub struct BmcMessageChannelInfoRecord {
+    pub channels: [MessageChannelInfo; 8],
+    pub messaging_interrupt: SystemInterrupt,
+    pub event_message_buffer_interrupt: SystemInterrupt,
+}
+
+impl BmcMessageChannelInfoRecord {
+    pub fn parse(record_data: &[u8]) -> Result<Self, ParseError> {
+        if record_data.len() < 10 {
+            return Err(ParseError::NotEnoughData);
+        }
+
+        let channels = core::array::from_fn(|i| MessageChannelInfo::from(record_data[i]));
+
+        Ok(Self {
+            channels,
+            messaging_interrupt: SystemInterrupt::from(record_data[8]),
+            event_message_buffer_interrupt: SystemInterrupt::from(record_data[9]),
+        })
+    }
+
+    /// Encode this record as the record data that [`BmcMessageChannelInfoRecord::parse`] parses.
+    pub fn encode(&self) -> Vec<u8> {
+        let mut data: Vec<u8> = self.channels.iter().copied().map(u8::from).collect();
+        data.push(self.messaging_interrupt.into());
+        data.push(self.event_message_buffer_interrupt.into());
+        // One reserved byte
+        data.push(0x00);
+        data
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_channel_info() {
+        let data = [
+            0x81, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x85, 0x0A, 0x12, 0x00,
+        ];
+
+        let record = BmcMessageChannelInfoRecord::parse(&data).unwrap();
+
+        assert!(record.channels[0].transmit_supported);
+        assert_eq!(ChannelProtocolType::IpmbV1_0, record.channels[0].protocol);
+        assert!(!record.channels[1].transmit_supported);
+        assert_eq!(SystemInterrupt::Irq(0x0A), record.messaging_interrupt);
+        assert_eq!(
+            SystemInterrupt::Pci(2),
+            record.event_message_buffer_interrupt
+        );
+    }
+
+    #[test]
+    fn encode_round_trip() {
+        let data = [
+            0x81, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x85, 0x0A, 0x12, 0x00,
+        ];
+
+        let record = BmcMessageChannelInfoRecord::parse(&data).unwrap();
+        let encoded = record.encode();
+
+        assert_eq!(&data[..], &encoded[..]);
+        assert_eq!(
+            record,
+            BmcMessageChannelInfoRecord::parse(&encoded).unwrap()
+        );
+    }
+}
@@ -6,7 +6,7 @@ pub enum IdStringModifier {
     Alpha,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct RecordSharing {
     pub id_string_modifier: IdStringModifier,
     pub share_count: u8,
@@ -14,7 +14,128 @@ pub struct RecordSharing {
     pub modifier_offset: u8,
 }
 
-#[derive(Debug, Clone)]
+impl RecordSharing {
+    pub(crate) fn parse(
+        direction_sharing_1: u8,
+        direction_sharing_2: u8,
+    ) -> Result<Self, ParseError> {
+        let id_string_modifier = match (direction_sharing_1 & 0x30) >> 4 {
+            0b00 => IdStringModifier::Numeric,
+            0b01 => IdStringModifier::Alpha,
+            v => return Err(ParseError::InvalidIdStringModifier(v)),
+        };
+
+        Ok(Self {
+            id_string_modifier,
+            share_count: direction_sharing_1 & 0xF,
+            entity_instance_increments: (direction_sharing_2 & 0x80) == 0x80,
+            modifier_offset: direction_sharing_2 & 0x7F,
+        })
+    }
+
+    /// Encode this record sharing, and `direction`, as the two bytes that
+    /// [`RecordSharing::parse`] parses.
+    pub(crate) fn encode(&self, direction: Direction) -> [u8; 2] {
+        let id_string_modifier = match self.id_string_modifier {
+            IdStringModifier::Numeric => 0b00,
+            IdStringModifier::Alpha => 0b01,
+        };
+
+        [
+            (u8::from(direction) << 6) | (id_string_modifier << 4) | (self.share_count & 0xF),
+            ((self.entity_instance_increments as u8) << 7) | (self.modifier_offset & 0x7F),
+        ]
+    }
+
+    /// The suffix of the ID string of the `index`th sensor sharing the record.
+    fn id_suffix(&self, index: u8) -> String {
+        let value = self.modifier_offset as usize + index as usize;
+
+        match self.id_string_modifier {
+            IdStringModifier::Numeric => value.to_string(),
+            IdStringModifier::Alpha => {
+                // A, B, ..., Z, AA, AB, ...
+                let mut suffix = Vec::new();
+                let mut value = value + 1;
+
+                while value > 0 {
+                    value -= 1;
+                    suffix.push(b'A' + (value % 26) as u8);
+                    value /= 26;
+                }
+
+                suffix.iter().rev().map(|c| *c as char).collect()
+            }
+        }
+    }
+
+    /// Expand a record with key `key`, ID string `id_string` and entity
+    /// instance `entity_instance` into the individual sensors that share it.
+    ///
+    /// Reference: IPMI 2.0 Specification, Section 43.2, Table 43-2
+    pub fn expand(
+        &self,
+        key: &SensorKey,
+        id_string: &SensorId,
+        entity_instance: EntityInstance,
+    ) -> Vec<SharedSensor> {
+        // A share count of 0 is treated as 1.
+        let share_count = self.share_count.max(1);
+
+        if share_count == 1 {
+            return vec![SharedSensor {
+                key: *key,
+                id_string: id_string.clone(),
+                entity_instance,
+            }];
+        }
+
+        (0..share_count)
+            .map_while(|index| {
+                let sensor_number = key.sensor_number.get().checked_add(index)?;
+                let sensor_number = SensorNumber::new(NonMaxU8::new(sensor_number)?);
+
+                let id_string = match id_string {
+                    SensorId::Unicode(v) => {
+                        SensorId::Unicode(format!("{v}{}", self.id_suffix(index)))
+                    }
+                    SensorId::Ascii8BAndLatin1(v) => {
+                        SensorId::Ascii8BAndLatin1(format!("{v}{}", self.id_suffix(index)))
+                    }
+                    other => {
+                        SensorId::Ascii8BAndLatin1(format!("{other}{}", self.id_suffix(index)))
+                    }
+                };
+
+                let entity_instance = if self.entity_instance_increments {
+                    let raw = u8::from(entity_instance);
+                    EntityInstance::from((raw & 0x80) | (raw.wrapping_add(index) & 0x7F))
+                } else {
+                    entity_instance
+                };
+
+                Some(SharedSensor {
+                    key: SensorKey {
+                        sensor_number,
+                        ..*key
+                    },
+                    id_string,
+                    entity_instance,
+                })
+            })
+            .collect()
+    }
+}
+
+/// One of the sensors that share a compact or event-only sensor record.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SharedSensor {
+    pub key: SensorKey,
+    pub id_string: SensorId,
+    pub entity_instance: EntityInstance,
+}
+
+#[derive(Debug, Clone, PartialEq)]
 pub struct CompactSensorRecord {
     common: SensorRecordCommon,
     pub direction: Direction,
@@ -47,23 +168,8 @@ impl CompactSensorRecord {
         let direction_sharing_1 = record_data[0];
         let direction_sharing_2 = record_data[1];
 
-        let direction = Direction::try_from((direction_sharing_1 & 0xC) >> 6)?;
-        let id_string_instance_modifier = match (direction_sharing_1 & 0x30) >> 4 {
-            0b00 => IdStringModifier::Numeric,
-            0b01 => IdStringModifier::Alpha,
-            _ => panic!("Invalid ID string modifier, no fallback available."),
-        };
-
-        let share_count = direction_sharing_1 & 0xF;
-        let entity_instance_increments = (direction_sharing_2 & 0x80) == 0x80;
-        let modifier_offset = direction_sharing_2 & 0x3F;
-
-        let record_sharing = RecordSharing {
-            id_string_modifier: id_string_instance_modifier,
-            share_count,
-            entity_instance_increments,
-            modifier_offset,
-        };
+        let direction = Direction::try_from((direction_sharing_1 & 0xC0) >> 6)?;
+        let record_sharing = RecordSharing::parse(direction_sharing_1, direction_sharing_2)?;
 
         let positive_going_threshold_hysteresis_value = record_data[2];
         let negative_going_threshold_hysteresis_value = record_data[3];
@@ -87,4 +193,104 @@ impl CompactSensorRecord {
             oem_data,
         })
     }
+
+    /// Encode this record as the record data that [`CompactSensorRecord::parse`] parses.
+    pub fn encode(&self) -> Result<Vec<u8>, EncodeError> {
+        let mut data = self.common.encode_without_id(0);
+        data.extend_from_slice(&self.record_sharing.encode(self.direction));
+        data.extend_from_slice(&[
+            self.positive_going_threshold_hysteresis_value,
+            self.negative_going_threshold_hysteresis_value,
+            // Three reserved bytes
+            0x00,
+            0x00,
+            0x00,
+            self.oem_data,
+        ]);
+        data.extend(self.common.sensor_id.encode()?);
+
+        Ok(data)
+    }
+
+    /// The individual sensors that share this record.
+    pub fn shared_sensors(&self) -> Vec<SharedSensor> {
+        self.record_sharing.expand(
+            &self.common.key,
+            &self.common.sensor_id,
+            self.common.entity_instance,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(sharing_1: u8, sharing_2: u8) -> Vec<u8> {
+        let mut data = vec![
+            0x20, 0x00, 0x10, 0x20, 0x01, 0x7F, 0x40, 0x0C, 0x6F, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00,
+        ];
+        data.extend([sharing_1, sharing_2, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]);
+        data.extend([0xC4, b'D', b'I', b'M', b'M']);
+        data
+    }
+
+    #[test]
+    fn expand_shared_sensors() {
+        // Input direction, alpha modifier, 4 sensors, incrementing entity instance.
+        let compact = CompactSensorRecord::parse(&record(0x54, 0x80)).unwrap();
+
+        assert_eq!(Direction::Input, compact.direction);
+
+        let sensors = compact.shared_sensors();
+        assert_eq!(4, sensors.len());
+
+        for (idx, (sensor, id)) in sensors
+            .iter()
+            .zip(["DIMMA", "DIMMB", "DIMMC", "DIMMD"])
+            .enumerate()
+        {
+            assert_eq!(0x10 + idx as u8, sensor.key.sensor_number.get());
+            assert_eq!(SensorId::Ascii8BAndLatin1(id.into()), sensor.id_string);
+            assert_eq!(1 + idx as u8, u8::from(sensor.entity_instance));
+        }
+
+        // Numeric modifier starting at 8, 2 sensors, fixed entity instance.
+        let compact = CompactSensorRecord::parse(&record(0x02, 0x08)).unwrap();
+        let sensors = compact.shared_sensors();
+
+        assert_eq!(
+            SensorId::Ascii8BAndLatin1("DIMM9".into()),
+            sensors[1].id_string
+        );
+        assert_eq!(1, u8::from(sensors[1].entity_instance));
+    }
+
+    #[test]
+    fn encode_round_trip() {
+        let data = record(0x54, 0x80);
+
+        let compact = CompactSensorRecord::parse(&data).unwrap();
+        let encoded = compact.encode().unwrap();
+
+        assert_eq!(data, encoded);
+        assert_eq!(compact, CompactSensorRecord::parse(&encoded).unwrap());
+    }
+
+    #[test]
+    fn alpha_suffix_wraps() {
+        let sharing = RecordSharing::parse(0x12, 0x19).unwrap();
+
+        assert_eq!("Z", sharing.id_suffix(0));
+        assert_eq!("AA", sharing.id_suffix(1));
+    }
+
+    #[test]
+    fn invalid_id_string_modifier() {
+        assert!(matches!(
+            CompactSensorRecord::parse(&record(0x24, 0x00)),
+            Err(ParseError::InvalidIdStringModifier(0b10))
+        ));
+    }
 }
@@ -0,0 +1,397 @@
+use crate::storage::sdr::EntityId;
+
+use super::{EncodeError, ParseError};
+
+/// The device that a device-relative entity instance is relative to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct EntityDevice {
+    /// The 7-bit slave address of the device.
+    pub address: u8,
+    pub channel: u8,
+}
+
+/// An entity, identified by its entity ID and entity instance number.
+///
+/// Device-relative entity instances (0x60 through 0x7F) are only unique
+/// together with the device that they are relative to.
+///
+/// Reference: IPMI 2.0 Specification, Section 39.1
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Entity {
+    pub id: EntityId,
+    /// The entity instance number, without the logical container bit.
+    pub instance: u8,
+    /// The device that `instance` is relative to, if it is a
+    /// device-relative instance and the device is known.
+    pub device: Option<EntityDevice>,
+}
+
+impl Entity {
+    /// Create a new entity with an unknown device.
+    pub fn new(id: EntityId, instance: u8) -> Self {
+        Self {
+            id,
+            instance: instance & 0x7F,
+            device: None,
+        }
+    }
+
+    /// Create a new entity whose instance is relative to the device with
+    /// 7-bit slave address `address` on channel `channel`, if it is a
+    /// device-relative instance.
+    pub fn device_relative(id: EntityId, instance: u8, address: u8, channel: u8) -> Self {
+        let mut entity = Self::new(id, instance);
+
+        if entity.is_device_relative() {
+            entity.device = Some(EntityDevice { address, channel });
+        }
+
+        entity
+    }
+
+    /// Whether the instance of this entity is relative to a device.
+    pub fn is_device_relative(&self) -> bool {
+        self.instance >= 0x60
+    }
+
+    /// Make this entity relative to `device`, if it is a device-relative
+    /// instance whose device is not known yet.
+    pub fn relative_to(mut self, device: EntityDevice) -> Self {
+        if self.is_device_relative() && self.device.is_none() {
+            self.device = Some(device);
+        }
+
+        self
+    }
+}
+
+impl core::fmt::Display for Entity {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{} {}", self.id, self.instance)
+    }
+}
+
+/// An entity, or a range of entities, contained by a container entity.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ContainedEntity {
+    Single(Entity),
+    /// All instances of `first.id` from `first.instance` through
+    /// `last.instance`.
+    Range {
+        first: Entity,
+        last: Entity,
+    },
+}
+
+impl ContainedEntity {
+    /// The entities described by this contained entity.
+    pub fn entities(&self) -> Vec<Entity> {
+        match self {
+            ContainedEntity::Single(entity) => vec![*entity],
+            ContainedEntity::Range { first, last } => (first.instance..=last.instance)
+                .map(|instance| Entity { instance, ..*first })
+                .collect(),
+        }
+    }
+}
+
+/// An Entity Association or Device-relative Entity Association record,
+/// describing the entities contained by a container entity.
+///
+/// Reference: IPMI 2.0 Specification, Section 43.4 and 43.5
+#[derive(Debug, Clone, PartialEq)]
+pub struct EntityAssociationRecord {
+    pub container: Entity,
+    /// Whether other entity association records that describe
+    /// entities contained by `container` exist.
+    pub linked: bool,
+    /// Whether the presence sensors of the contained entities should be
+    /// accessed even if the presence sensor of the container entity is
+    /// absent.
+    pub access_presence_sensors: bool,
+    pub contained: Vec<ContainedEntity>,
+}
+
+impl EntityAssociationRecord {
+    /// Parse an Entity Association record (type 0x08).
+    ///
+    /// The device of device-relative entity instances is not described by
+    /// these records: they are relative to the management controller that
+    /// provides the record. Use [`EntityAssociationRecord::relative_to`] to
+    /// set it.
+    pub fn parse(record_data: &[u8]) -> Result<Self, ParseError> {
+        if record_data.len() < 11 {
+            return Err(ParseError::NotEnoughData);
+        }
+
+        let container = Entity::new(record_data[0].into(), record_data[1]);
+        let contained = record_data[3..11]
+            .chunks(2)
+            .map(|v| Entity::new(v[0].into(), v[1]))
+            .collect::<Vec<_>>();
+
+        Ok(Self::new(container, record_data[2], contained))
+    }
+
+    /// Parse a Device-relative Entity Association record (type 0x09).
+    pub fn parse_device_relative(record_data: &[u8]) -> Result<Self, ParseError> {
+        if record_data.len() < 21 {
+            return Err(ParseError::NotEnoughData);
+        }
+
+        // The container entity is described by its entity ID and instance
+        // followed by its device, the contained entities by their device
+        // followed by their entity ID and instance.
+        let d = &record_data[..4];
+        let container = Entity::device_relative(d[0].into(), d[1], d[2] >> 1, d[3] >> 4);
+
+        let entity = |v: &[u8]| Entity::device_relative(v[2].into(), v[3], v[0] >> 1, v[1] >> 4);
+        let contained = record_data[5..21].chunks(4).map(entity).collect::<Vec<_>>();
+
+        Ok(Self::new(container, record_data[4], contained))
+    }
+
+    fn new(container: Entity, flags: u8, contained: Vec<Entity>) -> Self {
+        let is_range = (flags & 0x80) == 0x80;
+        let linked = (flags & 0x40) == 0x40;
+        let access_presence_sensors = (flags & 0x20) == 0x20;
+
+        // An entity ID of 0 marks an unused slot.
+        let contained = if is_range {
+            contained
+                .chunks(2)
+                .filter(|v| v[0].id != EntityId::Unspecified)
+                .map(|v| ContainedEntity::Range {
+                    first: v[0],
+                    last: v[1],
+                })
+                .collect()
+        } else {
+            contained
+                .into_iter()
+                .filter(|v| v.id != EntityId::Unspecified)
+                .map(ContainedEntity::Single)
+                .collect()
+        };
+
+        Self {
+            container,
+            linked,
+            access_presence_sensors,
+            contained,
+        }
+    }
+
+    /// Encode this record as the record data that [`EntityAssociationRecord::parse`] parses.
+    ///
+    /// The devices of device-relative entities, and the logical container
+    /// bit of entity instances, are not encoded.
+    pub fn encode(&self) -> Result<Vec<u8>, EncodeError> {
+        let (flags, contained) = self.encode_contained(4)?;
+
+        let mut data = vec![self.container.id.into(), self.container.instance, flags];
+        for entity in contained {
+            data.extend([entity.id.into(), entity.instance]);
+        }
+
+        Ok(data)
+    }
+
+    /// Encode this record as the record data that
+    /// [`EntityAssociationRecord::parse_device_relative`] parses.
+    pub fn encode_device_relative(&self) -> Result<Vec<u8>, EncodeError> {
+        let (flags, contained) = self.encode_contained(4)?;
+
+        let device = |entity: &Entity| {
+            entity
+                .device
+                .map_or([0, 0], |v| [v.address << 1, v.channel << 4])
+        };
+
+        let mut data = vec![self.container.id.into(), self.container.instance];
+        data.extend(device(&self.container));
+        data.push(flags);
+        for entity in contained {
+            data.extend(device(&entity));
+            data.extend([entity.id.into(), entity.instance]);
+        }
+
+        Ok(data)
+    }
+
+    /// Encode the flags of this record, and its contained entities padded
+    /// with unused slots to `slots` entities.
+    fn encode_contained(&self, slots: usize) -> Result<(u8, Vec<Entity>), EncodeError> {
+        let is_range = self
+            .contained
+            .iter()
+            .any(|v| matches!(v, ContainedEntity::Range { .. }));
+
+        let mut entities = Vec::with_capacity(slots);
+        for contained in &self.contained {
+            match (contained, is_range) {
+                (ContainedEntity::Single(entity), false) => entities.push(*entity),
+                (ContainedEntity::Range { first, last }, true) => entities.extend([*first, *last]),
+                _ => return Err(EncodeError::InvalidContainedEntities),
+            }
+        }
+
+        if entities.len() > slots {
+            return Err(EncodeError::InvalidContainedEntities);
+        }
+
+        entities.resize(slots, Entity::new(EntityId::Unspecified, 0));
+
+        let flags = ((is_range as u8) << 7)
+            | ((self.linked as u8) << 6)
+            | ((self.access_presence_sensors as u8) << 5);
+
+        Ok((flags, entities))
+    }
+
+    /// Make the device-relative entities in this record whose device is not
+    /// known relative to `device`.
+    pub fn relative_to(mut self, device: EntityDevice) -> Self {
+        self.container = self.container.relative_to(device);
+
+        for contained in &mut self.contained {
+            match contained {
+                ContainedEntity::Single(entity) => *entity = entity.relative_to(device),
+                ContainedEntity::Range { first, last } => {
+                    *first = first.relative_to(device);
+                    *last = last.relative_to(device);
+                }
+            }
+        }
+
+        self
+    }
+
+    /// All entities contained by the container entity of this record.
+    pub fn contained_entities(&self) -> Vec<Entity> {
+        self.contained
+            .iter()
+            .flat_map(ContainedEntity::entities)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_list() {
+        // System board 1 contains processor 1 and processor 2.
+        let data = [
+            0x07, 0x01, 0x00, 0x03, 0x01, 0x03, 0x02, 0x00, 0x00, 0x00, 0x00,
+        ];
+
+        let record = EntityAssociationRecord::parse(&data).unwrap();
+
+        assert_eq!(Entity::new(EntityId::SystemBoard, 0x01), record.container);
+        assert!(!record.linked);
+        assert_eq!(
+            vec![
+                Entity::new(EntityId::Processor, 0x01),
+                Entity::new(EntityId::Processor, 0x02)
+            ],
+            record.contained_entities()
+        );
+    }
+
+    #[test]
+    fn parse_range() {
+        // Power unit 1 contains power supplies 1 through 3, with linked records.
+        let data = [
+            0x13, 0x81, 0xC0, 0x0A, 0x01, 0x0A, 0x03, 0x00, 0x00, 0x00, 0x00,
+        ];
+
+        let record = EntityAssociationRecord::parse(&data).unwrap();
+
+        assert_eq!(Entity::new(EntityId::PowerUnit, 0x01), record.container);
+        assert!(record.linked);
+        assert_eq!(1, record.contained.len());
+        assert_eq!(
+            vec![
+                Entity::new(EntityId::PowerSupply, 0x01),
+                Entity::new(EntityId::PowerSupply, 0x02),
+                Entity::new(EntityId::PowerSupply, 0x03)
+            ],
+            record.contained_entities()
+        );
+    }
+
+    #[test]
+    fn parse_device_relative() {
+        let data = [
+            0x07, 0x01, 0x20, 0x00, 0x00, // Container: system board 1, BMC.
+            0x82, 0x10, 0x03, 0x60, // Processor 0x60 relative to 0x41, channel 1.
+            0x20, 0x00, 0x03, 0x02, // Processor 2, system-relative.
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        ];
+
+        let record = EntityAssociationRecord::parse_device_relative(&data).unwrap();
+
+        assert_eq!(Entity::new(EntityId::SystemBoard, 0x01), record.container);
+        assert_eq!(
+            vec![
+                Entity::device_relative(EntityId::Processor, 0x60, 0x41, 1),
+                Entity::new(EntityId::Processor, 0x02)
+            ],
+            record.contained_entities()
+        );
+        assert_eq!(
+            Some(EntityDevice {
+                address: 0x41,
+                channel: 1
+            }),
+            record.contained_entities()[0].device
+        );
+    }
+
+    #[test]
+    fn encode_round_trip() {
+        let data = [
+            0x13, 0x01, 0xC0, 0x0A, 0x01, 0x0A, 0x03, 0x00, 0x00, 0x00, 0x00,
+        ];
+
+        let record = EntityAssociationRecord::parse(&data).unwrap();
+        let encoded = record.encode().unwrap();
+
+        assert_eq!(&data[..], &encoded[..]);
+        assert_eq!(record, EntityAssociationRecord::parse(&encoded).unwrap());
+
+        let data = [
+            0x07, 0x01, 0x00, 0x00, 0x20, 0x82, 0x10, 0x03, 0x60, 0x00, 0x00, 0x03, 0x02, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        ];
+
+        let record = EntityAssociationRecord::parse_device_relative(&data).unwrap();
+        let encoded = record.encode_device_relative().unwrap();
+
+        assert_eq!(&data[..], &encoded[..]);
+        assert_eq!(
+            record,
+            EntityAssociationRecord::parse_device_relative(&encoded).unwrap()
+        );
+    }
+
+    #[test]
+    fn encode_mixed_contained() {
+        let record = EntityAssociationRecord {
+            container: Entity::new(EntityId::SystemBoard, 0x01),
+            linked: false,
+            access_presence_sensors: false,
+            contained: vec![
+                ContainedEntity::Single(Entity::new(EntityId::Processor, 0x01)),
+                ContainedEntity::Range {
+                    first: Entity::new(EntityId::PowerSupply, 0x01),
+                    last: Entity::new(EntityId::PowerSupply, 0x02),
+                },
+            ],
+        };
+
+        assert_eq!(Err(EncodeError::InvalidContainedEntities), record.encode());
+    }
+}
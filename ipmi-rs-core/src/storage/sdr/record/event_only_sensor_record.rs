@@ -1,15 +1,15 @@
 use crate::storage::sdr::event_reading_type_code::EventReadingTypeCodes;
-use crate::storage::sdr::record::compact_sensor_record::{IdStringModifier, RecordSharing};
+use crate::storage::sdr::record::compact_sensor_record::{RecordSharing, SharedSensor};
 use crate::storage::sdr::record::{Direction, EntityInstance, SensorId, SensorKey, TypeLengthRaw};
-use crate::storage::sdr::SensorType;
+use crate::storage::sdr::{EntityId, SensorType};
 
-use super::{DirectionalSensor, IdentifiableSensor, InstancedSensor, ParseError};
+use super::{DirectionalSensor, EncodeError, IdentifiableSensor, InstancedSensor, ParseError};
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 
 pub struct EventOnlySensorRecord {
     pub key: SensorKey,
-    pub entity_id: u8,
+    pub entity_id: EntityId,
     pub entity_instance: EntityInstance,
     pub id_string: SensorId,
     pub ty: SensorType,
@@ -25,7 +25,7 @@ impl IdentifiableSensor for EventOnlySensorRecord {
         &self.id_string
     }
 
-    fn entity_id(&self) -> u8 {
+    fn entity_id(&self) -> EntityId {
         self.entity_id
     }
 }
@@ -62,7 +62,7 @@ impl EventOnlySensorRecord {
 
         let key = SensorKey::parse(&record_data[..3])?;
 
-        let entity_id = record_data[3];
+        let entity_id = EntityId::from(record_data[3]);
         let entity_instance = EntityInstance::from(record_data[4]);
         let ty = record_data[5].into();
         let event_reading_type_code = record_data[6].into();
@@ -70,23 +70,8 @@ impl EventOnlySensorRecord {
         let direction_sharing_1 = record_data[7];
         let direction_sharing_2 = record_data[8];
 
-        let direction = Direction::try_from((direction_sharing_1 & 0xC) >> 6)?;
-        let id_string_instance_modifier = match (direction_sharing_1 & 0x30) >> 4 {
-            0b00 => Ok(IdStringModifier::Numeric),
-            0b01 => Ok(IdStringModifier::Alpha),
-            v => Err(ParseError::InvalidIdStringModifier(v)),
-        }?;
-
-        let share_count = direction_sharing_1 & 0xF;
-        let entity_instance_increments = (direction_sharing_2 & 0x80) == 0x80;
-        let modifier_offset = direction_sharing_2 & 0x3F;
-
-        let record_sharing = RecordSharing {
-            id_string_modifier: id_string_instance_modifier,
-            share_count,
-            entity_instance_increments,
-            modifier_offset,
-        };
+        let direction = Direction::try_from((direction_sharing_1 & 0xC0) >> 6)?;
+        let record_sharing = RecordSharing::parse(direction_sharing_1, direction_sharing_2)?;
 
         // one reserved byte
         let oem_reserved = record_data[10];
@@ -107,4 +92,46 @@ impl EventOnlySensorRecord {
             id_string,
         })
     }
+
+    /// Encode this record as the record data that [`EventOnlySensorRecord::parse`] parses.
+    pub fn encode(&self) -> Result<Vec<u8>, EncodeError> {
+        let mut data = Vec::new();
+        data.extend_from_slice(&self.key.encode());
+        data.push(self.entity_id.into());
+        data.push(self.entity_instance.into());
+        data.push(self.ty.into());
+        data.push(self.event_reading_type_code.into());
+        data.extend_from_slice(&self.record_sharing.encode(self.direction));
+        // one reserved byte
+        data.push(0x00);
+        data.push(self.oem_reserved);
+        data.extend(self.id_string.encode()?);
+
+        Ok(data)
+    }
+
+    /// The individual sensors that share this record.
+    pub fn shared_sensors(&self) -> Vec<SharedSensor> {
+        self.record_sharing
+            .expand(&self.key, &self.id_string, self.entity_instance)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn encode_round_trip() {
+        let data = [
+            0x20, 0x00, 0x30, 0x0A, 0x01, 0x08, 0x6F, 0x41, 0x00, 0x00, 0x00, 0xC3, b'P', b'S',
+            b'1',
+        ];
+
+        let record = EventOnlySensorRecord::parse(&data).unwrap();
+        let encoded = record.encode().unwrap();
+
+        assert_eq!(&data[..], &encoded[..]);
+        assert_eq!(record, EventOnlySensorRecord::parse(&encoded).unwrap());
+    }
 }
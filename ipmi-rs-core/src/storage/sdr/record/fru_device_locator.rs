@@ -1,25 +1,26 @@
 use crate::connection::LogicalUnit;
 use crate::storage::sdr::record::{SensorId, TypeLengthRaw};
+use crate::storage::sdr::EntityId;
 
-use super::{IdentifiableSensor, ParseError};
+use super::{EncodeError, IdentifiableSensor, ParseError};
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct LogicalFruDevice {
     pub fru_device_id: u8,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct PhysicalFruDevice {
     pub i2c_address: u8,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum FruDevice {
     Logical(LogicalFruDevice),
     Physical(PhysicalFruDevice),
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct FruRecordKey {
     pub device_access_address: u8,
     pub fru_device: FruDevice,
@@ -28,12 +29,12 @@ pub struct FruRecordKey {
     pub channel_number: u8,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct FruDeviceLocator {
     pub record_key: FruRecordKey,
     pub device_type: u8,
     pub device_type_modifier: u8,
-    pub fru_entity_id: u8,
+    pub fru_entity_id: EntityId,
     pub fru_entity_instance: u8,
     pub oem_reserved: u8,
     pub id_string: SensorId,
@@ -44,7 +45,7 @@ impl IdentifiableSensor for FruDeviceLocator {
         &self.id_string
     }
 
-    fn entity_id(&self) -> u8 {
+    fn entity_id(&self) -> EntityId {
         // Is this correct?
         self.fru_entity_id
     }
@@ -52,25 +53,25 @@ impl IdentifiableSensor for FruDeviceLocator {
 
 impl FruDeviceLocator {
     pub fn parse(record_data: &[u8]) -> Result<Self, ParseError> {
-        if record_data.len() < 8 {
+        if record_data.len() < 11 {
             return Err(ParseError::NotEnoughData);
         }
 
         let device_access_address = record_data[0] >> 1;
 
-        let fru_device = if record_data[1] & 0x80 == 0x80 {
-            FruDevice::Physical(PhysicalFruDevice {
-                i2c_address: (record_data[1] >> 1),
-            })
-        } else {
+        let fru_device = if record_data[2] & 0x80 == 0x80 {
             FruDevice::Logical(LogicalFruDevice {
                 fru_device_id: record_data[1],
             })
+        } else {
+            FruDevice::Physical(PhysicalFruDevice {
+                i2c_address: (record_data[1] >> 1),
+            })
         };
 
         let lun = LogicalUnit::from_low_bits(record_data[2] >> 3);
         let private_bus_id = record_data[2] & 0b111;
-        let channel_number = record_data[3];
+        let channel_number = record_data[3] >> 4;
 
         let record_key = FruRecordKey {
             device_access_address,
@@ -82,7 +83,7 @@ impl FruDeviceLocator {
 
         let device_type = record_data[5];
         let device_type_modifier = record_data[6];
-        let fru_entity_id = record_data[7];
+        let fru_entity_id = EntityId::from(record_data[7]);
         let fru_entity_instance = record_data[8];
         let oem_reserved = record_data[9];
 
@@ -102,7 +103,93 @@ impl FruDeviceLocator {
         })
     }
 
+    /// Encode this record as the record data that [`FruDeviceLocator::parse`] parses.
+    pub fn encode(&self) -> Result<Vec<u8>, EncodeError> {
+        let key = &self.record_key;
+
+        let (logical, fru_device) = match &key.fru_device {
+            FruDevice::Logical(v) => (1, v.fru_device_id),
+            FruDevice::Physical(v) => (0, v.i2c_address << 1),
+        };
+
+        let mut data = vec![
+            key.device_access_address << 1,
+            fru_device,
+            (logical << 7) | (key.lun.value() << 3) | (key.private_bus_id & 0b111),
+            key.channel_number << 4,
+            // Reserved
+            0x00,
+            self.device_type,
+            self.device_type_modifier,
+            self.fru_entity_id.into(),
+            self.fru_entity_instance,
+            self.oem_reserved,
+        ];
+        data.extend(self.id_string.encode()?);
+
+        Ok(data)
+    }
+
     pub fn id_string(&self) -> &SensorId {
         &self.id_string
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_logical_fru() {
+        let data = [
+            0x20, 0x01, 0x80, 0x00, 0x00, 0x10, 0x00, 0x07, 0x01, 0x00, 0xC5, b'B', b'O', b'A',
+            b'R', b'D',
+        ];
+
+        let locator = FruDeviceLocator::parse(&data).unwrap();
+
+        assert_eq!(0x10, locator.record_key.device_access_address);
+        assert!(matches!(
+            locator.record_key.fru_device,
+            FruDevice::Logical(LogicalFruDevice { fru_device_id: 1 })
+        ));
+        assert_eq!(EntityId::SystemBoard, locator.fru_entity_id);
+    }
+
+    #[test]
+    fn parse_physical_fru() {
+        let data = [
+            0x20, 0xA0, 0x0A, 0x20, 0x00, 0x0F, 0x00, 0x0A, 0x01, 0x00, 0xC3, b'P', b'S', b'U',
+        ];
+
+        let locator = FruDeviceLocator::parse(&data).unwrap();
+        let key = &locator.record_key;
+
+        assert!(matches!(
+            key.fru_device,
+            FruDevice::Physical(PhysicalFruDevice { i2c_address: 0x50 })
+        ));
+        assert_eq!(LogicalUnit::One, key.lun);
+        assert_eq!(2, key.private_bus_id);
+        assert_eq!(2, key.channel_number);
+        assert_eq!(0x0F, locator.device_type);
+    }
+
+    #[test]
+    fn encode_round_trip() {
+        let data = [
+            0x20, 0xA0, 0x0A, 0x20, 0x00, 0x0F, 0x00, 0x0A, 0x01, 0x00, 0xC3, b'P', b'S', b'U',
+        ];
+
+        let locator = FruDeviceLocator::parse(&data).unwrap();
+        let encoded = locator.encode().unwrap();
+
+        assert_eq!(&data[..], &encoded[..]);
+        assert_eq!(locator, FruDeviceLocator::parse(&encoded).unwrap());
+    }
+
+    #[test]
+    fn reject_short_record() {
+        assert!(FruDeviceLocator::parse(&[0x20; 10]).is_err());
+    }
+}
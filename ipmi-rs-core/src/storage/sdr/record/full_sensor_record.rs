@@ -2,7 +2,110 @@ use std::num::NonZeroU8;
 
 use super::*;
 
-#[derive(Debug, Clone)]
+/// The factors used to convert raw readings of a sensor to values in
+/// the units of the sensor.
+///
+/// Reference: IPMI 2.0 Specification, Section 36.3
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ConversionFactors {
+    pub m: i16,
+    /// The tolerance, in +/- half raw counts.
+    pub tolerance: u8,
+    pub b: i16,
+    /// The unsigned accuracy, in units of 0.01% scaled by `10^accuracy_exponent`.
+    pub accuracy: u16,
+    pub accuracy_exponent: u8,
+    pub result_exponent: i8,
+    pub b_exponent: i8,
+}
+
+impl ConversionFactors {
+    /// Parse conversion factors from the 6 bytes that encode them in full
+    /// sensor records and in Get Sensor Reading Factors responses.
+    pub(crate) fn parse(data: &[u8]) -> Option<Self> {
+        if data.len() < 6 {
+            return None;
+        }
+
+        // Sign-extend the `bits`-bit two's complement number `value`.
+        let signed = |value: u16, bits: u32| ((value << (16 - bits)) as i16) >> (16 - bits);
+
+        let m = signed(u16::from_le_bytes([data[0], data[1] >> 6]), 10);
+        let tolerance = data[1] & 0x3F;
+
+        let b = signed(u16::from_le_bytes([data[2], data[3] >> 6]), 10);
+
+        let accuracy = (((data[4] >> 4) as u16) << 6) | (data[3] & 0x3F) as u16;
+        let accuracy_exponent = (data[4] >> 2) & 0x3;
+
+        let result_exponent = signed((data[5] >> 4) as u16, 4) as i8;
+        let b_exponent = signed((data[5] & 0xF) as u16, 4) as i8;
+
+        Some(Self {
+            m,
+            tolerance,
+            b,
+            accuracy,
+            accuracy_exponent,
+            result_exponent,
+            b_exponent,
+        })
+    }
+
+    /// Encode these conversion factors as the 6 bytes that
+    /// [`ConversionFactors::parse`] parses.
+    ///
+    /// The sensor direction bits (bits \[1:0\] of byte 4) are left as 0.
+    pub(crate) fn encode(&self) -> [u8; 6] {
+        let m = self.m as u16 & 0x3FF;
+        let b = self.b as u16 & 0x3FF;
+
+        [
+            m as u8,
+            (((m >> 8) as u8) << 6) | (self.tolerance & 0x3F),
+            b as u8,
+            (((b >> 8) as u8) << 6) | (self.accuracy & 0x3F) as u8,
+            ((((self.accuracy >> 6) & 0xF) as u8) << 4) | ((self.accuracy_exponent & 0x3) << 2),
+            ((self.result_exponent as u8 & 0xF) << 4) | (self.b_exponent as u8 & 0xF),
+        ]
+    }
+
+    /// Apply the linear conversion `(M * x + B * 10^B_exp) * 10^R_exp` to `value`.
+    pub fn apply(&self, value: f32) -> f32 {
+        let b = self.b as f32 * 10f32.powi(self.b_exponent as i32);
+        let result_mul = 10f32.powi(self.result_exponent as i32);
+
+        (self.m as f32 * value + b) * result_mul
+    }
+
+    /// Apply the inverse of the linear conversion to `value`.
+    ///
+    /// Returns `None` if M is 0.
+    pub fn invert(&self, value: f32) -> Option<f32> {
+        if self.m == 0 {
+            return None;
+        }
+
+        let b = self.b as f32 * 10f32.powi(self.b_exponent as i32);
+        let result_mul = 10f32.powi(self.result_exponent as i32);
+
+        Some((value / result_mul - b) / self.m as f32)
+    }
+
+    /// The tolerance, as an absolute difference in the units of the sensor.
+    pub fn tolerance(&self) -> f32 {
+        let result_mul = 10f32.powi(self.result_exponent as i32);
+
+        self.m.unsigned_abs() as f32 * self.tolerance as f32 / 2.0 * result_mul
+    }
+
+    /// The accuracy, in percent.
+    pub fn accuracy_percent(&self) -> f32 {
+        self.accuracy as f32 * 10f32.powi(self.accuracy_exponent as i32) / 100.0
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
 
 pub struct FullSensorRecord {
     common: SensorRecordCommon,
@@ -69,54 +172,20 @@ impl FullSensorRecord {
         let linearization = record_data[0];
         let linearization = Linearization::from(linearization & 0x7F);
 
-        let m_lsb = record_data[1];
-        let m_msb_tolerance = record_data[2];
-        let m_sign = if m_msb_tolerance & 0x80 == 0x80 {
-            0b11111100
-        } else {
-            0
-        };
-
-        let m = i16::from_le_bytes([m_lsb, m_sign | (m_msb_tolerance >> 6) & 0x1]);
-
-        let tolerance = m_msb_tolerance & 0x3F;
-
-        let b_lsb = record_data[3];
-        let b_msb_accuracy_lsb = record_data[4];
-
-        let b_sign = if b_msb_accuracy_lsb & 0x80 == 0x80 {
-            0b11111100
-        } else {
-            0
-        };
-        let b = i16::from_le_bytes([b_lsb, b_sign | (b_msb_accuracy_lsb >> 6)]);
+        let factors =
+            ConversionFactors::parse(&record_data[1..7]).ok_or(ParseError::NotEnoughData)?;
 
-        let accuracy_msb_accuracy_exp_sensor_dir = record_data[5];
-
-        let accuracy = u16::from_le_bytes([
-            (accuracy_msb_accuracy_exp_sensor_dir >> 4) & 0xF,
-            (b_msb_accuracy_lsb & 0x3F),
-        ]);
-
-        let accuracy_exponent = (accuracy_msb_accuracy_exp_sensor_dir >> 2) & 0x3;
-
-        let direction = Direction::try_from(accuracy_msb_accuracy_exp_sensor_dir & 0b11)?;
-
-        let r_exp_b_exp = record_data[6];
-
-        let r_sign = if r_exp_b_exp & 0x80 == 0x80 {
-            0b11111000
-        } else {
-            0
-        };
-        let result_exponent = (r_sign | ((r_exp_b_exp >> 4) & 0x7)) as i8;
+        let ConversionFactors {
+            m,
+            tolerance,
+            b,
+            accuracy,
+            accuracy_exponent,
+            result_exponent,
+            b_exponent,
+        } = factors;
 
-        let b_sign = if r_exp_b_exp & 0x8 == 0x8 {
-            0b11111000
-        } else {
-            0
-        };
-        let b_exponent = (b_sign | (r_exp_b_exp & 0x7)) as i8;
+        let direction = Direction::try_from(record_data[5] & 0b11)?;
 
         let analog_characteristics = record_data[7];
 
@@ -193,6 +262,52 @@ impl FullSensorRecord {
         })
     }
 
+    /// Encode this record as the record data that [`FullSensorRecord::parse`] parses.
+    pub fn encode(&self) -> Result<Vec<u8>, EncodeError> {
+        let analog_data_format = match self.analog_data_format {
+            Some(DataFormat::Unsigned) => 0b00,
+            Some(DataFormat::OnesComplement) => 0b01,
+            Some(DataFormat::TwosComplement) => 0b10,
+            None => 0b11,
+        };
+
+        let mut factors = self.factors().encode();
+        factors[4] |= u8::from(self.direction);
+
+        let analog_characteristics = (self.nominal_reading.is_some() as u8)
+            | ((self.normal_maximum.is_some() as u8) << 1)
+            | ((self.normal_minimum.is_some() as u8) << 2);
+
+        let mut data = self.common.encode_without_id(analog_data_format);
+        data.push(u8::from(self.linearization) & 0x7F);
+        data.extend_from_slice(&factors);
+        data.extend_from_slice(&[
+            analog_characteristics,
+            self.nominal_reading.unwrap_or(0),
+            self.normal_maximum.unwrap_or(0),
+            self.normal_minimum.unwrap_or(0),
+            self.max_reading,
+            self.min_reading,
+            self.upper_non_recoverable_threshold,
+            self.upper_critical_threshold,
+            self.upper_non_critical_threshold,
+            self.lower_non_recoverable_threshold,
+            self.lower_critical_threshold,
+            self.lower_non_critical_threshold,
+            self.positive_going_threshold_hysteresis_value
+                .map_or(0, NonZeroU8::get),
+            self.negative_going_threshold_hysteresis_value
+                .map_or(0, NonZeroU8::get),
+            // Two reserved bytes
+            0x00,
+            0x00,
+            self.oem_data,
+        ]);
+        data.extend(self.common.sensor_id.encode()?);
+
+        Ok(data)
+    }
+
     pub fn threshold(&self, kind: ThresholdKind) -> Threshold {
         let readable = self.capabilities().threshold_access.readable(kind);
         let settable = self.capabilities().threshold_access.settable(kind);
@@ -217,23 +332,109 @@ impl FullSensorRecord {
         }
     }
 
-    fn convert(&self, value: u8) -> Option<Value> {
-        let m = self.m as f32;
-        let b = self.b as f32 * 10f32.powf(self.b_exponent as f32);
-        let result_mul = 10f32.powf(self.result_exponent as f32);
-        let format = self.analog_data_format?;
+    /// The conversion factors of this sensor.
+    pub fn factors(&self) -> ConversionFactors {
+        ConversionFactors {
+            m: self.m,
+            tolerance: self.tolerance,
+            b: self.b,
+            accuracy: self.accuracy,
+            accuracy_exponent: self.accuracy_exponent,
+            result_exponent: self.result_exponent,
+            b_exponent: self.b_exponent,
+        }
+    }
 
-        let value = match format {
+    fn decode_raw(&self, value: u8) -> Option<f32> {
+        let value = match self.analog_data_format? {
             DataFormat::Unsigned => value as f32,
-            DataFormat::OnesComplement => !value as i8 as f32,
+            DataFormat::OnesComplement if value & 0x80 == 0x80 => -((!value) as f32),
+            DataFormat::OnesComplement => value as f32,
             DataFormat::TwosComplement => value as i8 as f32,
         };
 
-        let value = (m * value + b) * result_mul;
+        Some(value)
+    }
+
+    /// Convert the raw value `value` to a value in the units of this sensor.
+    ///
+    /// Returns `None` for non-linear sensors, as the conversion factors of
+    /// those depend on the reading. Use [`FullSensorRecord::convert_with_factors`]
+    /// with the factors returned by Get Sensor Reading Factors instead.
+    pub fn convert(&self, value: u8) -> Option<Value> {
+        let value = self.factors().apply(self.decode_raw(value)?);
+        let value = self.linearization.apply(value)?;
+
+        Some(Value::new(self.common().sensor_units, value))
+    }
+
+    /// Convert the raw value `value` to a value in the units of this sensor,
+    /// using `factors` instead of the conversion factors of this sensor.
+    ///
+    /// This is used for non-linear sensors, for which `factors` are the
+    /// conversion factors returned by Get Sensor Reading Factors for `value`.
+    pub fn convert_with_factors(&self, value: u8, factors: &ConversionFactors) -> Option<Value> {
+        let value = factors.apply(self.decode_raw(value)?);
 
         Some(Value::new(self.common().sensor_units, value))
     }
 
+    /// Convert `value`, in the units of this sensor, to the raw value
+    /// that is closest to it.
+    ///
+    /// Returns `None` if this sensor has no analog data format, if it is
+    /// non-linear, or if `value` is out of the range of raw values.
+    pub fn raw_value(&self, value: f32) -> Option<u8> {
+        let format = self.analog_data_format?;
+        let value = self.linearization.invert(value)?;
+        let raw = self.factors().invert(value)?.round();
+
+        match format {
+            DataFormat::Unsigned if (0.0..=255.0).contains(&raw) => Some(raw as u8),
+            DataFormat::OnesComplement if (-127.0..0.0).contains(&raw) => Some(!((-raw) as u8)),
+            DataFormat::OnesComplement if (0.0..=127.0).contains(&raw) => Some(raw as u8),
+            DataFormat::TwosComplement if (-128.0..=127.0).contains(&raw) => Some(raw as i8 as u8),
+            _ => None,
+        }
+    }
+
+    /// The tolerance of readings of this sensor, in the units of this sensor.
+    pub fn tolerance(&self) -> Value {
+        Value::new(self.common().sensor_units, self.factors().tolerance())
+    }
+
+    /// Convert the raw hysteresis value `value` to a value in the units
+    /// of this sensor.
+    ///
+    /// Hysteresis values are an amount of raw counts, so only M and the
+    /// result exponent of this sensor apply to them.
+    pub fn convert_hysteresis(&self, value: u8) -> Option<Value> {
+        let m = self.m.unsigned_abs() as f32;
+        let result_mul = 10f32.powf(self.result_exponent as f32);
+
+        Some(Value::new(
+            self.common().sensor_units,
+            m * value as f32 * result_mul,
+        ))
+    }
+
+    /// Convert the hysteresis `value`, in the units of this sensor, to the
+    /// raw hysteresis value that is closest to it.
+    ///
+    /// Returns `None` if this sensor is not linear, or if `value` is out of
+    /// the range of raw values.
+    pub fn raw_hysteresis(&self, value: f32) -> Option<u8> {
+        if self.m == 0 || !matches!(self.linearization, Linearization::Linear) {
+            return None;
+        }
+
+        let m = self.m.unsigned_abs() as f32;
+        let result_mul = 10f32.powf(self.result_exponent as f32);
+
+        let raw = (value / result_mul / m).round();
+        (0.0..=255.0).contains(&raw).then_some(raw as u8)
+    }
+
     pub fn display_reading(&self, value: u8) -> Option<String> {
         self.convert(value).map(|v| v.display(true))
     }
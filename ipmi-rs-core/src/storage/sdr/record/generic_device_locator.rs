@@ -4,15 +4,16 @@
 
 use crate::connection::LogicalUnit;
 use crate::storage::sdr::record::{SensorId, TypeLengthRaw};
+use crate::storage::sdr::EntityId;
 
-use super::{IdentifiableSensor, ParseError};
+use super::{EncodeError, IdentifiableSensor, ParseError};
 use std::num::NonZeroU8;
 
 /// Record key for Generic Device Locator Record (SDR Type 10h).
 ///
 /// Reference: IPMI 2.0 Specification, Table 43-6, bytes 6-8
 /// (record data offsets 0-2).
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct GenericDeviceRecordKey {
     /// 7-bit I2C Slave Address of device on the channel.
     pub device_access_address: u8,
@@ -33,7 +34,7 @@ pub struct GenericDeviceRecordKey {
 /// FRU devices nor IPMI management controllers.
 ///
 /// Reference: IPMI 2.0 Specification, Section 43.7 and Table 43-6
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct GenericDeviceLocator {
     /// Record key data.
     pub record_key: GenericDeviceRecordKey,
@@ -50,7 +51,7 @@ pub struct GenericDeviceLocator {
     /// Entity ID for the device.
     ///
     /// Reference: IPMI 2.0 Specification, Table 43-13 "Entity ID Codes"
-    pub entity_id: u8,
+    pub entity_id: EntityId,
     /// Entity Instance.
     ///
     /// Note: The IPMI spec only labels this as "Entity Instance" (Table 43-6)
@@ -67,7 +68,7 @@ impl IdentifiableSensor for GenericDeviceLocator {
         &self.id_string
     }
 
-    fn entity_id(&self) -> u8 {
+    fn entity_id(&self) -> EntityId {
         self.entity_id
     }
 }
@@ -144,7 +145,7 @@ impl GenericDeviceLocator {
         // Byte 7: Entity ID
         //
         // Reference: IPMI 2.0 Spec, Table 43-6 and Table 43-13
-        let entity_id = record_data[7];
+        let entity_id = EntityId::from(record_data[7]);
 
         // Byte 8: Entity Instance
         //
@@ -187,7 +188,50 @@ impl GenericDeviceLocator {
         })
     }
 
+    /// Encode this record as the record data that [`GenericDeviceLocator::parse`] parses.
+    pub fn encode(&self) -> Result<Vec<u8>, EncodeError> {
+        let key = &self.record_key;
+
+        let mut data = vec![
+            key.device_access_address << 1,
+            (key.device_slave_address << 1) | ((key.channel_number >> 3) & 0b1),
+            ((key.channel_number & 0b111) << 5)
+                | (key.access_lun.value() << 3)
+                | key.private_bus_id.map_or(0, |v| v.get() & 0b111),
+            self.address_span & 0b111,
+            // Reserved
+            0x00,
+            self.device_type,
+            self.device_type_modifier,
+            self.entity_id.into(),
+            self.entity_instance,
+            self.oem_reserved,
+        ];
+        data.extend(self.id_string.encode()?);
+
+        Ok(data)
+    }
+
     pub fn id_string(&self) -> &SensorId {
         &self.id_string
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn encode_round_trip() {
+        let data = [
+            0x20, 0xA0, 0x08, 0x00, 0x00, 0x10, 0x00, 0x07, 0x01, 0x00, 0xC4, b'T', b'E', b'M',
+            b'P',
+        ];
+
+        let record = GenericDeviceLocator::parse(&data).unwrap();
+        let encoded = record.encode().unwrap();
+
+        assert_eq!(&data[..], &encoded[..]);
+        assert_eq!(record, GenericDeviceLocator::parse(&encoded).unwrap());
+    }
+}
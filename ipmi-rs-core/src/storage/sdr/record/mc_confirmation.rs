@@ -0,0 +1,117 @@
+use super::ParseError;
+
+/// A Management Controller Confirmation record, which records a
+/// management controller that was discovered on the IPMB.
+///
+/// Reference: IPMI 2.0 Specification, Section 43.10, Table 43-10
+#[derive(Debug, Clone, PartialEq)]
+pub struct McConfirmationRecord {
+    /// The 7-bit slave address of the management controller.
+    pub device_address: u8,
+    pub device_id: u8,
+    pub channel: u8,
+    pub device_revision: u8,
+    pub major_fw_revision: u8,
+    pub minor_fw_revision: u8,
+    /// The major version of the IPMI version supported by the device.
+    pub major_version: u8,
+    /// The minor version of the IPMI version supported by the device.
+    pub minor_version: u8,
+    /// The ID of the manufacturer.
+    pub manufacturer_id: u32,
+    /// The ID of the product.
+    pub product_id: u16,
+    pub device_guid: [u8; 16],
+}
+
+impl McConfirmationRecord {
+    pub fn parse(record_data: &[u8]) -> Result<Self, ParseError> {
+        if record_data.len() < 27 {
+            return Err(ParseError::NotEnoughData);
+        }
+
+        let minor_fw_revision = (record_data[4] >> 4) * 10 + (record_data[4] & 0xF);
+
+        let mut device_guid = [0u8; 16];
+        device_guid.copy_from_slice(&record_data[11..27]);
+
+        Ok(Self {
+            device_address: record_data[0] >> 1,
+            device_id: record_data[1],
+            channel: record_data[2] >> 4,
+            device_revision: record_data[2] & 0xF,
+            major_fw_revision: record_data[3] & 0x7F,
+            minor_fw_revision,
+            major_version: record_data[5] & 0xF,
+            minor_version: record_data[5] >> 4,
+            manufacturer_id: u32::from_le_bytes([
+                record_data[6],
+                record_data[7],
+                record_data[8],
+                0,
+            ]),
+            product_id: u16::from_le_bytes([record_data[9], record_data[10]]),
+            device_guid,
+        })
+    }
+
+    /// Encode this record as the record data that [`McConfirmationRecord::parse`] parses.
+    pub fn encode(&self) -> Vec<u8> {
+        let minor_fw_revision =
+            ((self.minor_fw_revision / 10) << 4) | (self.minor_fw_revision % 10);
+
+        let mut data = vec![
+            self.device_address << 1,
+            self.device_id,
+            (self.channel << 4) | (self.device_revision & 0xF),
+            self.major_fw_revision & 0x7F,
+            minor_fw_revision,
+            (self.minor_version << 4) | (self.major_version & 0xF),
+        ];
+        data.extend_from_slice(&self.manufacturer_id.to_le_bytes()[..3]);
+        data.extend_from_slice(&self.product_id.to_le_bytes());
+        data.extend_from_slice(&self.device_guid);
+        data
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_mc_confirmation() {
+        let mut data = vec![
+            0x20, 0x20, 0x01, 0x02, 0x13, 0x51, 0xA2, 0x02, 0x00, 0x00, 0x01,
+        ];
+        data.extend(0..16);
+
+        let record = McConfirmationRecord::parse(&data).unwrap();
+
+        assert_eq!(0x10, record.device_address);
+        assert_eq!(0, record.channel);
+        assert_eq!(1, record.device_revision);
+        assert_eq!(
+            (2, 13),
+            (record.major_fw_revision, record.minor_fw_revision)
+        );
+        assert_eq!((1, 5), (record.major_version, record.minor_version));
+        assert_eq!(674, record.manufacturer_id);
+        assert_eq!(0x0100, record.product_id);
+        assert_eq!(15, record.device_guid[15]);
+    }
+
+    #[test]
+    fn encode_round_trip() {
+        let mut data = vec![
+            0x20, 0x20, 0x01, 0x02, 0x13, 0x51, 0xA2, 0x02, 0x00, 0x00, 0x01,
+        ];
+        data.extend(0..16);
+
+        let record = McConfirmationRecord::parse(&data).unwrap();
+        let encoded = record.encode();
+
+        assert_eq!(data, encoded);
+        assert_eq!(record, McConfirmationRecord::parse(&encoded).unwrap());
+    }
+}
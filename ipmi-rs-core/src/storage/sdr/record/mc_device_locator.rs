@@ -1,20 +1,21 @@
 use crate::storage::sdr::record::{SensorId, TypeLengthRaw};
+use crate::storage::sdr::EntityId;
 
-use super::{IdentifiableSensor, ParseError};
+use super::{EncodeError, IdentifiableSensor, ParseError};
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct McRecordKey {
     pub i2c_address: u8,
     pub channel: u8,
 }
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum GlobalInitialization {
     EnableEventMessageGeneration,
     DisableEventMessageGeneration,
     DoNotInitialize,
     Reserved,
 }
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct DeviceCapabilities {
     pub chassis_device: bool,
     pub bridge: bool,
@@ -25,7 +26,7 @@ pub struct DeviceCapabilities {
     pub sdr_repository_device: bool,
     pub sensor_device: bool,
 }
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct McDeviceLocatorRecord {
     pub key: McRecordKey,
     pub acpi_system_power_state_notification_required: bool,
@@ -36,7 +37,7 @@ pub struct McDeviceLocatorRecord {
     pub log_initialization_errors_accessing_controller: bool,
     pub global_initialization: GlobalInitialization,
     pub device_capabilities: DeviceCapabilities,
-    pub entity_id: u8,
+    pub entity_id: EntityId,
     // Note: Unlike entity_instance in sensor SDRs, the IPMI specification specifies this as just
     // an entity instance number, hence not using the `EntityInstance` type here.
     pub entity_instance: u8,
@@ -49,7 +50,7 @@ impl IdentifiableSensor for McDeviceLocatorRecord {
         &self.id_string
     }
 
-    fn entity_id(&self) -> u8 {
+    fn entity_id(&self) -> EntityId {
         self.entity_id
     }
 }
@@ -102,7 +103,7 @@ impl McDeviceLocatorRecord {
 
         // 3 reserved bytes
 
-        let entity_id = record_data[7];
+        let entity_id = EntityId::from(record_data[7]);
         let entity_instance = record_data[8];
         let oem_reserved = record_data[9];
 
@@ -127,7 +128,69 @@ impl McDeviceLocatorRecord {
         })
     }
 
+    /// Encode this record as the record data that [`McDeviceLocatorRecord::parse`] parses.
+    pub fn encode(&self) -> Result<Vec<u8>, EncodeError> {
+        let global_initialization = match self.global_initialization {
+            GlobalInitialization::EnableEventMessageGeneration => 0b00,
+            GlobalInitialization::DisableEventMessageGeneration => 0b01,
+            GlobalInitialization::DoNotInitialize => 0b10,
+            GlobalInitialization::Reserved => 0b11,
+        };
+
+        let psn_and_gi = ((self.acpi_system_power_state_notification_required as u8) << 7)
+            | ((self.acpi_device_power_state_notification_required as u8) << 6)
+            | ((self.static_controller as u8) << 5)
+            | ((self.controller_logs_initialization_errors as u8) << 3)
+            | ((self.log_initialization_errors_accessing_controller as u8) << 2)
+            | global_initialization;
+
+        let caps = &self.device_capabilities;
+        let dc_byte = ((caps.chassis_device as u8) << 7)
+            | ((caps.bridge as u8) << 6)
+            | ((caps.ipmi_event_generator as u8) << 5)
+            | ((caps.ipmi_event_receiver as u8) << 4)
+            | ((caps.fru_inventory_device as u8) << 3)
+            | ((caps.sel_device as u8) << 2)
+            | ((caps.sdr_repository_device as u8) << 1)
+            | (caps.sensor_device as u8);
+
+        let mut data = vec![
+            self.key.i2c_address << 1,
+            self.key.channel & 0b1111,
+            psn_and_gi,
+            dc_byte,
+            // 3 reserved bytes
+            0x00,
+            0x00,
+            0x00,
+            self.entity_id.into(),
+            self.entity_instance,
+            self.oem_reserved,
+        ];
+        data.extend(self.id_string.encode()?);
+
+        Ok(data)
+    }
+
     pub fn id_string(&self) -> &SensorId {
         &self.id_string
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn encode_round_trip() {
+        let data = [
+            0x20, 0x00, 0x20, 0xAF, 0x00, 0x00, 0x00, 0x06, 0x01, 0x00, 0xC3, b'B', b'M', b'C',
+        ];
+
+        let record = McDeviceLocatorRecord::parse(&data).unwrap();
+        let encoded = record.encode().unwrap();
+
+        assert_eq!(&data[..], &encoded[..]);
+        assert_eq!(record, McDeviceLocatorRecord::parse(&encoded).unwrap());
+    }
+}
@@ -1,26 +1,41 @@
 mod full_sensor_record;
-pub use full_sensor_record::FullSensorRecord;
+pub use full_sensor_record::{ConversionFactors, FullSensorRecord};
 
+mod bmc_channel_info;
 mod compact_sensor_record;
+mod entity_association;
 mod event_only_sensor_record;
 mod fru_device_locator;
 mod generic_device_locator;
+mod mc_confirmation;
 mod mc_device_locator;
+mod oem;
 
 pub mod traits;
 pub use traits::*;
 
-pub use compact_sensor_record::CompactSensorRecord;
+pub use bmc_channel_info::{BmcMessageChannelInfoRecord, MessageChannelInfo, SystemInterrupt};
+pub use compact_sensor_record::{
+    CompactSensorRecord, IdStringModifier, RecordSharing, SharedSensor,
+};
+pub use entity_association::{ContainedEntity, Entity, EntityAssociationRecord, EntityDevice};
+pub use fru_device_locator::{
+    FruDevice, FruDeviceLocator, FruRecordKey, LogicalFruDevice, PhysicalFruDevice,
+};
 pub use generic_device_locator::GenericDeviceLocator;
+pub use mc_confirmation::McConfirmationRecord;
+pub use oem::{OemRecord, OemRecordDecoders};
 
 use nonmax::NonMaxU8;
 
 use crate::connection::{Channel, LogicalUnit};
 use crate::storage::sdr::record::event_only_sensor_record::EventOnlySensorRecord;
-use crate::storage::sdr::record::fru_device_locator::FruDeviceLocator;
 use crate::storage::sdr::record::mc_device_locator::McDeviceLocatorRecord;
 
-use super::{event_reading_type_code::EventReadingTypeCodes, RecordId, SensorType, Unit};
+use super::{
+    event_reading_type_code::EventReadingTypeCodes, units::IsPlural, EntityId, Quantity, RecordId,
+    SensorType, Unit,
+};
 
 #[derive(Debug)]
 pub struct Value {
@@ -33,14 +48,28 @@ impl Value {
         Self { units, value }
     }
 
+    /// The units of this value.
+    pub fn units(&self) -> &SensorUnits {
+        &self.units
+    }
+
+    /// The numeric value, in the units of this value.
+    pub fn value(&self) -> f32 {
+        self.value
+    }
+
+    /// The value of this sensor reading as a [`Quantity`], which can be
+    /// converted to SI base units.
+    pub fn quantity(&self) -> Quantity {
+        Quantity::new(self.value, self.units)
+    }
+
     pub fn display(&self, short: bool) -> String {
-        if self.units.is_percentage {
-            format!("{:.2} %", self.value)
-        } else {
-            // TODO: use Modifier unit and rate units
-            // somehow here
-            self.units.base_unit.display(short, self.value)
-        }
+        format!(
+            "{:.2} {}",
+            self.value,
+            self.units.display_str(short, self.value.is_plural())
+        )
     }
 }
 
@@ -80,6 +109,17 @@ impl SensorKey {
             sensor_number,
         })
     }
+
+    /// Encode this sensor key as the 3 bytes that [`SensorKey::parse`] parses.
+    pub fn encode(&self) -> [u8; 3] {
+        [
+            self.owner_id.into(),
+            (self.owner_channel.value() << 4)
+                | (self.fru_inv_device_owner_lun.value() << 2)
+                | self.owner_lun.value(),
+            self.sensor_number.get(),
+        ]
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -109,13 +149,13 @@ impl From<SensorOwner> for u8 {
     }
 }
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub enum EntityRelativeTo {
     System,
     Device,
 }
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 
 pub enum EntityInstance {
     Physical {
@@ -128,6 +168,19 @@ pub enum EntityInstance {
     },
 }
 
+impl From<EntityInstance> for u8 {
+    fn from(value: EntityInstance) -> Self {
+        match value {
+            EntityInstance::Physical {
+                instance_number, ..
+            } => instance_number,
+            EntityInstance::LogicalContainer {
+                instance_number, ..
+            } => 0x80 | instance_number,
+        }
+    }
+}
+
 impl From<u8> for EntityInstance {
     fn from(value: u8) -> Self {
         let instance_number = value & 0x7F;
@@ -185,7 +238,7 @@ impl From<u8> for SensorInitialization {
             scanning: flags.contains(Flags::SCANNING),
             events: flags.contains(Flags::EVENTS),
             thresholds: flags.contains(Flags::THRESHOLDS),
-            hysteresis: flags.contains(Flags::THRESHOLDS),
+            hysteresis: flags.contains(Flags::HYSTERESIS),
             sensor_type: flags.contains(Flags::TYPE),
             event_generation_enabled_on_startup: flags.contains(Flags::EVENTGEN_ON_STARTUP),
             sensor_scanning_enabled_on_startup: flags.contains(Flags::SCANNING_ON_STARTUP),
@@ -193,7 +246,24 @@ impl From<u8> for SensorInitialization {
     }
 }
 
-#[derive(Debug, Clone, Copy)]
+impl From<SensorInitialization> for u8 {
+    fn from(value: SensorInitialization) -> Self {
+        [
+            value.settable,
+            value.scanning,
+            value.events,
+            value.thresholds,
+            value.hysteresis,
+            value.sensor_type,
+            value.event_generation_enabled_on_startup,
+            value.sensor_scanning_enabled_on_startup,
+        ]
+        .into_iter()
+        .fold(0, |acc, flag| (acc << 1) | flag as u8)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub enum HysteresisCapability {
     NoneOrUnspecified,
     Readable,
@@ -219,6 +289,42 @@ bitflags::bitflags! {
     }
 }
 
+bitflags::bitflags! {
+    /// A mask of the event offsets (states) of a discrete sensor.
+    ///
+    /// Reference: IPMI 2.0 Specification, Section 43.1, Table 43-1
+    pub struct DiscreteEventMask: u16 {
+        const STATE_14 = 1 << 14;
+        const STATE_13 = 1 << 13;
+        const STATE_12 = 1 << 12;
+        const STATE_11 = 1 << 11;
+        const STATE_10 = 1 << 10;
+        const STATE_9 = 1 << 9;
+        const STATE_8 = 1 << 8;
+        const STATE_7 = 1 << 7;
+        const STATE_6 = 1 << 6;
+        const STATE_5 = 1 << 5;
+        const STATE_4 = 1 << 4;
+        const STATE_3 = 1 << 3;
+        const STATE_2 = 1 << 2;
+        const STATE_1 = 1 << 1;
+        const STATE_0 = 1 << 0;
+    }
+}
+
+impl DiscreteEventMask {
+    /// Get the mask for event offset `offset`, if it is a valid offset.
+    pub fn offset(offset: u8) -> Option<Self> {
+        Self::from_bits(1u16.checked_shl(offset as u32)?)
+    }
+
+    /// Iterate over the event offsets contained in this mask.
+    pub fn offsets(&self) -> impl Iterator<Item = u8> {
+        let bits = self.bits();
+        (0..15).filter(move |offset| bits & (1 << offset) != 0)
+    }
+}
+
 impl ThresholdAssertEventMask {
     pub fn for_kind(&self, kind: ThresholdKind) -> &[EventKind] {
         static BOTH: [EventKind; 2] = [EventKind::GoingHigh, EventKind::GoingLow];
@@ -271,7 +377,7 @@ pub enum EventKind {
     GoingLow,
 }
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 
 pub struct Thresholds {
     pub lower_non_recoverable: bool,
@@ -293,6 +399,22 @@ impl Thresholds {
             ThresholdKind::UpperNonRecoverable => self.upper_non_recoverable,
         }
     }
+
+    /// The bits of these thresholds in the layout of the readable and
+    /// settable threshold masks, from lower non-critical (bit 0) to upper
+    /// non-recoverable (bit 5).
+    fn bits(&self) -> u16 {
+        [
+            self.upper_non_recoverable,
+            self.upper_critical,
+            self.upper_non_critical,
+            self.lower_non_recoverable,
+            self.lower_critical,
+            self.lower_non_critical,
+        ]
+        .into_iter()
+        .fold(0, |acc, flag| (acc << 1) | flag as u16)
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -330,7 +452,7 @@ pub struct Threshold {
     pub event_deassert_going_low: bool,
 }
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub enum ThresholdAccessCapability {
     None,
     Readable {
@@ -368,7 +490,7 @@ impl ThresholdAccessCapability {
     }
 }
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub struct SensorCapabilities {
     pub ignore: bool,
     pub auto_rearm: bool,
@@ -378,6 +500,11 @@ pub struct SensorCapabilities {
     pub threshold_access: ThresholdAccessCapability,
     pub assertion_threshold_events: ThresholdAssertEventMask,
     pub deassertion_threshold_events: ThresholdAssertEventMask,
+    // The raw masks, which also hold the bits that the fields above do not
+    // describe, such as the event masks of discrete sensors.
+    assertion_mask: u16,
+    deassertion_mask: u16,
+    reading_mask: u16,
 }
 
 impl SensorCapabilities {
@@ -389,7 +516,7 @@ impl SensorCapabilities {
     ) -> Self {
         let ignore = (caps & 0x80) == 0x80;
         let auto_rearm = (caps & 0x40) == 0x40;
-        let hysteresis = match caps & 0x30 >> 4 {
+        let hysteresis = match (caps & 0x30) >> 4 {
             0b00 => HysteresisCapability::NoneOrUnspecified,
             0b01 => HysteresisCapability::Readable,
             0b10 => HysteresisCapability::ReadableAndSettable,
@@ -407,8 +534,8 @@ impl SensorCapabilities {
             lower_critical: ((assert_lower_thrsd >> 13) & 0x1) == 1,
             lower_non_critical: ((assert_lower_thrsd >> 12) & 0x1) == 1,
             upper_non_recoverable: ((deassert_upper_thrshd >> 14) & 0x1) == 1,
-            upper_critical: ((deassert_upper_thrshd >> 14) & 0x1) == 1,
-            upper_non_critical: ((deassert_upper_thrshd >> 14) & 0x1) == 1,
+            upper_critical: ((deassert_upper_thrshd >> 13) & 0x1) == 1,
+            upper_non_critical: ((deassert_upper_thrshd >> 12) & 0x1) == 1,
         };
 
         let threshold_set_mask = Thresholds {
@@ -454,8 +581,68 @@ impl SensorCapabilities {
             threshold_access: threshold_access_support,
             assertion_threshold_events: assertion_event_mask,
             deassertion_threshold_events: deassertion_event_mask,
+            assertion_mask: assert_lower_thrsd,
+            deassertion_mask: deassert_upper_thrshd,
+            reading_mask: discrete_rd_thrsd_set_thrshd_read,
         }
     }
+
+    /// Encode these capabilities as the sensor capabilities byte, and the
+    /// assertion, deassertion and settable/readable masks that
+    /// [`SensorCapabilities::new`] takes.
+    ///
+    /// The bits described by the fields of these capabilities take
+    /// precedence over the raw masks that they were parsed from.
+    pub fn encode(&self) -> (u8, u16, u16, u16) {
+        let hysteresis = match self.hysteresis {
+            HysteresisCapability::NoneOrUnspecified => 0b00,
+            HysteresisCapability::Readable => 0b01,
+            HysteresisCapability::ReadableAndSettable => 0b10,
+            HysteresisCapability::FixedAndUnreadable => 0b11,
+        };
+
+        let (access, readable, values, settable) = match self.threshold_access {
+            ThresholdAccessCapability::None => (0b00, None, None, None),
+            ThresholdAccessCapability::Readable { readable, values } => {
+                (0b01, Some(readable), Some(values), None)
+            }
+            ThresholdAccessCapability::ReadableAndSettable {
+                readable,
+                values,
+                settable,
+            } => (0b10, Some(readable), Some(values), Some(settable)),
+            ThresholdAccessCapability::FixedAndUnreadable { supported } => {
+                (0b11, Some(supported), None, None)
+            }
+        };
+
+        let caps = ((self.ignore as u8) << 7)
+            | ((self.auto_rearm as u8) << 6)
+            | (hysteresis << 4)
+            | (access << 2)
+            | (self.event_message_control & 0b11);
+
+        let mut assertion =
+            (self.assertion_mask & !0x0FFF) | self.assertion_threshold_events.bits();
+        let mut deassertion =
+            (self.deassertion_mask & !0x0FFF) | self.deassertion_threshold_events.bits();
+        let mut reading = self.reading_mask;
+
+        if let Some(values) = values {
+            assertion = (assertion & !0x7000) | ((values.bits() & 0b111) << 12);
+            deassertion = (deassertion & !0x7000) | (((values.bits() >> 3) & 0b111) << 12);
+        }
+
+        if let Some(readable) = readable {
+            reading = (reading & !0x003F) | readable.bits();
+        }
+
+        if let Some(settable) = settable {
+            reading = (reading & !0x3F00) | (settable.bits() << 8);
+        }
+
+        (caps, assertion, deassertion, reading)
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -475,13 +662,45 @@ pub enum RateUnit {
     Day,
 }
 
+impl RateUnit {
+    /// The duration of this rate unit, in seconds.
+    pub fn seconds(&self) -> f64 {
+        match self {
+            RateUnit::Microsecond => 1e-6,
+            RateUnit::Millisecond => 1e-3,
+            RateUnit::Second => 1.0,
+            RateUnit::Minute => 60.0,
+            RateUnit::Hour => 3600.0,
+            RateUnit::Day => 86400.0,
+        }
+    }
+
+    /// The unit that this rate is expressed in.
+    pub fn unit(&self) -> Unit {
+        match self {
+            RateUnit::Microsecond => Unit::Microsecond,
+            RateUnit::Millisecond => Unit::Millisecond,
+            RateUnit::Second => Unit::Second,
+            RateUnit::Minute => Unit::Minute,
+            RateUnit::Hour => Unit::Hour,
+            RateUnit::Day => Unit::Day,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum ModifierUnit {
     BasUnitDivByModifier(Unit),
     BaseUnitMulByModifier(Unit),
 }
 
-#[derive(Debug, Clone, Copy)]
+/// The units of a sensor reading.
+///
+/// A reading is expressed in the base unit, multiplied or divided by the
+/// modifier unit (if any), per rate unit (if any).
+///
+/// Reference: IPMI 2.0 Specification, Section 43.1, Table 43-1, byte 21
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub struct SensorUnits {
     pub rate: Option<RateUnit>,
     pub modifier: Option<ModifierUnit>,
@@ -524,10 +743,77 @@ impl SensorUnits {
             is_percentage,
         }
     }
-}
 
-#[derive(Debug, Clone, Copy)]
+    /// Encode these units as the sensor units 1 (without the analog data
+    /// format), base unit and modifier unit bytes.
+    pub fn encode(&self) -> (u8, u8, u8) {
+        let rate = match self.rate {
+            None => 0b000,
+            Some(RateUnit::Microsecond) => 0b001,
+            Some(RateUnit::Millisecond) => 0b010,
+            Some(RateUnit::Second) => 0b011,
+            Some(RateUnit::Minute) => 0b100,
+            Some(RateUnit::Hour) => 0b101,
+            Some(RateUnit::Day) => 0b110,
+        };
+
+        let (modifier, modifier_unit) = match self.modifier {
+            None => (0b00, 0),
+            Some(ModifierUnit::BasUnitDivByModifier(unit)) => (0b01, unit.into()),
+            Some(ModifierUnit::BaseUnitMulByModifier(unit)) => (0b10, unit.into()),
+        };
+
+        let sensor_units_1 = (rate << 3) | (modifier << 1) | self.is_percentage as u8;
+
+        (sensor_units_1, self.base_unit.into(), modifier_unit)
+    }
+
+    /// The composed name of these units, using unit symbols where
+    /// available if `short` is set.
+    ///
+    /// For example, a base unit of Watt multiplied by a modifier unit of
+    /// Hour is displayed as `W*h` or `Watts * Hour`, and a base unit of
+    /// Cubic Feet per Minute with a rate unit of Minute is displayed as
+    /// `cfm/min` or `Cubic Foot per Minute per Minute`.
+    pub fn display_str(&self, short: bool, plural: bool) -> String {
+        if self.is_percentage {
+            return "%".into();
+        }
+
+        let mut value = self.base_unit.display_str(short, plural).to_string();
+
+        let (mul, div, per) = if short {
+            ("*", "/", "/")
+        } else {
+            (" * ", " / ", " per ")
+        };
 
+        match self.modifier {
+            Some(ModifierUnit::BaseUnitMulByModifier(unit)) => {
+                value.push_str(mul);
+                value.push_str(unit.display_str(short, false));
+            }
+            Some(ModifierUnit::BasUnitDivByModifier(unit)) => {
+                value.push_str(div);
+                value.push_str(unit.display_str(short, false));
+            }
+            None => {}
+        }
+
+        if let Some(rate) = self.rate {
+            value.push_str(per);
+            value.push_str(rate.unit().display_str(short, false));
+        }
+
+        value
+    }
+}
+
+/// The linearization function of a sensor, which is applied after
+/// the linear conversion of a raw reading.
+///
+/// Reference: IPMI 2.0 Specification, Section 36.3, Table 43-1
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub enum Linearization {
     Linear,
     Ln,
@@ -541,10 +827,36 @@ pub enum Linearization {
     Cube,
     Sqrt,
     CubeRoot,
+    /// The sensor is non-linear, and the conversion factors for a reading
+    /// must be retrieved using Get Sensor Reading Factors.
+    NonLinear,
+    /// The sensor is non-linear in an OEM-defined way, and the conversion
+    /// factors for a reading must be retrieved using Get Sensor Reading Factors.
     Oem(u8),
     Unknown(u8),
 }
 
+impl From<Linearization> for u8 {
+    fn from(value: Linearization) -> Self {
+        match value {
+            Linearization::Linear => 0,
+            Linearization::Ln => 1,
+            Linearization::Log10 => 2,
+            Linearization::Log2 => 3,
+            Linearization::E => 4,
+            Linearization::Exp10 => 5,
+            Linearization::Exp2 => 6,
+            Linearization::OneOverX => 7,
+            Linearization::Sqr => 8,
+            Linearization::Cube => 9,
+            Linearization::Sqrt => 10,
+            Linearization::CubeRoot => 11,
+            Linearization::NonLinear => 0x70,
+            Linearization::Oem(v) | Linearization::Unknown(v) => v,
+        }
+    }
+}
+
 impl From<u8> for Linearization {
     fn from(value: u8) -> Self {
         match value {
@@ -557,16 +869,74 @@ impl From<u8> for Linearization {
             6 => Self::Exp2,
             7 => Self::OneOverX,
             8 => Self::Sqr,
-            9 => Self::Sqrt,
-            10 => Self::Cube,
-            11 => Self::Sqrt,
-            12 => Self::CubeRoot,
+            9 => Self::Cube,
+            10 => Self::Sqrt,
+            11 => Self::CubeRoot,
+            0x70 => Self::NonLinear,
             0x71..=0x7F => Self::Oem(value),
             v => Self::Unknown(v),
         }
     }
 }
 
+impl Linearization {
+    /// Whether the conversion factors of a sensor with this linearization
+    /// depend on the reading, and must be retrieved using Get Sensor
+    /// Reading Factors.
+    pub fn is_non_linear(&self) -> bool {
+        matches!(self, Self::NonLinear | Self::Oem(_) | Self::Unknown(_))
+    }
+
+    /// Apply this linearization function to `value`.
+    ///
+    /// Returns `None` for non-linear sensors, or if the result is not
+    /// a finite number.
+    pub fn apply(&self, value: f32) -> Option<f32> {
+        let result = match self {
+            Self::Linear => value,
+            Self::Ln => value.ln(),
+            Self::Log10 => value.log10(),
+            Self::Log2 => value.log2(),
+            Self::E => value.exp(),
+            Self::Exp10 => 10f32.powf(value),
+            Self::Exp2 => value.exp2(),
+            Self::OneOverX => value.recip(),
+            Self::Sqr => value.powi(2),
+            Self::Cube => value.powi(3),
+            Self::Sqrt => value.sqrt(),
+            Self::CubeRoot => value.cbrt(),
+            Self::NonLinear | Self::Oem(_) | Self::Unknown(_) => return None,
+        };
+
+        result.is_finite().then_some(result)
+    }
+
+    /// Apply the inverse of this linearization function to `value`.
+    ///
+    /// Returns `None` for non-linear sensors, or if the result is not
+    /// a finite number.
+    pub fn invert(&self, value: f32) -> Option<f32> {
+        let result = match self {
+            Self::Linear => value,
+            Self::Ln => value.exp(),
+            Self::Log10 => 10f32.powf(value),
+            Self::Log2 => value.exp2(),
+            Self::E => value.ln(),
+            Self::Exp10 => value.log10(),
+            Self::Exp2 => value.log2(),
+            Self::OneOverX => value.recip(),
+            Self::Sqr => value.sqrt(),
+            Self::Cube => value.cbrt(),
+            Self::Sqrt if value < 0.0 => return None,
+            Self::Sqrt => value.powi(2),
+            Self::CubeRoot => value.powi(3),
+            Self::NonLinear | Self::Oem(_) | Self::Unknown(_) => return None,
+        };
+
+        result.is_finite().then_some(result)
+    }
+}
+
 #[derive(Clone, Copy, Debug, PartialEq)]
 pub enum Direction {
     UnspecifiedNotApplicable,
@@ -588,6 +958,16 @@ impl TryFrom<u8> for Direction {
     }
 }
 
+impl From<Direction> for u8 {
+    fn from(value: Direction) -> Self {
+        match value {
+            Direction::UnspecifiedNotApplicable => 0b00,
+            Direction::Input => 0b01,
+            Direction::Output => 0b10,
+        }
+    }
+}
+
 #[derive(Debug)]
 pub enum ParseError {
     NotEnoughData,
@@ -599,6 +979,21 @@ pub enum ParseError {
     InvalidOwnerChannel,
 }
 
+/// An error that occured while encoding a record.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum EncodeError {
+    /// An ID string is longer than the 31 bytes that can be encoded.
+    IdStringTooLong,
+    /// An ID string contains characters that can not be represented in
+    /// its encoding.
+    InvalidIdString,
+    /// The record is longer than the 255 bytes that can be encoded.
+    RecordTooLong,
+    /// The contained entities of an entity association record can not be
+    /// encoded: there are too many, or ranges and single entities are mixed.
+    InvalidContainedEntities,
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub struct TypeLengthRaw<'a>(u8, &'a [u8]);
 
@@ -643,6 +1038,34 @@ pub enum SensorId {
     Ascii8BAndLatin1(String),
 }
 
+impl SensorId {
+    /// Encode this ID as a type/length byte, followed by the ID bytes.
+    ///
+    /// Reference: IPMI 2.0 Specification, Section 43.15
+    pub fn encode(&self) -> Result<Vec<u8>, EncodeError> {
+        let (type_code, data) = match self {
+            SensorId::Unicode(v) => (0b00, v.as_bytes().to_vec()),
+            SensorId::BCDPlus(v) => (0b01, v.clone()),
+            SensorId::Ascii6BPacked(v) => (0b10, v.clone()),
+            SensorId::Ascii8BAndLatin1(v) => (
+                0b11,
+                v.chars()
+                    .map(|c| u8::try_from(c).map_err(|_| EncodeError::InvalidIdString))
+                    .collect::<Result<Vec<_>, _>>()?,
+            ),
+        };
+
+        if data.len() > 0x1F {
+            return Err(EncodeError::IdStringTooLong);
+        }
+
+        let mut encoded = Vec::with_capacity(data.len() + 1);
+        encoded.push((type_code << 6) | data.len() as u8);
+        encoded.extend(data);
+        Ok(encoded)
+    }
+}
+
 impl core::fmt::Display for SensorId {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -673,7 +1096,7 @@ impl SensorNumber {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct RecordHeader {
     pub id: RecordId,
 
@@ -681,20 +1104,25 @@ pub struct RecordHeader {
     pub sdr_version_minor: u8,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct Record {
     pub header: RecordHeader,
     pub contents: RecordContents,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum RecordContents {
     FullSensor(FullSensorRecord),
     CompactSensor(CompactSensorRecord),
     EventOnlySensor(EventOnlySensorRecord),
+    EntityAssociation(EntityAssociationRecord),
+    DeviceRelativeEntityAssociation(EntityAssociationRecord),
     GenericDeviceLocator(GenericDeviceLocator),
     FruDeviceLocator(FruDeviceLocator),
     McDeviceLocator(McDeviceLocatorRecord),
+    McConfirmation(McConfirmationRecord),
+    BmcMessageChannelInfo(BmcMessageChannelInfoRecord),
+    Oem(OemRecord),
     Unknown { ty: u8, data: Vec<u8> },
 }
 
@@ -715,8 +1143,25 @@ impl Record {
         self.contents.event_only()
     }
 
+    /// The size of the header of a record, in bytes.
+    pub const HEADER_SIZE: usize = 5;
+
+    /// Split the raw record at the start of `data`, including its header,
+    /// from the data that follows it.
+    ///
+    /// Returns `None` if `data` does not start with a complete record.
+    pub fn split_raw(data: &[u8]) -> Option<(&[u8], &[u8])> {
+        let len = Self::HEADER_SIZE + *data.get(4)? as usize;
+
+        if data.len() < len {
+            return None;
+        }
+
+        Some(data.split_at(len))
+    }
+
     pub fn parse(data: &[u8]) -> Result<Self, ParseError> {
-        if data.len() < 5 {
+        if data.len() < Self::HEADER_SIZE {
             return Err(ParseError::NotEnoughData);
         }
 
@@ -726,7 +1171,7 @@ impl Record {
         let record_type = data[3];
         let record_length = data[4];
 
-        let record_data = &data[5..];
+        let record_data = &data[Self::HEADER_SIZE..];
         if record_data.len() != record_length as usize {
             return Err(ParseError::IncorrectRecordLength);
         }
@@ -737,12 +1182,24 @@ impl Record {
             RecordContents::CompactSensor(CompactSensorRecord::parse(record_data)?)
         } else if record_type == 0x03 {
             RecordContents::EventOnlySensor(EventOnlySensorRecord::parse(record_data)?)
+        } else if record_type == 0x08 {
+            RecordContents::EntityAssociation(EntityAssociationRecord::parse(record_data)?)
+        } else if record_type == 0x09 {
+            RecordContents::DeviceRelativeEntityAssociation(
+                EntityAssociationRecord::parse_device_relative(record_data)?,
+            )
         } else if record_type == 0x10 {
             RecordContents::GenericDeviceLocator(GenericDeviceLocator::parse(record_data)?)
         } else if record_type == 0x11 {
             RecordContents::FruDeviceLocator(FruDeviceLocator::parse(record_data)?)
         } else if record_type == 0x12 {
             RecordContents::McDeviceLocator(McDeviceLocatorRecord::parse(record_data)?)
+        } else if record_type == 0x13 {
+            RecordContents::McConfirmation(McConfirmationRecord::parse(record_data)?)
+        } else if record_type == 0x14 {
+            RecordContents::BmcMessageChannelInfo(BmcMessageChannelInfoRecord::parse(record_data)?)
+        } else if record_type == 0xC0 {
+            RecordContents::Oem(OemRecord::parse(record_data)?)
         } else {
             RecordContents::Unknown {
                 ty: record_type,
@@ -760,6 +1217,26 @@ impl Record {
         })
     }
 
+    /// Encode the header and contents of this record.
+    ///
+    /// Reserved fields, and fields that are not used according to other
+    /// fields, are encoded as 0. For records that were parsed, the result is
+    /// equal to the data they were parsed from unless those fields were not 0.
+    pub fn encode(&self) -> Result<Vec<u8>, EncodeError> {
+        let (record_type, record_data) = self.contents.encode()?;
+        let record_length =
+            u8::try_from(record_data.len()).map_err(|_| EncodeError::RecordTooLong)?;
+
+        let mut data = Vec::with_capacity(Self::HEADER_SIZE + record_data.len());
+        data.extend_from_slice(&self.header.id.value().to_le_bytes());
+        data.push((self.header.sdr_version_minor << 4) | (self.header.sdr_version_major & 0x0F));
+        data.push(record_type);
+        data.push(record_length);
+        data.extend(record_data);
+
+        Ok(data)
+    }
+
     pub fn id(&self) -> Option<&SensorId> {
         self.contents.id()
     }
@@ -770,14 +1247,41 @@ impl Record {
 }
 
 impl RecordContents {
+    /// Encode these contents as a record type and record data.
+    pub fn encode(&self) -> Result<(u8, Vec<u8>), EncodeError> {
+        let encoded = match self {
+            RecordContents::FullSensor(full) => (0x01, full.encode()?),
+            RecordContents::CompactSensor(compact) => (0x02, compact.encode()?),
+            RecordContents::EventOnlySensor(event) => (0x03, event.encode()?),
+            RecordContents::EntityAssociation(association) => (0x08, association.encode()?),
+            RecordContents::DeviceRelativeEntityAssociation(association) => {
+                (0x09, association.encode_device_relative()?)
+            }
+            RecordContents::GenericDeviceLocator(generic) => (0x10, generic.encode()?),
+            RecordContents::FruDeviceLocator(fru) => (0x11, fru.encode()?),
+            RecordContents::McDeviceLocator(mc) => (0x12, mc.encode()?),
+            RecordContents::McConfirmation(mc) => (0x13, mc.encode()),
+            RecordContents::BmcMessageChannelInfo(info) => (0x14, info.encode()),
+            RecordContents::Oem(oem) => (0xC0, oem.encode()),
+            RecordContents::Unknown { ty, data } => (*ty, data.clone()),
+        };
+
+        Ok(encoded)
+    }
+
     pub fn common_data(&self) -> Option<&SensorRecordCommon> {
         match self {
             RecordContents::FullSensor(s) => Some(s.common()),
             RecordContents::CompactSensor(s) => Some(s.common()),
             RecordContents::EventOnlySensor(_) => None,
+            RecordContents::EntityAssociation(_) => None,
+            RecordContents::DeviceRelativeEntityAssociation(_) => None,
             RecordContents::GenericDeviceLocator(_) => None,
             RecordContents::FruDeviceLocator(_) => None,
             RecordContents::McDeviceLocator(_) => None,
+            RecordContents::McConfirmation(_) => None,
+            RecordContents::BmcMessageChannelInfo(_) => None,
+            RecordContents::Oem(_) => None,
             RecordContents::Unknown { .. } => None,
         }
     }
@@ -806,15 +1310,39 @@ impl RecordContents {
         }
     }
 
+    /// Get the OEM record, if this is an OEM record.
+    pub fn oem(&self) -> Option<&OemRecord> {
+        if let RecordContents::Oem(oem) = self {
+            Some(oem)
+        } else {
+            None
+        }
+    }
+
+    /// Get the entity association described by an Entity Association or
+    /// Device-relative Entity Association record.
+    pub fn entity_association(&self) -> Option<&EntityAssociationRecord> {
+        match self {
+            RecordContents::EntityAssociation(association)
+            | RecordContents::DeviceRelativeEntityAssociation(association) => Some(association),
+            _ => None,
+        }
+    }
+
     pub fn id(&self) -> Option<&SensorId> {
         match self {
             RecordContents::FullSensor(full) => Some(full.id_string()),
             RecordContents::CompactSensor(compact) => Some(compact.id_string()),
             RecordContents::EventOnlySensor(event) => Some(&event.id_string),
+            RecordContents::EntityAssociation(_)
+            | RecordContents::DeviceRelativeEntityAssociation(_) => None,
             RecordContents::GenericDeviceLocator(generic) => Some(&generic.id_string),
             RecordContents::FruDeviceLocator(fru) => Some(&fru.id_string),
             RecordContents::McDeviceLocator(mc) => Some(&mc.id_string),
-            RecordContents::Unknown { .. } => None,
+            RecordContents::McConfirmation(_)
+            | RecordContents::BmcMessageChannelInfo(_)
+            | RecordContents::Oem(_)
+            | RecordContents::Unknown { .. } => None,
         }
     }
 
@@ -823,9 +1351,14 @@ impl RecordContents {
             RecordContents::FullSensor(full) => Some(full.sensor_number()),
             RecordContents::CompactSensor(compact) => Some(compact.sensor_number()),
             RecordContents::EventOnlySensor(event) => Some(event.key.sensor_number),
-            RecordContents::GenericDeviceLocator(_)
+            RecordContents::EntityAssociation(_)
+            | RecordContents::DeviceRelativeEntityAssociation(_)
+            | RecordContents::GenericDeviceLocator(_)
             | RecordContents::FruDeviceLocator(_)
-            | RecordContents::McDeviceLocator(_) => None,
+            | RecordContents::McDeviceLocator(_)
+            | RecordContents::McConfirmation(_)
+            | RecordContents::BmcMessageChannelInfo(_)
+            | RecordContents::Oem(_) => None,
             RecordContents::Unknown { .. } => None,
         }
     }
@@ -836,19 +1369,23 @@ impl RecordContents {
             RecordContents::FullSensor(full) => Some(*full.ty()),
             RecordContents::CompactSensor(compact) => Some(*compact.ty()),
             RecordContents::EventOnlySensor(event) => Some(event.ty),
-            RecordContents::GenericDeviceLocator(_)
+            RecordContents::EntityAssociation(_)
+            | RecordContents::DeviceRelativeEntityAssociation(_)
+            | RecordContents::GenericDeviceLocator(_)
             | RecordContents::FruDeviceLocator(_)
-            | RecordContents::McDeviceLocator(_) => None,
+            | RecordContents::McDeviceLocator(_)
+            | RecordContents::McConfirmation(_)
+            | RecordContents::BmcMessageChannelInfo(_)
+            | RecordContents::Oem(_) => None,
             RecordContents::Unknown { .. } => None,
         }
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct SensorRecordCommon {
     pub key: SensorKey,
-    // TODO: make a type EntityId
-    pub entity_id: u8,
+    pub entity_id: EntityId,
     pub entity_instance: EntityInstance,
     pub initialization: SensorInitialization,
     pub capabilities: SensorCapabilities,
@@ -864,13 +1401,13 @@ impl SensorRecordCommon {
     /// You _must_ remember to [`SensorRecordCommon::set_id`] once the ID of the
     /// record has been parsed.
     pub(crate) fn parse_without_id(record_data: &[u8]) -> Result<(Self, &[u8]), ParseError> {
-        if record_data.len() < 17 {
+        if record_data.len() < 18 {
             return Err(ParseError::NotEnoughData);
         }
 
         let sensor_key = SensorKey::parse(&record_data[..3])?;
 
-        let entity_id = record_data[3];
+        let entity_id = EntityId::from(record_data[3]);
 
         let entity_instance = record_data[4];
         let entity_instance = EntityInstance::from(entity_instance);
@@ -922,6 +1459,30 @@ impl SensorRecordCommon {
     pub(crate) fn set_id(&mut self, id: SensorId) {
         self.sensor_id = id;
     }
+
+    /// Encode the common sensor record data that [`SensorRecordCommon::parse_without_id`]
+    /// parses. `analog_data_format` is encoded in bits \[7:6\] of the sensor
+    /// units 1 byte.
+    pub(crate) fn encode_without_id(&self, analog_data_format: u8) -> Vec<u8> {
+        let (caps, assertion, deassertion, reading) = self.capabilities.encode();
+        let (sensor_units_1, base_unit, modifier_unit) = self.sensor_units.encode();
+
+        let mut data = Vec::with_capacity(18);
+        data.extend_from_slice(&self.key.encode());
+        data.push(self.entity_id.into());
+        data.push(self.entity_instance.into());
+        data.push(self.initialization.into());
+        data.push(caps);
+        data.push(self.ty.into());
+        data.push(self.event_reading_type_code.into());
+        data.extend_from_slice(&assertion.to_le_bytes());
+        data.extend_from_slice(&deassertion.to_le_bytes());
+        data.extend_from_slice(&reading.to_le_bytes());
+        data.push((analog_data_format << 6) | sensor_units_1);
+        data.push(base_unit);
+        data.push(modifier_unit);
+        data
+    }
 }
 
 #[cfg(test)]
@@ -936,4 +1497,50 @@ mod tests {
             assert_eq!(x, value);
         }
     }
+
+    #[test]
+    fn linearization_codes() {
+        assert_eq!(Linearization::Sqr, Linearization::from(8));
+        assert_eq!(Linearization::Cube, Linearization::from(9));
+        assert_eq!(Linearization::Sqrt, Linearization::from(10));
+        assert_eq!(Linearization::CubeRoot, Linearization::from(11));
+        assert_eq!(Linearization::NonLinear, Linearization::from(0x70));
+        assert_eq!(Linearization::Oem(0x71), Linearization::from(0x71));
+        assert_eq!(Linearization::Unknown(0x0C), Linearization::from(0x0C));
+
+        assert_eq!(Some(100.0), Linearization::Exp10.apply(2.0));
+        assert_eq!(Some(2.0), Linearization::Exp10.invert(100.0));
+        assert_eq!(None, Linearization::Ln.apply(0.0));
+        assert_eq!(None, Linearization::NonLinear.apply(1.0));
+    }
+
+    #[test]
+    fn conversion_factors_sign() {
+        let factors = ConversionFactors::parse(&[0xFF, 0xC0, 0x00, 0x80, 0x00, 0x00]).unwrap();
+
+        assert_eq!(-1, factors.m);
+        assert_eq!(-512, factors.b);
+        assert_eq!([0xFF, 0xC0, 0x00, 0x80, 0x00, 0x00], factors.encode());
+    }
+
+    #[test]
+    fn sensor_id_encoding() {
+        let id = SensorId::Ascii8BAndLatin1("Fan2A".into());
+        let encoded = id.encode().unwrap();
+
+        assert_eq!(&[0xC5, b'F', b'a', b'n', b'2', b'A'], &encoded[..]);
+        assert_eq!(
+            id,
+            SensorId::try_from(TypeLengthRaw::new(encoded[0], &encoded[1..])).unwrap()
+        );
+
+        assert_eq!(
+            Err(EncodeError::IdStringTooLong),
+            SensorId::Unicode("a".repeat(32)).encode()
+        );
+        assert_eq!(
+            Err(EncodeError::InvalidIdString),
+            SensorId::Ascii8BAndLatin1("\u{263A}".into()).encode()
+        );
+    }
 }
@@ -0,0 +1,135 @@
+use super::ParseError;
+
+/// An OEM record.
+///
+/// Reference: IPMI 2.0 Specification, Section 43.12, Table 43-12
+#[derive(Debug, Clone, PartialEq)]
+pub struct OemRecord {
+    /// The IANA enterprise number of the manufacturer that defined the record.
+    pub manufacturer_id: u32,
+    /// The OEM data following the manufacturer ID.
+    pub data: Vec<u8>,
+}
+
+impl OemRecord {
+    pub fn parse(record_data: &[u8]) -> Result<Self, ParseError> {
+        if record_data.len() < 3 {
+            return Err(ParseError::NotEnoughData);
+        }
+
+        Ok(Self {
+            manufacturer_id: u32::from_le_bytes([
+                record_data[0],
+                record_data[1],
+                record_data[2],
+                0,
+            ]),
+            data: record_data[3..].to_vec(),
+        })
+    }
+
+    /// Encode this record as the record data that [`OemRecord::parse`] parses.
+    pub fn encode(&self) -> Vec<u8> {
+        let mut data = self.manufacturer_id.to_le_bytes()[..3].to_vec();
+        data.extend_from_slice(&self.data);
+        data
+    }
+}
+
+type Decoder<T> = Box<dyn Fn(&[u8]) -> Option<T> + Send + Sync>;
+
+/// A set of manufacturer-specific decoders for [`OemRecord`]s.
+///
+/// Every decoder produces a `T`, usually an enum of the OEM records that
+/// an application is interested in.
+pub struct OemRecordDecoders<T> {
+    decoders: Vec<(u32, Decoder<T>)>,
+}
+
+impl<T> Default for OemRecordDecoders<T> {
+    fn default() -> Self {
+        Self {
+            decoders: Vec::new(),
+        }
+    }
+}
+
+impl<T> OemRecordDecoders<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register `decoder` for OEM records of the manufacturer with IANA
+    /// enterprise number `manufacturer_id`.
+    ///
+    /// The decoder receives the data following the manufacturer ID, and
+    /// should return `None` if it does not recognize the data. Decoders
+    /// are tried in the order in which they were registered.
+    pub fn register<F>(&mut self, manufacturer_id: u32, decoder: F) -> &mut Self
+    where
+        F: Fn(&[u8]) -> Option<T> + Send + Sync + 'static,
+    {
+        self.decoders.push((manufacturer_id, Box::new(decoder)));
+        self
+    }
+
+    /// Decode `record` with the first registered decoder for its
+    /// manufacturer that recognizes it.
+    pub fn decode(&self, record: &OemRecord) -> Option<T> {
+        self.decoders
+            .iter()
+            .filter(|(id, _)| *id == record.manufacturer_id)
+            .find_map(|(_, decoder)| decoder(&record.data))
+    }
+}
+
+impl<T> core::fmt::Debug for OemRecordDecoders<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let ids: Vec<_> = self.decoders.iter().map(|(id, _)| id).collect();
+        f.debug_struct("OemRecordDecoders")
+            .field("manufacturer_ids", &ids)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, PartialEq)]
+    enum Decoded {
+        FanZone { zone: u8, fans: Vec<u8> },
+        Platform(u8),
+    }
+
+    #[test]
+    fn decode_registered() {
+        let mut decoders = OemRecordDecoders::new();
+        decoders
+            .register(674, |data| match data {
+                [0x01, zone, fans @ ..] => Some(Decoded::FanZone {
+                    zone: *zone,
+                    fans: fans.to_vec(),
+                }),
+                _ => None,
+            })
+            .register(674, |data| data.first().map(|v| Decoded::Platform(*v)))
+            .register(10876, |_| None);
+
+        let fan_zone = OemRecord::parse(&[0xA2, 0x02, 0x00, 0x01, 0x02, 0x30, 0x31]).unwrap();
+        assert_eq!(674, fan_zone.manufacturer_id);
+        assert_eq!(
+            Some(Decoded::FanZone {
+                zone: 2,
+                fans: vec![0x30, 0x31]
+            }),
+            decoders.decode(&fan_zone)
+        );
+
+        let platform = OemRecord::parse(&[0xA2, 0x02, 0x00, 0x05]).unwrap();
+        assert_eq!(Some(Decoded::Platform(5)), decoders.decode(&platform));
+
+        let supermicro = OemRecord::parse(&[0x7C, 0x2A, 0x00, 0x01]).unwrap();
+        assert_eq!(None, decoders.decode(&supermicro));
+    }
+}
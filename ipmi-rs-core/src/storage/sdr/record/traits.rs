@@ -1,4 +1,4 @@
-use crate::storage::sdr::{event_reading_type_code::EventReadingTypeCodes, SensorType};
+use crate::storage::sdr::{event_reading_type_code::EventReadingTypeCodes, EntityId, SensorType};
 
 use super::{
     Direction, EntityInstance, SensorCapabilities, SensorId, SensorKey, SensorNumber,
@@ -12,7 +12,7 @@ pub trait DirectionalSensor {
 pub trait IdentifiableSensor {
     fn id_string(&self) -> &SensorId;
 
-    fn entity_id(&self) -> u8;
+    fn entity_id(&self) -> EntityId;
 }
 
 pub trait InstancedSensor: IdentifiableSensor {
@@ -45,7 +45,7 @@ where
         &self.common().sensor_id
     }
 
-    fn entity_id(&self) -> u8 {
+    fn entity_id(&self) -> EntityId {
         self.common().entity_id
     }
 }
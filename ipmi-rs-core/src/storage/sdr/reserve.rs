@@ -0,0 +1,62 @@
+//! Reserve SDR Repository and Reserve Device SDR Repository Commands
+//!
+//! Reference: IPMI 2.0 Specification, Section 33.11 "Reserve SDR Repository Command"
+//! and Section 35.4 "Reserve Device SDR Repository Command"
+
+use std::num::NonZeroU16;
+
+use crate::connection::{IpmiCommand, Message, NetFn, NotEnoughData};
+
+/// Reserve SDR Repository command.
+///
+/// A reservation ID is required for reading SDRs in parts, and for
+/// modifying the SDR repository. A reservation is cancelled when the SDR
+/// repository is modified, or when another reservation is made.
+///
+/// Reference: IPMI 2.0 Specification, Section 33.11, Table 33-4
+pub struct ReserveSdrRepository;
+
+impl IpmiCommand for ReserveSdrRepository {
+    type Output = NonZeroU16;
+    type Error = NotEnoughData;
+
+    fn parse_success_response(data: &[u8]) -> Result<Self::Output, Self::Error> {
+        if data.len() < 2 {
+            return Err(NotEnoughData);
+        }
+
+        let reservation_id = u16::from_le_bytes([data[0], data[1]]);
+        // Reservation ID of 0 is not valid per spec
+        NonZeroU16::new(reservation_id).ok_or(NotEnoughData)
+    }
+}
+
+impl From<ReserveSdrRepository> for Message {
+    fn from(_: ReserveSdrRepository) -> Self {
+        Message::new_request(NetFn::Storage, 0x22, Vec::new())
+    }
+}
+
+/// Reserve Device SDR Repository command.
+///
+/// The device SDR equivalent of [`ReserveSdrRepository`]. A reservation is
+/// cancelled when the sensor population of the device changes, or when
+/// another reservation is made.
+///
+/// Reference: IPMI 2.0 Specification, Section 35.4, Table 35-4
+pub struct ReserveDeviceSdrRepository;
+
+impl IpmiCommand for ReserveDeviceSdrRepository {
+    type Output = NonZeroU16;
+    type Error = NotEnoughData;
+
+    fn parse_success_response(data: &[u8]) -> Result<Self::Output, Self::Error> {
+        ReserveSdrRepository::parse_success_response(data)
+    }
+}
+
+impl From<ReserveDeviceSdrRepository> for Message {
+    fn from(_: ReserveDeviceSdrRepository) -> Self {
+        Message::new_request(NetFn::SensorEvent, 0x22, Vec::new())
+    }
+}
@@ -0,0 +1,59 @@
+//! Get SDR Repository Time and Set SDR Repository Time Commands
+//!
+//! Reference: IPMI 2.0 Specification, Section 33.17 "Get SDR Repository Time Command"
+//! and Section 33.18 "Set SDR Repository Time Command"
+
+use crate::{
+    connection::{IpmiCommand, Message, NetFn, NotEnoughData},
+    storage::Timestamp,
+};
+
+/// Get SDR Repository Time command.
+///
+/// Reference: IPMI 2.0 Specification, Section 33.17, Table 33-10
+pub struct GetSdrRepositoryTime;
+
+impl From<GetSdrRepositoryTime> for Message {
+    fn from(_: GetSdrRepositoryTime) -> Self {
+        Message::new_request(NetFn::Storage, 0x28, Vec::new())
+    }
+}
+
+impl IpmiCommand for GetSdrRepositoryTime {
+    type Output = Timestamp;
+    type Error = NotEnoughData;
+
+    fn parse_success_response(data: &[u8]) -> Result<Self::Output, Self::Error> {
+        if data.len() < 4 {
+            return Err(NotEnoughData);
+        }
+
+        Ok(Timestamp::from(u32::from_le_bytes([
+            data[0], data[1], data[2], data[3],
+        ])))
+    }
+}
+
+/// Set SDR Repository Time command.
+///
+/// Reference: IPMI 2.0 Specification, Section 33.18, Table 33-11
+pub struct SetSdrRepositoryTime(pub Timestamp);
+
+impl From<SetSdrRepositoryTime> for Message {
+    fn from(value: SetSdrRepositoryTime) -> Self {
+        Message::new_request(
+            NetFn::Storage,
+            0x29,
+            u32::from(value.0).to_le_bytes().to_vec(),
+        )
+    }
+}
+
+impl IpmiCommand for SetSdrRepositoryTime {
+    type Output = ();
+    type Error = NotEnoughData;
+
+    fn parse_success_response(_: &[u8]) -> Result<Self::Output, Self::Error> {
+        Ok(())
+    }
+}
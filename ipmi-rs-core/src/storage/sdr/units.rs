@@ -75,7 +75,9 @@ macro_rules ! unit {
                 }
             }
 
-            fn display_str(&self, short: bool, plural: bool) -> &str
+            /// The name of this unit, or its symbol if `short` is
+            /// set and the unit has one.
+            pub fn display_str(&self, short: bool, plural: bool) -> &str
             {
                 let short_value = if short {
                     self.short_display()
@@ -0,0 +1,51 @@
+//! Enter SDR Repository Update Mode and Exit SDR Repository Update Mode Commands
+//!
+//! Reference: IPMI 2.0 Specification, Section 33.19 "Enter SDR Repository Update Mode Command"
+//! and Section 33.20 "Exit SDR Repository Update Mode Command"
+
+use crate::connection::{IpmiCommand, Message, NetFn, NotEnoughData};
+
+/// Enter SDR Repository Update Mode command.
+///
+/// SDR repositories that only support modal updates
+/// ([`Operation::ModalUpdate`](super::SdrOperation::ModalUpdate)) must be
+/// put in update mode before they can be modified. While in update mode,
+/// the SDR repository can not be read.
+///
+/// Reference: IPMI 2.0 Specification, Section 33.19, Table 33-12
+pub struct EnterSdrRepositoryUpdateMode;
+
+impl From<EnterSdrRepositoryUpdateMode> for Message {
+    fn from(_: EnterSdrRepositoryUpdateMode) -> Self {
+        Message::new_request(NetFn::Storage, 0x2A, Vec::new())
+    }
+}
+
+impl IpmiCommand for EnterSdrRepositoryUpdateMode {
+    type Output = ();
+    type Error = NotEnoughData;
+
+    fn parse_success_response(_: &[u8]) -> Result<Self::Output, Self::Error> {
+        Ok(())
+    }
+}
+
+/// Exit SDR Repository Update Mode command.
+///
+/// Reference: IPMI 2.0 Specification, Section 33.20, Table 33-13
+pub struct ExitSdrRepositoryUpdateMode;
+
+impl From<ExitSdrRepositoryUpdateMode> for Message {
+    fn from(_: ExitSdrRepositoryUpdateMode) -> Self {
+        Message::new_request(NetFn::Storage, 0x2B, Vec::new())
+    }
+}
+
+impl IpmiCommand for ExitSdrRepositoryUpdateMode {
+    type Output = ();
+    type Error = NotEnoughData;
+
+    fn parse_success_response(_: &[u8]) -> Result<Self::Output, Self::Error> {
+        Ok(())
+    }
+}
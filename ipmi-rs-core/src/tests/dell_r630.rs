@@ -1,6 +1,16 @@
-use crate::sensor_event::{RawSensorReading, ThresholdReading};
-use crate::storage::sdr::record::SensorId;
-use crate::storage::sdr::Record;
+use crate::connection::{IpmiCommand, LogicalUnit, Message};
+use crate::sensor_event::{
+    GetSensorReading, HysteresisError, RawSensorReading, SetSensorHysteresis, SetSensorThresholds,
+    ThresholdError, ThresholdReading,
+};
+use crate::storage::sdr::record::{
+    HysteresisCapability, Linearization, RecordContents, SensorId, ThresholdKind,
+    WithSensorRecordCommon,
+};
+use crate::storage::sdr::{
+    dump::{read_dump, write_dump},
+    Record, RecordParseError,
+};
 
 const FAN_2A_SDR: [u8; 55] = [
     0x0E, 0x00, 0x0D, 0x00, 0x51, 0x01, 0x30, 0x20, 0x00, 0x32, 0x07, 0x01, 0x7F, 0xD4, 0x04, 0x01,
@@ -53,3 +63,163 @@ fn test_decode_inlet_temp() {
             .unwrap()
     );
 }
+
+#[test]
+fn test_fan_threshold_conversion() {
+    let sensor = Record::parse(&FAN_2A_SDR[2..]).unwrap();
+    let sensor = sensor.full_sensor().unwrap();
+
+    assert_eq!(Some(0x2C), sensor.raw_value(5280.0));
+    assert_eq!(Some(0x1D), sensor.raw_value(3500.0));
+    assert_eq!(None, sensor.raw_value(-120.0));
+    assert_eq!(None, sensor.raw_value(40000.0));
+
+    // This sensor reports its thresholds as readable, but not settable.
+    assert_eq!(
+        Err(ThresholdError::NotSettable(ThresholdKind::LowerCritical)),
+        SetSensorThresholds::for_sensor(sensor, &[(ThresholdKind::LowerCritical, 3500.0)])
+            .map(|_| ())
+    );
+
+    let mut settable_sdr = FAN_2A_SDR;
+    // Readable and settable thresholds, with lower critical settable.
+    settable_sdr[13] = (settable_sdr[13] & !0x0C) | 0x08;
+    settable_sdr[21] |= 0x02;
+
+    let settable = Record::parse(&settable_sdr[2..]).unwrap();
+    let settable = settable.full_sensor().unwrap();
+
+    let command =
+        SetSensorThresholds::for_sensor(settable, &[(ThresholdKind::LowerCritical, 3500.0)])
+            .unwrap();
+    let message: Message = command.into();
+    assert_eq!(
+        &[0x32, 0x02, 0x00, 0x1D, 0x00, 0x00, 0x00, 0x00],
+        message.data()
+    );
+}
+
+#[test]
+fn test_fan_hysteresis() {
+    let sensor = Record::parse(&FAN_2A_SDR[2..]).unwrap();
+    let sensor = sensor.full_sensor().unwrap();
+
+    assert_eq!(
+        HysteresisCapability::Readable,
+        sensor.capabilities().hysteresis
+    );
+    assert_eq!(
+        Err(HysteresisError::NotSettable),
+        SetSensorHysteresis::for_full_sensor(sensor, 240.0, 240.0).map(|_| ())
+    );
+
+    let mut settable_sdr = FAN_2A_SDR;
+    settable_sdr[13] = (settable_sdr[13] & !0x30) | 0x20;
+
+    let settable = Record::parse(&settable_sdr[2..]).unwrap();
+    let settable = settable.full_sensor().unwrap();
+
+    assert_eq!(Some(2), settable.raw_hysteresis(240.0));
+    assert_eq!(
+        Err(HysteresisError::OutOfRange),
+        SetSensorHysteresis::for_full_sensor(settable, 240.0, 40000.0).map(|_| ())
+    );
+
+    let message: Message = SetSensorHysteresis::for_full_sensor(settable, 240.0, 360.0)
+        .unwrap()
+        .into();
+    assert_eq!(&[0x32, 0xFF, 0x02, 0x03], message.data());
+}
+
+#[test]
+fn test_fan_linearization() {
+    let mut sdr = FAN_2A_SDR;
+
+    // Non-linear, requiring Get Sensor Reading Factors.
+    sdr[25] = 0x70;
+    let sensor = Record::parse(&sdr[2..]).unwrap();
+    let sensor = sensor.full_sensor().unwrap();
+
+    assert!(sensor.linearization.is_non_linear());
+    assert!(sensor.convert(0x2C).is_none());
+    assert_eq!(
+        5280.0,
+        sensor
+            .convert_with_factors(0x2C, &sensor.factors())
+            .unwrap()
+            .value()
+    );
+
+    // Square root.
+    sdr[25] = 0x0A;
+    let sensor = Record::parse(&sdr[2..]).unwrap();
+    let sensor = sensor.full_sensor().unwrap();
+
+    assert_eq!(Linearization::Sqrt, sensor.linearization);
+    let value = sensor.convert(0x2C).unwrap().value();
+    assert_eq!(5280f32.sqrt(), value);
+    assert_eq!(Some(0x2C), sensor.raw_value(value));
+}
+
+#[test]
+fn test_sdr_dump() {
+    // System board 1 contains processor 1 and processor 2.
+    let entity_association = [
+        0x40, 0x00, 0x51, 0x08, 0x0B, 0x07, 0x01, 0x00, 0x03, 0x01, 0x03, 0x02, 0x00, 0x00, 0x00,
+        0x00,
+    ];
+
+    let mut dump = Vec::new();
+    dump.extend_from_slice(&FAN_2A_SDR[2..]);
+    dump.extend_from_slice(&entity_association);
+    dump.extend_from_slice(&INLET_TEMP_SDR[2..]);
+
+    let records: Vec<_> = read_dump(&dump).collect::<Result<_, _>>().unwrap();
+    assert_eq!(3, records.len());
+    assert!(matches!(
+        records[1].contents,
+        RecordContents::EntityAssociation(_)
+    ));
+    assert_eq!(
+        SensorId::Ascii8BAndLatin1("Inlet Temp".to_string()),
+        records[2].common_data().unwrap().sensor_id
+    );
+    assert_eq!(dump, write_dump(&records).unwrap());
+
+    let truncated: Vec<_> = read_dump(&dump[..dump.len() - 1]).collect();
+    assert_eq!(3, truncated.len());
+    assert!(truncated[1].is_ok());
+    assert!(matches!(truncated[2], Err(RecordParseError::NotEnoughData)));
+}
+
+#[test]
+fn test_encode_round_trip() {
+    for sdr in [&FAN_2A_SDR[2..], &INLET_TEMP_SDR[2..]] {
+        let record = Record::parse(sdr).unwrap();
+        let encoded = record.encode().unwrap();
+
+        assert_eq!(sdr, &encoded[..]);
+        assert_eq!(record, Record::parse(&encoded).unwrap());
+    }
+}
+
+#[test]
+fn test_sensor_lun() {
+    let sensor = Record::parse(&FAN_2A_SDR[2..]).unwrap();
+    let key = &sensor.full_sensor().unwrap().common().key;
+    assert_eq!(
+        LogicalUnit::Zero,
+        GetSensorReading::for_sensor_key(key).target_lun()
+    );
+
+    let mut sdr = FAN_2A_SDR;
+    // Owner LUN 2.
+    sdr[8] = 0x02;
+
+    let sensor = Record::parse(&sdr[2..]).unwrap();
+    let key = &sensor.full_sensor().unwrap().common().key;
+    assert_eq!(
+        LogicalUnit::Two,
+        GetSensorReading::for_sensor_key(key).target_lun()
+    );
+}
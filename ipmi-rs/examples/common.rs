@@ -1,6 +1,6 @@
 #![allow(unused)]
 
-use std::{io::ErrorKind, time::Duration};
+use std::{io::ErrorKind, path::PathBuf, time::Duration};
 
 use clap::{Args, Parser};
 use ipmi_rs::{
@@ -96,6 +96,9 @@ pub struct CommonOpts {
     /// How many milliseconds to wait before timing out while waiting for a response
     #[clap(default_value = "2000", long)]
     timeout_ms: u64,
+    /// A file to cache the SDR repository in
+    #[clap(long)]
+    sdr_cache: Option<PathBuf>,
 }
 
 fn error<T>(val: T) -> std::io::Error
@@ -115,7 +118,8 @@ impl CommonOpts {
             log::debug!("Opening file {path}");
 
             let file = File::new(path, timeout)?;
-            let ipmi = Ipmi::new(file);
+            let mut ipmi = Ipmi::new(file);
+            ipmi.set_sdr_cache(self.sdr_cache.clone());
             Ok(IpmiConnectionEnum::File(ipmi))
         } else if self.connection_uri.starts_with("rmcp://") {
             let (_, data) = self.connection_uri.split_once("rmcp://").unwrap();
@@ -134,7 +138,8 @@ impl CommonOpts {
             rmcp.activate(true, Some(username), Some(password.as_bytes()))
                 .map_err(|e| error(format!("RMCP activation error: {:?}", e)))?;
 
-            let ipmi = Ipmi::new(rmcp);
+            let mut ipmi = Ipmi::new(rmcp);
+            ipmi.set_sdr_cache(self.sdr_cache.clone());
             Ok(IpmiConnectionEnum::Rmcp(ipmi))
         } else {
             Err(error(format!(
@@ -125,6 +125,20 @@ fn main() -> std::io::Result<()> {
             }
             RecordContents::CompactSensor(compact) => log_sensor("Compact Sensor", compact),
             RecordContents::EventOnlySensor(event) => log_sensor("Event-only Sensor", event),
+            RecordContents::EntityAssociation(association)
+            | RecordContents::DeviceRelativeEntityAssociation(association) => {
+                let contained: Vec<_> = association
+                    .contained_entities()
+                    .iter()
+                    .map(|v| v.to_string())
+                    .collect();
+
+                log::info!(
+                    "Entity Association. Entity {} contains {}",
+                    association.container,
+                    contained.join(", ")
+                );
+            }
             RecordContents::GenericDeviceLocator(generic) => {
                 log_id("Generic Device Locator", generic);
                 log_device_type(generic.device_type, generic.device_type_modifier);
@@ -134,6 +148,27 @@ fn main() -> std::io::Result<()> {
                 log_device_type(fru.device_type, fru.device_type_modifier);
             }
             RecordContents::McDeviceLocator(mc) => log_id("MC Device Locator", mc),
+            RecordContents::McConfirmation(mc) => {
+                log::info!(
+                    "MC Confirmation. Address: 0x{:02X}, manufacturer: {}, product: 0x{:04X}",
+                    mc.device_address,
+                    mc.manufacturer_id,
+                    mc.product_id
+                );
+            }
+            RecordContents::BmcMessageChannelInfo(info) => {
+                log::info!(
+                    "BMC Message Channel Info. Messaging interrupt: {:?}",
+                    info.messaging_interrupt
+                );
+            }
+            RecordContents::Oem(oem) => {
+                log::info!(
+                    "OEM record. Manufacturer: {}, {} bytes of data",
+                    oem.manufacturer_id,
+                    oem.data.len()
+                );
+            }
             RecordContents::Unknown { ty, .. } => {
                 log::info!("Unknown record type. Type: 0x{ty:02X}");
             }
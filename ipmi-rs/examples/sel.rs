@@ -23,10 +23,10 @@ use ipmi_rs::{
     storage::{
         sdr::{
             record::{
-                FullSensorRecord, InstancedSensor, RecordContents, SensorKey as SdrSensorKey,
-                SensorOwner, WithSensorRecordCommon,
+                FullSensorRecord, IdentifiableSensor, InstancedSensor, RecordContents,
+                SensorKey as SdrSensorKey, SensorOwner, WithSensorRecordCommon,
             },
-            EventData, SensorType, Unit,
+            EntityId, EventData, SensorType, Unit,
         },
         sel::{
             ClearSel, Entry, ErasureProgress, EventGenerator, GetSelEntry, GetSelInfo,
@@ -43,6 +43,8 @@ type SensorKey = (u8, u8, u8, u8);
 #[derive(Clone)]
 struct SensorInfo {
     name: String,
+    /// The entity monitored by the sensor
+    entity: EntityId,
     /// Conversion parameters from Full Sensor Record (if available)
     conversion: Option<SensorConversion>,
 }
@@ -195,21 +197,24 @@ fn build_sensor_lookup(ipmi: &mut IpmiConnectionEnum) -> SensorLookup {
     log::info!("Loading SDR records for sensor name lookup...");
 
     for record in ipmi.sdrs() {
-        let (_sensor_type, _sensor_number, sensor_key) = match &record.contents {
+        let (_sensor_type, _sensor_number, sensor_key, entity) = match &record.contents {
             RecordContents::FullSensor(full) => (
                 u8::from(*full.ty()),
                 full.common().key.sensor_number.get(),
                 &full.common().key,
+                full.entity_id(),
             ),
             RecordContents::CompactSensor(compact) => (
                 u8::from(*compact.ty()),
                 compact.common().key.sensor_number.get(),
                 &compact.common().key,
+                compact.entity_id(),
             ),
             RecordContents::EventOnlySensor(event) => (
                 u8::from(event.ty),
                 event.key.sensor_number.get(),
                 &event.key,
+                event.entity_id,
             ),
             _ => continue,
         };
@@ -226,7 +231,11 @@ fn build_sensor_lookup(ipmi: &mut IpmiConnectionEnum) -> SensorLookup {
 
         lookup.insert(
             sensor_lookup_key_from_sdr(sensor_key),
-            SensorInfo { name, conversion },
+            SensorInfo {
+                name,
+                entity,
+                conversion,
+            },
         );
     }
 
@@ -534,12 +543,11 @@ fn print_entry(
                 generator_id,
                 *sensor_number,
             ));
-            let sensor_name = sensor_info.map(|s| s.name.as_str()).unwrap_or("");
-
-            let sensor_display = if sensor_name.is_empty() {
-                format!("{} #{}", sensor, sensor_number)
-            } else {
-                format!("{} ({})", sensor_name, sensor)
+            let sensor_display = match sensor_info {
+                Some(info) if !info.name.is_empty() => {
+                    format!("{} ({}, {})", info.name, sensor, info.entity)
+                }
+                _ => format!("{} #{}", sensor, sensor_number),
             };
 
             let parsed_event_data = event_data;
@@ -0,0 +1,42 @@
+use ipmi_rs_core::{
+    chassis::{
+        BootFlags, BootOptionParameter, BootOptionParameterData, GetSystemBootOptions,
+        SetSystemBootOptions,
+    },
+    connection::{IpmiConnection, NetFn, NotEnoughData},
+};
+
+use crate::{Ipmi, IpmiError};
+
+impl<CON> Ipmi<CON>
+where
+    CON: IpmiConnection,
+{
+    /// Get the currently configured boot flags.
+    pub fn boot_flags(&mut self) -> Result<BootFlags, IpmiError<CON::Error, NotEnoughData>> {
+        let response = self.send_recv(GetSystemBootOptions::new(BootOptionParameter::BootFlags))?;
+
+        match response.parse() {
+            Ok(BootOptionParameterData::BootFlags(flags)) => Ok(flags),
+            _ => Err(IpmiError::Command {
+                error: NotEnoughData,
+                netfn: NetFn::Chassis,
+                cmd: 0x09,
+                completion_code: None,
+                data: response.data,
+            }),
+        }
+    }
+
+    /// Configure the boot flags, e.g. to boot from a specific device on the
+    /// next (or every) boot.
+    pub fn set_boot_flags(
+        &mut self,
+        flags: BootFlags,
+    ) -> Result<(), IpmiError<CON::Error, NotEnoughData>> {
+        self.send_recv(SetSystemBootOptions::from_data(
+            BootOptionParameter::BootFlags,
+            &BootOptionParameterData::BootFlags(flags),
+        ))
+    }
+}
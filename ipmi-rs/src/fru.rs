@@ -0,0 +1,379 @@
+use ipmi_rs_core::{
+    app::{I2cBus, MasterWriteRead},
+    connection::{
+        Address, Channel, CompletionErrorCode, IpmiConnection, LogicalUnit, NetFn, NotEnoughData,
+    },
+    storage::{
+        fru::{
+            FruAccess, FruInventory, FruInventoryAreaInfo, FruParseError, GetFruInventoryAreaInfo,
+            ReadFruData, WriteFruData,
+        },
+        sdr::{
+            record::{FruDevice, FruDeviceLocator, RecordContents, SensorId},
+            EntityId,
+        },
+    },
+};
+
+use crate::{is_length_error, Ipmi, IpmiError};
+
+/// The initial amount of bytes requested per Read FRU Data command.
+const FRU_READ_CHUNK_SIZE: u16 = 32;
+
+/// The initial amount of bytes written per Write FRU Data command.
+const FRU_WRITE_CHUNK_SIZE: usize = 16;
+
+/// The amount of bytes read per Master Write-Read command when reading
+/// a FRU EEPROM.
+const FRU_EEPROM_CHUNK_SIZE: usize = 16;
+
+/// The 8-bit slave address of the BMC.
+const BMC_ADDRESS: u8 = 0x20;
+
+/// The amount of times a write is retried if the device is busy or
+/// does not write any data.
+const FRU_WRITE_RETRIES: usize = 3;
+
+fn access_unit(access: FruAccess) -> u16 {
+    match access {
+        FruAccess::Bytes => 1,
+        FruAccess::Words => 2,
+    }
+}
+
+/// The size and the amount of address bytes of the FRU EEPROM
+/// with device type `device_type`.
+///
+/// Reference: IPMI 2.0 Specification, Table 43-12
+fn eeprom_layout(device_type: u8) -> Option<(usize, usize)> {
+    let layout = match device_type {
+        0x08 => (128, 1),
+        0x09 => (256, 1),
+        0x0A => (512, 1),
+        0x0B => (1024, 1),
+        0x0C | 0x0D => (2048, 1),
+        0x0E => (4096, 2),
+        0x0F => (8192, 2),
+        _ => return None,
+    };
+
+    Some(layout)
+}
+
+fn fru_error<CON>(netfn: NetFn, cmd: u8, data: Vec<u8>) -> IpmiError<CON, NotEnoughData> {
+    IpmiError::Command {
+        error: NotEnoughData,
+        netfn,
+        cmd,
+        completion_code: None,
+        data,
+    }
+}
+
+/// The contents of a FRU described by a FRU device locator record.
+#[derive(Debug)]
+pub struct LocatedFru<E> {
+    /// The locator describing the FRU.
+    pub locator: FruDeviceLocator,
+    /// The raw FRU data, or the error that occured while reading it.
+    pub data: Result<Vec<u8>, IpmiError<E, NotEnoughData>>,
+}
+
+impl<E> LocatedFru<E> {
+    /// The ID string of the FRU device locator.
+    pub fn id_string(&self) -> &SensorId {
+        &self.locator.id_string
+    }
+
+    /// The entity ID and entity instance of the FRU.
+    pub fn entity(&self) -> (EntityId, u8) {
+        (self.locator.fru_entity_id, self.locator.fru_entity_instance)
+    }
+
+    /// Parse the FRU data, if it was read successfully.
+    pub fn inventory(&self) -> Option<Result<FruInventory, FruParseError>> {
+        self.data
+            .as_ref()
+            .ok()
+            .map(|data| FruInventory::parse(data))
+    }
+}
+
+impl<CON> Ipmi<CON>
+where
+    CON: IpmiConnection,
+{
+    /// Get the size and access mode of the FRU device with ID `device_id`.
+    pub fn fru_inventory_area_info(
+        &mut self,
+        device_id: u8,
+    ) -> Result<FruInventoryAreaInfo, IpmiError<CON::Error, NotEnoughData>> {
+        self.fru_inventory_area_info_at(device_id, None, LogicalUnit::Zero)
+    }
+
+    fn fru_inventory_area_info_at(
+        &mut self,
+        device_id: u8,
+        target: Option<(Address, Channel)>,
+        lun: LogicalUnit,
+    ) -> Result<FruInventoryAreaInfo, IpmiError<CON::Error, NotEnoughData>> {
+        let mut command = GetFruInventoryAreaInfo::new(device_id).with_lun(lun);
+        if let Some((address, channel)) = target {
+            command = command.with_target(address, channel);
+        }
+
+        self.send_recv(command)
+    }
+
+    /// Read the full contents of the FRU device with ID `device_id`.
+    ///
+    /// The data is read in chunks. If the device indicates that a chunk
+    /// is too large, the chunk size is reduced and the read is retried.
+    pub fn read_fru(
+        &mut self,
+        device_id: u8,
+    ) -> Result<Vec<u8>, IpmiError<CON::Error, NotEnoughData>> {
+        self.read_fru_at(device_id, None, LogicalUnit::Zero)
+    }
+
+    /// Read the full contents of the FRU device with ID `device_id` on
+    /// logical unit `lun` of `target`, or of the BMC if `target` is `None`.
+    fn read_fru_at(
+        &mut self,
+        device_id: u8,
+        target: Option<(Address, Channel)>,
+        lun: LogicalUnit,
+    ) -> Result<Vec<u8>, IpmiError<CON::Error, NotEnoughData>> {
+        let info = self.fru_inventory_area_info_at(device_id, target, lun)?;
+        let unit = access_unit(info.access);
+        let size = info.size;
+        let mut data = Vec::with_capacity(size as usize);
+        let mut chunk_size = FRU_READ_CHUNK_SIZE;
+
+        while (data.len() as u16) < size {
+            let offset = data.len() as u16;
+            let count = chunk_size.min(size - offset);
+            // Round up to a full word so that odd-sized areas can be read.
+            let count = count.div_ceil(unit);
+
+            let mut command = ReadFruData::new(device_id, offset / unit, count as u8).with_lun(lun);
+            if let Some((address, channel)) = target {
+                command = command.with_target(address, channel);
+            }
+
+            let result = self.send_recv(command);
+
+            match result {
+                Ok(chunk) => {
+                    let Some(bytes) = chunk.bytes(info.access) else {
+                        log::error!(
+                            "FRU device {device_id} returned less data than its count at offset {offset}"
+                        );
+                        return Err(fru_error(NetFn::Storage, 0x11, chunk.data));
+                    };
+
+                    let remaining = (size - offset) as usize;
+                    data.extend(bytes.iter().take(remaining));
+                }
+                Err(IpmiError::Failed {
+                    completion_code, ..
+                }) if is_length_error(completion_code) && chunk_size > unit * 2 => {
+                    chunk_size /= 2;
+                    log::debug!("Reducing FRU read chunk size to {chunk_size} bytes");
+                }
+                Err(e) => return Err(e),
+            }
+        }
+
+        Ok(data)
+    }
+
+    /// Write `data` to the FRU device with ID `device_id`, starting at
+    /// byte offset `offset`.
+    ///
+    /// The data is written in chunks. Partial writes are continued where the
+    /// device left off, and writes are retried if the device reports that
+    /// it is busy. For devices that are accessed by words, `offset` and the
+    /// length of `data` must be a multiple of 2.
+    pub fn write_fru(
+        &mut self,
+        device_id: u8,
+        offset: u16,
+        data: &[u8],
+    ) -> Result<(), IpmiError<CON::Error, NotEnoughData>> {
+        let info = self.fru_inventory_area_info(device_id)?;
+        let unit = access_unit(info.access) as usize;
+        let offset = offset as usize;
+
+        if !offset.is_multiple_of(unit) || !data.len().is_multiple_of(unit) {
+            log::error!("FRU device {device_id} is accessed by words, but write is not aligned");
+            return Err(fru_error(NetFn::Storage, 0x12, data.to_vec()));
+        }
+
+        if offset + data.len() > info.size as usize {
+            log::error!(
+                "Write of {} bytes at offset {offset} exceeds size of FRU device {device_id} ({} bytes)",
+                data.len(),
+                info.size
+            );
+            return Err(fru_error(NetFn::Storage, 0x12, data.to_vec()));
+        }
+
+        let mut written = 0;
+        let mut chunk_size = FRU_WRITE_CHUNK_SIZE;
+        let mut retries = 0;
+
+        while written < data.len() {
+            let count = chunk_size.min(data.len() - written);
+            let chunk = data[written..written + count].to_vec();
+            let chunk_offset = ((offset + written) / unit) as u16;
+
+            let result = self.send_recv(WriteFruData::new(device_id, chunk_offset, chunk));
+
+            match result {
+                Ok(units) if units > 0 => {
+                    written += (units as usize * unit).min(count);
+                    retries = 0;
+                }
+                // Completion code 0x81 indicates that the FRU device is busy.
+                Ok(0)
+                | Err(IpmiError::Failed {
+                    completion_code: CompletionErrorCode::CommandSpecific(0x81),
+                    ..
+                }) if retries < FRU_WRITE_RETRIES => {
+                    retries += 1;
+                    log::debug!(
+                        "Retrying FRU write at offset {} (attempt {retries})",
+                        offset + written
+                    );
+                }
+                Ok(_) => {
+                    log::error!(
+                        "FRU device {device_id} did not accept data at offset {}",
+                        offset + written
+                    );
+                    return Err(fru_error(NetFn::Storage, 0x12, Vec::new()));
+                }
+                Err(IpmiError::Failed {
+                    completion_code, ..
+                }) if is_length_error(completion_code) && chunk_size > unit * 2 => {
+                    chunk_size /= 2;
+                    log::debug!("Reducing FRU write chunk size to {chunk_size} bytes");
+                }
+                Err(e) => return Err(e),
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Read every FRU described by a FRU device locator record in the SDR
+    /// repository.
+    ///
+    /// Logical FRU devices are read with Read FRU Data, bridged to the
+    /// controller that owns them if that controller is not the BMC.
+    /// Non-intelligent FRU EEPROMs are read with Master Write-Read. FRU
+    /// devices of other types are skipped.
+    pub fn frus(&mut self) -> Vec<LocatedFru<CON::Error>> {
+        let locators: Vec<_> = self
+            .sdrs()
+            .filter_map(|record| match record.contents {
+                RecordContents::FruDeviceLocator(locator) => Some(locator),
+                _ => None,
+            })
+            .collect();
+
+        locators
+            .into_iter()
+            .filter_map(|locator| self.read_located_fru(locator))
+            .collect()
+    }
+
+    fn read_located_fru(&mut self, locator: FruDeviceLocator) -> Option<LocatedFru<CON::Error>> {
+        let key = locator.record_key.clone();
+        let address = key.device_access_address << 1;
+
+        let Some(channel) = Channel::new(key.channel_number) else {
+            log::warn!(
+                "FRU {} has invalid channel number {}. Skipping.",
+                locator.id_string,
+                key.channel_number
+            );
+            return None;
+        };
+
+        let target = if address == BMC_ADDRESS && channel == Channel::Primary {
+            None
+        } else {
+            Some((Address(address), channel))
+        };
+
+        let data = match key.fru_device {
+            FruDevice::Logical(device) => self.read_fru_at(device.fru_device_id, target, key.lun),
+            FruDevice::Physical(device) => {
+                let Some(layout) = eeprom_layout(locator.device_type) else {
+                    log::warn!(
+                        "FRU {} has unsupported device type 0x{:02X}. Skipping.",
+                        locator.id_string,
+                        locator.device_type
+                    );
+                    return None;
+                };
+
+                let bus = if key.private_bus_id == 0 {
+                    I2cBus::Public { bus_id: 0 }
+                } else {
+                    I2cBus::Private {
+                        channel,
+                        bus_id: key.private_bus_id,
+                    }
+                };
+
+                self.read_fru_eeprom(bus, device.i2c_address, layout, target)
+            }
+        };
+
+        Some(LocatedFru { locator, data })
+    }
+
+    /// Read the contents of a FRU EEPROM with 7-bit address `i2c_address`.
+    fn read_fru_eeprom(
+        &mut self,
+        bus: I2cBus,
+        i2c_address: u8,
+        (size, address_bytes): (usize, usize),
+        target: Option<(Address, Channel)>,
+    ) -> Result<Vec<u8>, IpmiError<CON::Error, NotEnoughData>> {
+        let mut data = Vec::with_capacity(size);
+
+        while data.len() < size {
+            let offset = data.len();
+            let count = FRU_EEPROM_CHUNK_SIZE.min(size - offset);
+
+            let (slave_address, write) = if address_bytes == 2 {
+                (i2c_address, (offset as u16).to_be_bytes().to_vec())
+            } else {
+                // Small EEPROMs select 256-byte blocks using the low bits
+                // of the slave address.
+                let block = ((offset >> 8) & 0b111) as u8;
+                (i2c_address | block, vec![offset as u8])
+            };
+
+            let mut command = MasterWriteRead::new(bus, slave_address, count as u8, write);
+            if let Some((address, channel)) = target {
+                command = command.with_target(address, channel);
+            }
+
+            let chunk = self.send_recv(command)?;
+
+            if chunk.is_empty() {
+                log::error!("FRU EEPROM 0x{i2c_address:02X} returned no data at offset {offset}");
+                return Err(fru_error(NetFn::App, 0x52, chunk));
+            }
+
+            data.extend(chunk.into_iter().take(count));
+        }
+
+        Ok(data)
+    }
+}
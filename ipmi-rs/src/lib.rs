@@ -16,13 +16,30 @@ pub mod rmcp;
 mod error;
 pub use error::IpmiError;
 
-use ipmi_rs_core::{
-    connection::{CompletionErrorCode, IpmiCommand, LogicalUnit, Request, RequestTargetAddress},
-    storage::sdr::{self, Record as SdrRecord},
-};
+mod power;
+
+mod boot;
+
+mod sensor;
+pub use sensor::{RearmedSensor, SensorReading, SensorSnapshot, ThresholdValue};
+
+mod fru;
+pub use fru::LocatedFru;
+
+mod sdr;
+pub use sdr::SdrIter;
+
+#[cfg(test)]
+mod tests;
+
+use std::path::{Path, PathBuf};
+
+use ipmi_rs_core::connection::{CompletionErrorCode, IpmiCommand, Request, RequestTargetAddress};
 
 pub struct Ipmi<CON> {
     inner: CON,
+    sdr_cache: Option<PathBuf>,
+    device_sdr_cache: Vec<sdr::DeviceSdrCache>,
 }
 
 impl<CON> Ipmi<CON> {
@@ -49,14 +66,32 @@ where
     }
 
     pub fn new(inner: CON) -> Self {
-        Self { inner }
+        Self {
+            inner,
+            sdr_cache: None,
+            device_sdr_cache: Vec::new(),
+        }
+    }
+
+    /// Cache the SDR repository in the file at `path`, or disable caching
+    /// if `path` is `None`.
+    ///
+    /// If a cache is configured, [`Ipmi::sdrs`] yields the cached records
+    /// for as long as the SDR repository reports the same most recent
+    /// addition and erase timestamps as when the cache was written.
+    /// Otherwise, it reads the records from the SDR repository and updates
+    /// the cache once all records have been read.
+    pub fn set_sdr_cache(&mut self, path: Option<PathBuf>) {
+        self.sdr_cache = path;
+    }
+
+    /// The path of the file that the SDR repository is cached in, if any.
+    pub fn sdr_cache(&self) -> Option<&Path> {
+        self.sdr_cache.as_deref()
     }
 
     pub fn sdrs(&mut self) -> SdrIter<'_, CON> {
-        SdrIter {
-            ipmi: self,
-            next_id: Some(sdr::RecordId::FIRST),
-        }
+        SdrIter::new(self)
     }
 
     pub fn send_recv<CMD>(
@@ -66,11 +101,25 @@ where
     where
         CMD: IpmiCommand,
     {
+        let lun = request.target_lun();
         let target_address = match request.target() {
-            Some((a, c)) => RequestTargetAddress::BmcOrIpmb(a, c, LogicalUnit::Zero),
-            None => RequestTargetAddress::Bmc(LogicalUnit::Zero),
+            Some((a, c)) => RequestTargetAddress::BmcOrIpmb(a, c, lun),
+            None => RequestTargetAddress::Bmc(lun),
         };
 
+        self.send_recv_to(request, target_address)
+    }
+
+    /// Send `request` to `target_address`, ignoring the target and target
+    /// LUN of `request`.
+    pub fn send_recv_to<CMD>(
+        &mut self,
+        request: CMD,
+        target_address: RequestTargetAddress,
+    ) -> Result<CMD::Output, IpmiError<CON::Error, CMD::Error>>
+    where
+        CMD: IpmiCommand,
+    {
         let message = request.into();
         let (message_netfn, message_cmd) = (message.netfn(), message.cmd());
         let mut request = Request::new(message, target_address);
@@ -117,59 +166,11 @@ where
     }
 }
 
-pub struct SdrIter<'ipmi, CON> {
-    ipmi: &'ipmi mut Ipmi<CON>,
-    next_id: Option<sdr::RecordId>,
-}
-
-impl<T> Iterator for SdrIter<'_, T>
-where
-    T: connection::IpmiConnection,
-{
-    type Item = SdrRecord;
-
-    fn next(&mut self) -> Option<Self::Item> {
-        while let Some(current_id) = self.next_id.take() {
-            if current_id.is_last() {
-                return None;
-            }
-
-            let next_record = self
-                .ipmi
-                .send_recv(sdr::GetDeviceSdr::new(None, current_id));
-
-            match next_record {
-                Ok(record) => {
-                    let next_record_id = record.next_entry;
-
-                    if next_record_id == current_id {
-                        log::error!("Got duplicate SDR record IDs! Stopping iteration.");
-                        return None;
-                    }
-
-                    self.next_id = Some(next_record_id);
-                    return Some(record.record);
-                }
-                Err(IpmiError::Command {
-                    error: (e, Some(next_record_id)),
-                    ..
-                }) => {
-                    log::warn!(
-                        "Recoverable error while parsing SDR record 0x{:04X}: {e:?}. Skipping to next.",
-                        current_id.value()
-                    );
-                    self.next_id = Some(next_record_id);
-                    continue; // skip the current one
-                }
-                Err(e) => {
-                    log::error!(
-                        "Unrecoverable error while parsing SDR record 0x{:04X}: {e:?}",
-                        current_id.value()
-                    );
-                    return None;
-                }
-            }
-        }
-        None
-    }
+pub(crate) fn is_length_error(completion_code: CompletionErrorCode) -> bool {
+    matches!(
+        completion_code,
+        CompletionErrorCode::RequestDataLengthLimitExceeded
+            | CompletionErrorCode::RequestDataLenInvalid
+            | CompletionErrorCode::CannotReturnNumOfRequestedBytes
+    )
 }
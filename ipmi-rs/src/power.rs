@@ -0,0 +1,88 @@
+use std::time::{Duration, Instant};
+
+use ipmi_rs_core::{
+    chassis::{ChassisControl, ChassisControlAction, ChassisStatus, GetChassisStatus, PowerState},
+    connection::{IpmiConnection, NotEnoughData},
+};
+
+use crate::{Ipmi, IpmiError};
+
+/// The interval at which [`Ipmi::wait_for_power_state`] polls the chassis status.
+const POWER_STATE_POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+impl<CON> Ipmi<CON>
+where
+    CON: IpmiConnection,
+{
+    /// Get the current chassis status.
+    pub fn chassis_status(
+        &mut self,
+    ) -> Result<ChassisStatus, IpmiError<CON::Error, NotEnoughData>> {
+        self.send_recv(GetChassisStatus)
+    }
+
+    /// Get the current power state of the system.
+    pub fn power_state(&mut self) -> Result<PowerState, IpmiError<CON::Error, NotEnoughData>> {
+        self.chassis_status().map(|s| s.power_state())
+    }
+
+    /// Perform the chassis control action `action`.
+    pub fn chassis_control(
+        &mut self,
+        action: ChassisControlAction,
+    ) -> Result<(), IpmiError<CON::Error, NotEnoughData>> {
+        self.send_recv(ChassisControl::new(action))
+    }
+
+    /// Power up the system.
+    pub fn power_on(&mut self) -> Result<(), IpmiError<CON::Error, NotEnoughData>> {
+        self.chassis_control(ChassisControlAction::PowerUp)
+    }
+
+    /// Force the system to power down, without an orderly shutdown.
+    pub fn power_off(&mut self) -> Result<(), IpmiError<CON::Error, NotEnoughData>> {
+        self.chassis_control(ChassisControlAction::PowerDown)
+    }
+
+    /// Power cycle the system.
+    pub fn power_cycle(&mut self) -> Result<(), IpmiError<CON::Error, NotEnoughData>> {
+        self.chassis_control(ChassisControlAction::PowerCycle)
+    }
+
+    /// Perform a hard reset of the system.
+    pub fn hard_reset(&mut self) -> Result<(), IpmiError<CON::Error, NotEnoughData>> {
+        self.chassis_control(ChassisControlAction::HardReset)
+    }
+
+    /// Request an orderly shutdown of the operating system via ACPI.
+    pub fn soft_shutdown(&mut self) -> Result<(), IpmiError<CON::Error, NotEnoughData>> {
+        self.chassis_control(ChassisControlAction::SoftShutdown)
+    }
+
+    /// Poll the chassis status until the system reaches `state`, or until
+    /// `timeout` has elapsed.
+    ///
+    /// Returns `true` if the system reached `state`, and `false` if the
+    /// timeout elapsed first.
+    pub fn wait_for_power_state(
+        &mut self,
+        state: PowerState,
+        timeout: Duration,
+    ) -> Result<bool, IpmiError<CON::Error, NotEnoughData>> {
+        let start = Instant::now();
+
+        loop {
+            if self.power_state()? == state {
+                return Ok(true);
+            }
+
+            let elapsed = start.elapsed();
+            if elapsed >= timeout {
+                log::debug!("System did not reach power state {state} within {timeout:?}");
+                return Ok(false);
+            }
+
+            std::thread::sleep(POWER_STATE_POLL_INTERVAL.min(timeout - elapsed));
+        }
+    }
+}
@@ -311,3 +311,22 @@ fn ipmb_message_test() {
 
     assert_eq!(expected, data);
 }
+
+#[test]
+fn ipmb_message_lun_test() {
+    use crate::connection::{
+        Address, Channel, LogicalUnit, Message, Request, RequestTargetAddress,
+    };
+
+    let data = next_ipmb_message(
+        &Request::new(
+            Message::new_raw(0x04, 0x2D, vec![0x32]),
+            RequestTargetAddress::BmcOrIpmb(Address(0x20), Channel::Primary, LogicalUnit::Two),
+        ),
+        &mut IpmbState::default(),
+    );
+
+    // The responder LUN is encoded in the low bits of the netfn byte.
+    assert_eq!([0x20, 0x12], data[..2]);
+    assert!(validate_ipmb_checksums(&data));
+}
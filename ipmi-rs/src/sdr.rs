@@ -0,0 +1,682 @@
+use std::{
+    io::ErrorKind,
+    num::NonZeroU16,
+    path::PathBuf,
+    time::{Duration, Instant},
+};
+
+use ipmi_rs_core::{
+    connection::{
+        Address, Channel, CompletionErrorCode, IpmiConnection, LogicalUnit, NetFn, NotEnoughData,
+        RequestTargetAddress,
+    },
+    storage::{
+        sdr::{
+            AddSdr, ClearSdrRepository, DeleteSdr, EnterSdrRepositoryUpdateMode,
+            ExitSdrRepositoryUpdateMode, GetDeviceSdr, GetDeviceSdrInfo, GetSdrRepositoryInfo,
+            InitializationProgress, PartialAddSdr, Record, RecordId, ReserveDeviceSdrRepository,
+            ReserveSdrRepository, RunInitializationAgent, SdrCache, SdrCount, SdrOperation,
+            SdrRepositoryInfo, SdrSource,
+        },
+        sel::ErasureProgress,
+    },
+};
+use nonmax::NonMaxU8;
+
+use crate::{is_length_error, Ipmi, IpmiError};
+
+/// The initial amount of bytes requested per Get SDR command when reading
+/// the body of a record.
+const SDR_READ_CHUNK_SIZE: u8 = 32;
+
+/// The smallest amount of bytes requested per Get SDR command.
+const SDR_MIN_CHUNK_SIZE: u8 = 4;
+
+/// The amount of times the read of a record is restarted if the
+/// reservation is cancelled while reading it.
+const SDR_RESERVATION_RETRIES: usize = 3;
+
+/// The amount of bytes written per Partial Add SDR command.
+const SDR_WRITE_CHUNK_SIZE: usize = 16;
+
+/// The interval at which the progress of long-running SDR repository
+/// operations is polled.
+const SDR_POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+enum ReadResult {
+    Record {
+        next: RecordId,
+        record: Record,
+        data: Vec<u8>,
+    },
+    Skip {
+        next: RecordId,
+        data: Option<Vec<u8>>,
+    },
+    Stop,
+}
+
+/// The device SDRs of a management controller, along with the sensor
+/// population change indicator that they were read at.
+pub(crate) struct DeviceSdrCache {
+    controller: Option<(Address, Channel)>,
+    change_indicator: Option<u32>,
+    records: Vec<Record>,
+}
+
+/// An iterator over the records in the SDR repository, or over the device
+/// SDRs of one LUN of a management controller.
+///
+/// Records are read in parts: first the header, then the body in chunks,
+/// using a reservation of the SDR repository. The reservation is renewed
+/// if it is cancelled while a record is being read, and the chunk size is
+/// reduced if the BMC cannot return the amount of bytes requested. If the
+/// SDR repository cannot be reserved, records are read in full.
+///
+/// If an SDR cache is configured using [`Ipmi::set_sdr_cache`] and it is
+/// up to date, the records of the SDR repository are read from the cache
+/// instead.
+pub struct SdrIter<'ipmi, CON> {
+    ipmi: &'ipmi mut Ipmi<CON>,
+    source: SdrSource,
+    target: RequestTargetAddress,
+    incomplete: bool,
+    next_id: Option<RecordId>,
+    reservation_id: Option<NonZeroU16>,
+    partial_reads: bool,
+    chunk_size: u8,
+    cached: Option<std::vec::IntoIter<Vec<u8>>>,
+    cache: Option<(PathBuf, SdrCache)>,
+}
+
+impl<'ipmi, CON> SdrIter<'ipmi, CON>
+where
+    CON: IpmiConnection,
+{
+    pub(crate) fn new(ipmi: &'ipmi mut Ipmi<CON>) -> Self {
+        let cache_path = ipmi.sdr_cache().map(PathBuf::from);
+
+        let mut iter = Self {
+            ipmi,
+            source: SdrSource::Repository,
+            target: RequestTargetAddress::Bmc(LogicalUnit::Zero),
+            incomplete: false,
+            next_id: Some(RecordId::FIRST),
+            reservation_id: None,
+            partial_reads: true,
+            chunk_size: SDR_READ_CHUNK_SIZE,
+            cached: None,
+            cache: None,
+        };
+
+        if let Some(path) = cache_path {
+            iter.load_cache(path);
+        }
+
+        iter
+    }
+
+    /// Create an iterator over the device SDRs of the LUN of the
+    /// management controller at `target`.
+    pub(crate) fn device(ipmi: &'ipmi mut Ipmi<CON>, target: RequestTargetAddress) -> Self {
+        Self {
+            ipmi,
+            source: SdrSource::Device,
+            target,
+            incomplete: false,
+            next_id: Some(RecordId::FIRST),
+            reservation_id: None,
+            partial_reads: true,
+            chunk_size: SDR_READ_CHUNK_SIZE,
+            cached: None,
+            cache: None,
+        }
+    }
+
+    /// Mark the records yielded by this iterator as incomplete.
+    fn invalidate(&mut self) {
+        self.incomplete = true;
+        self.cache = None;
+    }
+
+    fn load_cache(&mut self, path: PathBuf) {
+        let info = match self.ipmi.send_recv(GetSdrRepositoryInfo) {
+            Ok(info) => info,
+            Err(e) => {
+                log::warn!("Could not get SDR repository info: {e:?}. Not using SDR cache.");
+                return;
+            }
+        };
+
+        match std::fs::read(&path).map(|data| SdrCache::parse(&data)) {
+            Ok(Ok(cache)) if cache.is_valid_for(&info) => {
+                log::debug!("Reading SDR records from cache {}", path.display());
+                self.cached = Some(cache.records.into_iter());
+                return;
+            }
+            Ok(Ok(_)) => log::debug!("SDR cache {} is out of date", path.display()),
+            Ok(Err(e)) => log::warn!("Ignoring invalid SDR cache {}: {e:?}", path.display()),
+            Err(e) if e.kind() == ErrorKind::NotFound => {}
+            Err(e) => log::warn!("Could not read SDR cache {}: {e}", path.display()),
+        }
+
+        self.cache = Some((path, SdrCache::new(&info)));
+    }
+
+    fn store_cache(&mut self) {
+        let Some((path, cache)) = self.cache.take() else {
+            return;
+        };
+
+        // Only store the cache if the repository did not change while its
+        // records were being read.
+        match self.ipmi.send_recv(GetSdrRepositoryInfo) {
+            Ok(info) if cache.is_valid_for(&info) => {
+                if let Err(e) = std::fs::write(&path, cache.to_bytes()) {
+                    log::warn!("Could not write SDR cache {}: {e}", path.display());
+                }
+            }
+            Ok(_) => log::debug!("SDR repository changed while reading it. Not updating cache."),
+            Err(e) => log::warn!("Could not get SDR repository info: {e:?}. Not updating cache."),
+        }
+    }
+
+    fn next_cached(&mut self) -> Option<Record> {
+        let cached = self.cached.as_mut()?;
+
+        for data in cached {
+            match Record::parse(&data) {
+                Ok(record) => return Some(record),
+                Err(e) => {
+                    log::warn!("Recoverable error while parsing cached SDR record: {e:?}. Skipping to next.")
+                }
+            }
+        }
+
+        None
+    }
+
+    fn reservation(&mut self) -> Option<NonZeroU16> {
+        if self.reservation_id.is_none() && self.partial_reads {
+            let reservation = match self.source {
+                SdrSource::Repository => self.ipmi.send_recv_to(ReserveSdrRepository, self.target),
+                SdrSource::Device => self
+                    .ipmi
+                    .send_recv_to(ReserveDeviceSdrRepository, self.target),
+            };
+
+            match reservation {
+                Ok(reservation_id) => self.reservation_id = Some(reservation_id),
+                Err(e) => {
+                    log::warn!("Could not reserve SDR repository: {e:?}. Reading records in full.");
+                    self.partial_reads = false;
+                }
+            }
+        }
+
+        self.reservation_id
+    }
+
+    fn read_full(&mut self, current_id: RecordId) -> ReadResult {
+        let command = GetDeviceSdr::new(None, current_id).with_source(self.source);
+
+        match self.ipmi.send_recv_to(command, self.target) {
+            Ok(record) => ReadResult::Record {
+                next: record.next_entry,
+                record: record.record,
+                data: record.data,
+            },
+            Err(IpmiError::Command {
+                error: (e, Some(next_record_id)),
+                data,
+                ..
+            }) => {
+                log::warn!(
+                    "Recoverable error while parsing SDR record 0x{:04X}: {e:?}. Skipping to next.",
+                    current_id.value()
+                );
+                ReadResult::Skip {
+                    next: next_record_id,
+                    data: data.get(2..).map(<[u8]>::to_vec),
+                }
+            }
+            Err(e) => {
+                log::error!(
+                    "Unrecoverable error while parsing SDR record 0x{:04X}: {e:?}",
+                    current_id.value()
+                );
+                ReadResult::Stop
+            }
+        }
+    }
+
+    fn read_partial(&mut self, current_id: RecordId) -> ReadResult {
+        let mut retries = 0;
+
+        'record: loop {
+            let Some(reservation_id) = self.reservation() else {
+                return self.read_full(current_id);
+            };
+
+            let mut data: Vec<u8> = Vec::new();
+            let mut next = RecordId::LAST;
+            let mut len = Record::HEADER_SIZE;
+
+            while data.len() < len {
+                let offset = data.len() as u8;
+                let count = if offset == 0 {
+                    Record::HEADER_SIZE as u8
+                } else {
+                    self.chunk_size.min((len - data.len()) as u8)
+                };
+
+                // `count` never exceeds `SDR_READ_CHUNK_SIZE`.
+                let bytes_to_read = NonMaxU8::new(count).unwrap();
+                let command =
+                    GetDeviceSdr::partial(Some(reservation_id), current_id, offset, bytes_to_read)
+                        .with_source(self.source);
+
+                match self.ipmi.send_recv_to(command, self.target) {
+                    Ok(partial) if partial.data.is_empty() => {
+                        log::error!(
+                            "SDR record 0x{:04X} returned no data at offset {offset}",
+                            current_id.value()
+                        );
+                        return ReadResult::Stop;
+                    }
+                    Ok(partial) => {
+                        next = partial.next_entry;
+                        data.extend(partial.data.into_iter().take(count as usize));
+
+                        // The header may be returned across several chunks.
+                        if len == Record::HEADER_SIZE && data.len() >= len {
+                            len += data[4] as usize;
+                        }
+                    }
+                    Err(IpmiError::Failed {
+                        completion_code: CompletionErrorCode::ReservationCancelledOrInvalidId,
+                        ..
+                    }) if retries < SDR_RESERVATION_RETRIES => {
+                        retries += 1;
+                        log::debug!(
+                            "SDR reservation cancelled while reading record 0x{:04X}. Reserving again.",
+                            current_id.value()
+                        );
+                        self.reservation_id = None;
+                        continue 'record;
+                    }
+                    Err(IpmiError::Failed {
+                        completion_code, ..
+                    }) if is_length_error(completion_code)
+                        && self.chunk_size > SDR_MIN_CHUNK_SIZE =>
+                    {
+                        self.chunk_size /= 2;
+                        log::debug!("Reducing SDR read chunk size to {} bytes", self.chunk_size);
+                    }
+                    Err(e) => {
+                        log::error!(
+                            "Unrecoverable error while reading SDR record 0x{:04X}: {e:?}",
+                            current_id.value()
+                        );
+                        return ReadResult::Stop;
+                    }
+                }
+
+                if data.len() < len && data.len() > u8::MAX as usize {
+                    log::warn!(
+                        "SDR record 0x{:04X} is too large to be read in parts. Skipping to next.",
+                        current_id.value()
+                    );
+                    return ReadResult::Skip { next, data: None };
+                }
+            }
+
+            return match Record::parse(&data) {
+                Ok(record) => ReadResult::Record { next, record, data },
+                Err(e) => {
+                    log::warn!(
+                        "Recoverable error while parsing SDR record 0x{:04X}: {e:?}. Skipping to next.",
+                        current_id.value()
+                    );
+                    ReadResult::Skip {
+                        next,
+                        data: Some(data),
+                    }
+                }
+            };
+        }
+    }
+}
+
+impl<T> Iterator for SdrIter<'_, T>
+where
+    T: IpmiConnection,
+{
+    type Item = Record;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.cached.is_some() {
+            return self.next_cached();
+        }
+
+        while let Some(current_id) = self.next_id.take() {
+            if current_id.is_last() {
+                self.store_cache();
+                return None;
+            }
+
+            let result = if self.partial_reads {
+                self.read_partial(current_id)
+            } else {
+                self.read_full(current_id)
+            };
+
+            match result {
+                ReadResult::Record { next, record, data } => {
+                    if next == current_id {
+                        log::error!("Got duplicate SDR record IDs! Stopping iteration.");
+                        self.invalidate();
+                        return None;
+                    }
+
+                    if let Some((_, cache)) = &mut self.cache {
+                        cache.records.push(data);
+                    }
+
+                    self.next_id = Some(next);
+                    return Some(record);
+                }
+                ReadResult::Skip { next, data } => {
+                    // A record that could not be read at all can not be
+                    // cached, so the cache would be incomplete.
+                    match (data, &mut self.cache) {
+                        (Some(data), Some((_, cache))) => cache.records.push(data),
+                        (None, _) => self.invalidate(),
+                        _ => {}
+                    }
+
+                    // skip the current one
+                    self.next_id = Some(next);
+                }
+                ReadResult::Stop => {
+                    self.invalidate();
+                    return None;
+                }
+            }
+        }
+        None
+    }
+}
+
+fn unsupported<CON>(cmd: u8) -> IpmiError<CON, NotEnoughData> {
+    IpmiError::Failed {
+        netfn: NetFn::Storage,
+        cmd,
+        completion_code: CompletionErrorCode::InvalidCommand,
+        data: Vec::new(),
+    }
+}
+
+impl<CON> Ipmi<CON>
+where
+    CON: IpmiConnection,
+{
+    /// Modify the SDR repository using `f`, putting the SDR repository in
+    /// update mode first if it only supports modal updates.
+    fn modify_sdr_repository<T>(
+        &mut self,
+        f: impl FnOnce(&mut Self, &SdrRepositoryInfo) -> Result<T, IpmiError<CON::Error, NotEnoughData>>,
+    ) -> Result<T, IpmiError<CON::Error, NotEnoughData>> {
+        let info = self.send_recv(GetSdrRepositoryInfo)?;
+
+        if !info.requires_update_mode() {
+            return f(self, &info);
+        }
+
+        self.send_recv(EnterSdrRepositoryUpdateMode)?;
+        let result = f(self, &info);
+        let exit = self.send_recv(ExitSdrRepositoryUpdateMode);
+
+        let result = result?;
+        exit?;
+        Ok(result)
+    }
+
+    fn sdr_reservation(
+        &mut self,
+        info: &SdrRepositoryInfo,
+    ) -> Result<Option<NonZeroU16>, IpmiError<CON::Error, NotEnoughData>> {
+        if info.supports(SdrOperation::Reserve) {
+            self.send_recv(ReserveSdrRepository).map(Some)
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Add the raw `record`, including its header, to the SDR repository,
+    /// and return the record ID assigned to it.
+    ///
+    /// The record is written in parts using Partial Add SDR if the SDR
+    /// repository supports it, and with a single Add SDR command otherwise.
+    /// A partial add is restarted if the reservation is cancelled while
+    /// the record is being written.
+    ///
+    /// The length of `record` must match the record length in its header.
+    pub fn add_sdr(
+        &mut self,
+        record: &[u8],
+    ) -> Result<RecordId, IpmiError<CON::Error, NotEnoughData>> {
+        if !matches!(Record::split_raw(record), Some((_, rest)) if rest.is_empty()) {
+            log::error!("Record length does not match the length in its header");
+            return Err(IpmiError::Command {
+                error: NotEnoughData,
+                netfn: NetFn::Storage,
+                cmd: 0x24,
+                completion_code: None,
+                data: record.to_vec(),
+            });
+        }
+
+        self.modify_sdr_repository(|ipmi, info| {
+            let partial_add =
+                info.supports(SdrOperation::PartialAdd) && info.supports(SdrOperation::Reserve);
+
+            if !partial_add {
+                return ipmi.send_recv(AddSdr::new(record.to_vec()));
+            }
+
+            let mut retries = 0;
+
+            'record: loop {
+                let reservation_id = ipmi.send_recv(ReserveSdrRepository)?;
+                let mut record_id = RecordId::FIRST;
+                let mut offset = 0;
+
+                while offset < record.len() {
+                    let mut end = (offset + SDR_WRITE_CHUNK_SIZE).min(record.len());
+
+                    // The offset of a part is a single byte, so the part that
+                    // would be followed by a part at an offset above 255
+                    // contains the remainder of the record instead.
+                    if end > u8::MAX as usize {
+                        end = record.len();
+                    }
+
+                    // `offset` never exceeds 255.
+                    let part_offset = u8::try_from(offset).unwrap();
+                    let last = end == record.len();
+                    let command = PartialAddSdr::new(
+                        reservation_id,
+                        record_id,
+                        part_offset,
+                        last,
+                        record[offset..end].to_vec(),
+                    );
+
+                    match ipmi.send_recv(command) {
+                        Ok(id) => {
+                            record_id = id;
+                            offset = end;
+                        }
+                        Err(IpmiError::Failed {
+                            completion_code: CompletionErrorCode::ReservationCancelledOrInvalidId,
+                            ..
+                        }) if retries < SDR_RESERVATION_RETRIES => {
+                            retries += 1;
+                            log::debug!(
+                                "SDR reservation cancelled while adding record. Restarting."
+                            );
+                            continue 'record;
+                        }
+                        Err(e) => return Err(e),
+                    }
+                }
+
+                return Ok(record_id);
+            }
+        })
+    }
+
+    /// Read the device SDRs of every LUN of the management controller at
+    /// `controller`, or of the BMC if `controller` is `None`.
+    ///
+    /// Only the LUNs that Get Device SDR Info reports to have sensors are
+    /// read. The records are cached for as long as the sensor population
+    /// change indicator of the controller is unchanged. Controllers with a
+    /// static sensor population do not have a change indicator, so their
+    /// records are cached for the lifetime of this `Ipmi`.
+    pub fn device_sdrs(
+        &mut self,
+        controller: Option<(Address, Channel)>,
+    ) -> Result<Vec<Record>, IpmiError<CON::Error, NotEnoughData>> {
+        let target = |lun| match controller {
+            Some((address, channel)) => RequestTargetAddress::BmcOrIpmb(address, channel, lun),
+            None => RequestTargetAddress::Bmc(lun),
+        };
+
+        let info = self.send_recv_to(GetDeviceSdrInfo::new(SdrCount), target(LogicalUnit::Zero))?;
+
+        let cached = self
+            .device_sdr_cache
+            .iter()
+            .find(|v| v.controller == controller);
+
+        if let Some(cached) = cached {
+            if cached.change_indicator == info.sensor_population_epoch {
+                log::debug!("Reading device SDRs from cache");
+                return Ok(cached.records.clone());
+            }
+        }
+
+        self.device_sdr_cache.retain(|v| v.controller != controller);
+
+        let mut records = Vec::new();
+        let mut complete = true;
+
+        for lun in [
+            LogicalUnit::Zero,
+            LogicalUnit::One,
+            LogicalUnit::Two,
+            LogicalUnit::Three,
+        ] {
+            if !info.lun_has_sensors(lun) {
+                continue;
+            }
+
+            let mut iter = SdrIter::device(self, target(lun));
+            records.extend(iter.by_ref());
+            complete &= !iter.incomplete;
+        }
+
+        // Only cache the records if all of them could be read, and the sensor
+        // population did not change while they were being read.
+        let after =
+            self.send_recv_to(GetDeviceSdrInfo::new(SdrCount), target(LogicalUnit::Zero))?;
+
+        if !complete {
+            log::debug!("Not all device SDRs could be read. Not caching them.");
+        } else if after.sensor_population_epoch != info.sensor_population_epoch {
+            log::debug!("Sensor population changed while reading device SDRs. Not caching them.");
+        } else {
+            self.device_sdr_cache.push(DeviceSdrCache {
+                controller,
+                change_indicator: info.sensor_population_epoch,
+                records: records.clone(),
+            });
+        }
+
+        Ok(records)
+    }
+
+    /// Delete the record with ID `record_id` from the SDR repository.
+    pub fn delete_sdr(
+        &mut self,
+        record_id: RecordId,
+    ) -> Result<(), IpmiError<CON::Error, NotEnoughData>> {
+        self.modify_sdr_repository(|ipmi, info| {
+            if !info.supports(SdrOperation::Delete) {
+                log::error!("SDR repository does not support deleting records");
+                return Err(unsupported(0x26));
+            }
+
+            let reservation_id = ipmi.sdr_reservation(info)?;
+            ipmi.send_recv(DeleteSdr::new(reservation_id, record_id))?;
+            Ok(())
+        })
+    }
+
+    /// Erase all records in the SDR repository, and poll the erasure status
+    /// until the erase has completed, or until `timeout` has elapsed.
+    ///
+    /// Returns `true` if the erase completed, and `false` if the timeout
+    /// elapsed first.
+    pub fn clear_sdr_repository(
+        &mut self,
+        timeout: Duration,
+    ) -> Result<bool, IpmiError<CON::Error, NotEnoughData>> {
+        self.modify_sdr_repository(|ipmi, info| {
+            let reservation_id = ipmi.sdr_reservation(info)?;
+            let mut progress = ipmi.send_recv(ClearSdrRepository::initiate(reservation_id))?;
+            let start = Instant::now();
+
+            while progress == ErasureProgress::InProgress {
+                let elapsed = start.elapsed();
+                if elapsed >= timeout {
+                    log::debug!("SDR repository erase did not complete within {timeout:?}");
+                    return Ok(false);
+                }
+
+                std::thread::sleep(SDR_POLL_INTERVAL.min(timeout - elapsed));
+                progress = ipmi.send_recv(ClearSdrRepository::get_status(reservation_id))?;
+            }
+
+            Ok(true)
+        })
+    }
+
+    /// Run the initialization agent, and poll its status until
+    /// initialization has completed, or until `timeout` has elapsed.
+    ///
+    /// Returns `true` if initialization completed, and `false` if the
+    /// timeout elapsed first.
+    pub fn run_initialization_agent(
+        &mut self,
+        timeout: Duration,
+    ) -> Result<bool, IpmiError<CON::Error, NotEnoughData>> {
+        let mut progress = self.send_recv(RunInitializationAgent::run())?;
+        let start = Instant::now();
+
+        while progress == InitializationProgress::InProgress {
+            let elapsed = start.elapsed();
+            if elapsed >= timeout {
+                log::debug!("Initialization agent did not complete within {timeout:?}");
+                return Ok(false);
+            }
+
+            std::thread::sleep(SDR_POLL_INTERVAL.min(timeout - elapsed));
+            progress = self.send_recv(RunInitializationAgent::get_status())?;
+        }
+
+        Ok(true)
+    }
+}
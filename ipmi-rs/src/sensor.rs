@@ -0,0 +1,284 @@
+use ipmi_rs_core::{
+    connection::{IpmiConnection, NotEnoughData},
+    sensor_event::{
+        DiscreteReading, GetSensorReading, GetSensorReadingFactors, GetSensorThresholds,
+        RearmSensorEvents, ThresholdReading, ThresholdStatus,
+    },
+    storage::sdr::{
+        event_reading_type_code::EventReadingTypeCodes,
+        record::{
+            EntityInstance, FullSensorRecord, InstancedSensor, RecordContents, SensorId, SensorKey,
+            SharedSensor, ThresholdKind, Value,
+        },
+        EntityId, EntityTree, Record as SdrRecord, SensorType,
+    },
+};
+
+use crate::{Ipmi, IpmiError};
+
+/// A snapshot of the state of a sensor, combining its SDR with its
+/// current reading.
+#[derive(Debug)]
+pub struct SensorSnapshot<E> {
+    pub name: SensorId,
+    pub key: SensorKey,
+    pub entity_id: EntityId,
+    pub entity_instance: EntityInstance,
+    pub sensor_type: SensorType,
+    pub event_reading_type_code: EventReadingTypeCodes,
+    /// The current reading of the sensor, or the error that occured while
+    /// reading it. `None` for event-only sensors, which can not be read.
+    pub reading: Option<Result<SensorReading, IpmiError<E, NotEnoughData>>>,
+}
+
+/// The current reading of a sensor.
+#[derive(Debug)]
+pub struct SensorReading {
+    pub all_event_messages_disabled: bool,
+    pub scanning_disabled: bool,
+    /// The raw reading of a threshold-based sensor, or `None` if the
+    /// reading is unavailable.
+    pub raw: Option<u8>,
+    /// The reading of a threshold-based sensor in the units of the
+    /// sensor. Only available for sensors with a full sensor record.
+    pub value: Option<Value>,
+    /// The threshold status of a threshold-based sensor.
+    pub threshold_status: Option<ThresholdStatus>,
+    /// The states of a discrete sensor.
+    pub discrete: Option<DiscreteReading>,
+}
+
+impl SensorReading {
+    /// Whether the reading or state of the sensor is available.
+    pub fn is_available(&self) -> bool {
+        self.raw.is_some() || self.discrete.as_ref().is_some_and(|d| d.states.is_some())
+    }
+}
+
+/// The current value of a sensor threshold.
+#[derive(Debug)]
+pub struct ThresholdValue {
+    pub kind: ThresholdKind,
+    pub value: Value,
+}
+
+/// The result of re-arming the events of a sensor.
+#[derive(Debug)]
+pub struct RearmedSensor<E> {
+    pub sensor_id: SensorId,
+    pub key: SensorKey,
+    pub result: Result<(), IpmiError<E, NotEnoughData>>,
+}
+
+impl<CON> Ipmi<CON>
+where
+    CON: IpmiConnection,
+{
+    /// Read the current thresholds of `sensor`, converted to the units
+    /// of the sensor using [`Ipmi::sensor_value`].
+    ///
+    /// Thresholds that are not readable are omitted.
+    pub fn sensor_thresholds(
+        &mut self,
+        sensor: &FullSensorRecord,
+    ) -> Result<Vec<ThresholdValue>, IpmiError<CON::Error, NotEnoughData>> {
+        let raw = self.send_recv(GetSensorThresholds::for_sensor_key(sensor.key_data()))?;
+
+        let mut thresholds = Vec::new();
+
+        for kind in ThresholdKind::variants() {
+            let Some(raw) = raw.get(kind) else {
+                continue;
+            };
+
+            if let Some(value) = self.sensor_value(sensor, raw)? {
+                thresholds.push(ThresholdValue { kind, value });
+            }
+        }
+
+        Ok(thresholds)
+    }
+
+    /// Convert the raw reading `reading` of `sensor` to a value in the
+    /// units of the sensor.
+    ///
+    /// For non-linear sensors, the conversion factors for `reading` are
+    /// retrieved from the sensor with Get Sensor Reading Factors.
+    pub fn sensor_value(
+        &mut self,
+        sensor: &FullSensorRecord,
+        reading: u8,
+    ) -> Result<Option<Value>, IpmiError<CON::Error, NotEnoughData>> {
+        if !sensor.linearization.is_non_linear() {
+            return Ok(sensor.convert(reading));
+        }
+
+        let factors = self.send_recv(GetSensorReadingFactors::for_sensor_key(
+            sensor.key_data(),
+            reading,
+        ))?;
+
+        Ok(sensor.convert_with_factors(reading, &factors.factors))
+    }
+
+    /// Build the entity tree described by the SDR repository, which groups
+    /// sensors by the entity that they monitor.
+    pub fn entity_tree(&mut self) -> EntityTree {
+        let records: Vec<_> = self.sdrs().collect();
+        EntityTree::new(&records)
+    }
+
+    /// Take a snapshot of every sensor in the SDR repository.
+    ///
+    /// Full, compact and event-only sensor records are included, and other
+    /// records are skipped. Compact and event-only sensor records that are
+    /// shared between multiple sensors produce a snapshot per sensor. A
+    /// failure to read one sensor is recorded in its snapshot, and does not
+    /// prevent the other sensors from being read.
+    pub fn sensors(&mut self) -> Vec<SensorSnapshot<CON::Error>> {
+        let records: Vec<_> = self.sdrs().collect();
+
+        records
+            .iter()
+            .flat_map(|record| self.sensor_snapshots(record))
+            .collect()
+    }
+
+    fn sensor_snapshots(&mut self, record: &SdrRecord) -> Vec<SensorSnapshot<CON::Error>> {
+        let (sensor, full, shared): (&dyn InstancedSensor, _, _) = match &record.contents {
+            RecordContents::FullSensor(full) => (full, Some(full), None),
+            RecordContents::CompactSensor(compact) => {
+                (compact, None, Some(compact.shared_sensors()))
+            }
+            RecordContents::EventOnlySensor(event_only) => {
+                (event_only, None, Some(event_only.shared_sensors()))
+            }
+            _ => return Vec::new(),
+        };
+
+        let shared = shared.unwrap_or_else(|| {
+            vec![SharedSensor {
+                key: *sensor.key_data(),
+                id_string: sensor.id_string().clone(),
+                entity_instance: *sensor.entity_instance(),
+            }]
+        });
+
+        let readable = !matches!(record.contents, RecordContents::EventOnlySensor(_));
+
+        shared
+            .into_iter()
+            .map(|shared| {
+                let reading = readable.then(|| self.sensor_reading(sensor, &shared.key, full));
+
+                if let Some(Err(e)) = &reading {
+                    log::warn!(
+                        "Failed to get sensor reading for {}: {e:?}",
+                        shared.id_string
+                    );
+                }
+
+                SensorSnapshot {
+                    name: shared.id_string,
+                    key: shared.key,
+                    entity_id: sensor.entity_id(),
+                    entity_instance: shared.entity_instance,
+                    sensor_type: *sensor.ty(),
+                    event_reading_type_code: *sensor.event_reading_type_codes(),
+                    reading,
+                }
+            })
+            .collect()
+    }
+
+    fn sensor_reading(
+        &mut self,
+        sensor: &dyn InstancedSensor,
+        key: &SensorKey,
+        full: Option<&FullSensorRecord>,
+    ) -> Result<SensorReading, IpmiError<CON::Error, NotEnoughData>> {
+        let raw = self.send_recv(GetSensorReading::for_sensor_key(key))?;
+        let codes = *sensor.event_reading_type_codes();
+
+        if codes != EventReadingTypeCodes::Threshold {
+            let discrete = DiscreteReading::new(codes, *sensor.ty(), &raw);
+
+            return Ok(SensorReading {
+                all_event_messages_disabled: discrete.all_event_messages_disabled,
+                scanning_disabled: discrete.scanning_disabled,
+                raw: None,
+                value: None,
+                threshold_status: None,
+                discrete: Some(discrete),
+            });
+        }
+
+        let reading = ThresholdReading::from(&raw);
+
+        let value = match (reading.reading, full) {
+            (Some(raw), Some(full)) => self.sensor_value(full, raw).unwrap_or_else(|e| {
+                log::warn!("Failed to convert reading of {}: {e:?}", sensor.id_string());
+                None
+            }),
+            _ => None,
+        };
+
+        Ok(SensorReading {
+            all_event_messages_disabled: reading.all_event_messages_disabled,
+            scanning_disabled: reading.scanning_disabled,
+            raw: reading.reading,
+            value,
+            threshold_status: reading.threshold_status,
+            discrete: None,
+        })
+    }
+
+    /// Re-arm all events of every sensor in the SDR repository that
+    /// requires manual re-arming, including every sensor that shares a
+    /// compact sensor record.
+    ///
+    /// A failure to re-arm one sensor does not prevent the other sensors
+    /// from being re-armed.
+    pub fn rearm_manual_sensors(&mut self) -> Vec<RearmedSensor<CON::Error>> {
+        let sensors: Vec<_> = self
+            .sdrs()
+            .flat_map(|record| {
+                let Some(common) = record.common_data() else {
+                    return Vec::new();
+                };
+
+                if common.capabilities.auto_rearm {
+                    return Vec::new();
+                }
+
+                // Every sensor that shares a compact sensor record must be
+                // re-armed individually.
+                match &record.contents {
+                    RecordContents::CompactSensor(compact) => compact
+                        .shared_sensors()
+                        .into_iter()
+                        .map(|v| (v.id_string, v.key))
+                        .collect(),
+                    _ => vec![(common.sensor_id.clone(), common.key)],
+                }
+            })
+            .collect();
+
+        sensors
+            .into_iter()
+            .map(|(sensor_id, key)| {
+                let result = self.send_recv(RearmSensorEvents::for_sensor_key(&key));
+
+                if let Err(e) = &result {
+                    log::warn!("Failed to re-arm sensor {sensor_id}: {e:?}");
+                }
+
+                RearmedSensor {
+                    sensor_id,
+                    key,
+                    result,
+                }
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,58 @@
+use ipmi_rs_core::connection::{LogicalUnit, NetFn, RequestTargetAddress};
+
+use super::MockConnection;
+use crate::Ipmi;
+
+#[test]
+fn read_logical_fru_on_lun() {
+    // A logical FRU device with ID 2 on LUN 1 of the BMC.
+    let record = [
+        0x01, 0x00, 0x51, 0x11, 0x0E, 0x20, 0x02, 0x88, 0x00, 0x00, 0x10, 0x00, 0x07, 0x01, 0x00,
+        0xC3, b'F', b'R', b'U',
+    ];
+
+    let mut ipmi = Ipmi::new(MockConnection::default());
+    ipmi.inner_mut()
+        // Reserve SDR Repository is not supported, so the record is read in full.
+        .respond(NetFn::Storage, 0x22, 0xC1, &[])
+        .respond(
+            NetFn::Storage,
+            0x23,
+            0x00,
+            &[[0xFF, 0xFF].as_slice(), &record].concat(),
+        )
+        // 4 bytes, accessed by bytes.
+        .respond(NetFn::Storage, 0x10, 0x00, &[0x04, 0x00, 0x00])
+        .respond(NetFn::Storage, 0x11, 0x00, &[0x04, 0x01, 0x02, 0x03, 0x04]);
+
+    let frus = ipmi.frus();
+
+    assert_eq!(1, frus.len());
+    assert_eq!(
+        &[0x01, 0x02, 0x03, 0x04],
+        frus[0].data.as_ref().unwrap().as_slice()
+    );
+
+    let requests = &ipmi.inner_mut().requests;
+    assert_eq!(4, requests.len());
+
+    for request in &requests[2..] {
+        assert_eq!(RequestTargetAddress::Bmc(LogicalUnit::One), request.target);
+        assert_eq!(2, request.data[0]);
+    }
+}
+
+#[test]
+fn read_fru_honours_count() {
+    let mut ipmi = Ipmi::new(MockConnection::default());
+    ipmi.inner_mut()
+        .respond(NetFn::Storage, 0x10, 0x00, &[0x04, 0x00, 0x00])
+        // Only 2 of the returned bytes were read.
+        .respond(NetFn::Storage, 0x11, 0x00, &[0x02, 0x01, 0x02, 0xFF, 0xFF])
+        .respond(NetFn::Storage, 0x11, 0x00, &[0x02, 0x03, 0x04]);
+
+    assert_eq!(vec![0x01, 0x02, 0x03, 0x04], ipmi.read_fru(0).unwrap());
+
+    let requests = &ipmi.inner_mut().requests;
+    assert_eq!([0x00, 0x02, 0x00, 0x02], requests[2].data[..]);
+}
@@ -0,0 +1,66 @@
+use std::collections::VecDeque;
+
+use ipmi_rs_core::connection::{
+    IpmiConnection, Message, NetFn, Request, RequestTargetAddress, Response,
+};
+
+mod fru;
+mod sdr;
+mod sensor;
+
+/// A request sent over a [`MockConnection`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct SentRequest {
+    pub target: RequestTargetAddress,
+    pub netfn: NetFn,
+    pub cmd: u8,
+    pub data: Vec<u8>,
+}
+
+/// A connection that records the requests sent over it, and replies to
+/// them with queued responses.
+#[derive(Debug, Default)]
+pub struct MockConnection {
+    responses: VecDeque<Response>,
+    pub requests: Vec<SentRequest>,
+}
+
+impl MockConnection {
+    /// Queue a response with completion code `cc` and `data` to a request
+    /// with `netfn` and `cmd`.
+    pub fn respond(&mut self, netfn: NetFn, cmd: u8, cc: u8, data: &[u8]) -> &mut Self {
+        let data = [&[cc], data].concat();
+        let message = Message::new_response(netfn, cmd, data);
+
+        self.responses.push_back(Response::new(message, 0).unwrap());
+        self
+    }
+}
+
+impl IpmiConnection for MockConnection {
+    type SendError = std::io::Error;
+    type RecvError = std::io::Error;
+    type Error = std::io::Error;
+
+    fn send(&mut self, request: &mut Request) -> Result<(), Self::SendError> {
+        self.requests.push(SentRequest {
+            target: request.target(),
+            netfn: request.netfn(),
+            cmd: request.cmd(),
+            data: request.data().to_vec(),
+        });
+
+        Ok(())
+    }
+
+    fn recv(&mut self) -> Result<Response, Self::RecvError> {
+        self.responses
+            .pop_front()
+            .ok_or_else(|| std::io::Error::other("No response queued"))
+    }
+
+    fn send_recv(&mut self, request: &mut Request) -> Result<Response, Self::Error> {
+        self.send(request)?;
+        self.recv()
+    }
+}
@@ -0,0 +1,75 @@
+use ipmi_rs_core::{connection::NetFn, storage::sdr::record::RecordContents};
+
+use super::MockConnection;
+use crate::Ipmi;
+
+#[test]
+fn partial_read_split_header() {
+    let record = [
+        0x01, 0x00, 0x51, 0x11, 0x0E, 0x20, 0x02, 0x88, 0x00, 0x00, 0x10, 0x00, 0x07, 0x01, 0x00,
+        0xC3, b'F', b'R', b'U',
+    ];
+
+    let chunk = |data: &[u8]| [[0xFF, 0xFF].as_slice(), data].concat();
+
+    let mut ipmi = Ipmi::new(MockConnection::default());
+    ipmi.inner_mut()
+        .respond(NetFn::Storage, 0x22, 0x00, &[0x01, 0x00])
+        // The first response contains only part of the header.
+        .respond(NetFn::Storage, 0x23, 0x00, &chunk(&record[..3]))
+        .respond(NetFn::Storage, 0x23, 0x00, &chunk(&record[3..5]))
+        .respond(NetFn::Storage, 0x23, 0x00, &chunk(&record[5..]));
+
+    let records: Vec<_> = ipmi.sdrs().collect();
+
+    assert_eq!(1, records.len());
+    assert!(matches!(
+        records[0].contents,
+        RecordContents::FruDeviceLocator(_)
+    ));
+
+    let requests = &ipmi.inner_mut().requests;
+    assert_eq!(4, requests.len());
+    // Offset and amount of bytes of the last request.
+    assert_eq!([0x05, 0x0E], requests[3].data[4..]);
+}
+
+#[test]
+fn partial_add_long_record() {
+    let mut record = vec![0x00, 0x00, 0x51, 0xC0, 0xFF];
+    record.extend((0..255).map(|v| v as u8));
+
+    let mut ipmi = Ipmi::new(MockConnection::default());
+    let connection = ipmi.inner_mut();
+    connection
+        // Non-modal updates, Reserve, Partial Add and Delete are supported.
+        .respond(
+            NetFn::Storage,
+            0x20,
+            0x00,
+            &[
+                0x51, 0x01, 0x00, 0xFF, 0xFF, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x2E,
+            ],
+        )
+        .respond(NetFn::Storage, 0x22, 0x00, &[0x01, 0x00]);
+
+    for _ in 0..16 {
+        connection.respond(NetFn::Storage, 0x25, 0x00, &[0x42, 0x00]);
+    }
+
+    assert_eq!(0x0042, ipmi.add_sdr(&record).unwrap().value());
+
+    let parts: Vec<_> = ipmi.inner_mut().requests[2..].to_vec();
+    assert_eq!(16, parts.len());
+
+    let mut written = Vec::new();
+    for (idx, part) in parts.iter().enumerate() {
+        assert_eq!(written.len(), part.data[4] as usize);
+        assert_eq!(idx == 15, part.data[5] == 0x01);
+        written.extend_from_slice(&part.data[6..]);
+    }
+
+    // The last part starts at offset 240 and contains the remaining 20 bytes.
+    assert_eq!(240, parts[15].data[4]);
+    assert_eq!(record, written);
+}
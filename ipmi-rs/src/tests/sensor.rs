@@ -0,0 +1,98 @@
+use ipmi_rs_core::{
+    connection::NetFn,
+    storage::sdr::{
+        record::{Linearization, ThresholdKind},
+        Record,
+    },
+};
+
+use super::MockConnection;
+use crate::Ipmi;
+
+/// The full sensor record of the "Fan2A" sensor of a Dell R630.
+const FAN_2A_SDR: [u8; 53] = [
+    0x0D, 0x00, 0x51, 0x01, 0x30, 0x20, 0x00, 0x32, 0x07, 0x01, 0x7F, 0xD4, 0x04, 0x01, 0x05, 0x30,
+    0x05, 0x00, 0x03, 0x00, 0x00, 0x12, 0x00, 0x00, 0x78, 0x02, 0x00, 0x02, 0x30, 0x00, 0x07, 0x54,
+    0xC5, 0x8B, 0xFF, 0x00, 0xFF, 0xFF, 0xFF, 0x00, 0x05, 0x07, 0x01, 0x01, 0x00, 0x00, 0x00, 0xC5,
+    0x46, 0x61, 0x6E, 0x32, 0x41,
+];
+
+#[test]
+fn non_linear_thresholds() {
+    let mut sdr = FAN_2A_SDR;
+    // Non-linear, requiring Get Sensor Reading Factors.
+    sdr[23] = 0x70;
+
+    let record = Record::parse(&sdr).unwrap();
+    let sensor = record.full_sensor().unwrap();
+    assert_eq!(Linearization::NonLinear, sensor.linearization);
+
+    let mut ipmi = Ipmi::new(MockConnection::default());
+    ipmi.inner_mut()
+        // Only the lower critical threshold (raw value 5) is readable.
+        .respond(
+            NetFn::SensorEvent,
+            0x27,
+            0x00,
+            &[0x02, 0x00, 0x05, 0x00, 0x00, 0x00, 0x00],
+        )
+        // M = 2 for raw value 5.
+        .respond(
+            NetFn::SensorEvent,
+            0x23,
+            0x00,
+            &[0x06, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00],
+        );
+
+    let thresholds = ipmi.sensor_thresholds(sensor).unwrap();
+
+    assert_eq!(1, thresholds.len());
+    assert_eq!(ThresholdKind::LowerCritical, thresholds[0].kind);
+    assert_eq!(10.0, thresholds[0].value.value());
+
+    let requests = &ipmi.inner_mut().requests;
+    assert_eq!(2, requests.len());
+    assert_eq!(0x23, requests[1].cmd);
+    assert_eq!(vec![0x32, 0x05], requests[1].data);
+}
+
+#[test]
+fn rearm_shared_sensors() {
+    // A compact sensor record for sensors 0x10 through 0x12, which require
+    // manual re-arming.
+    let mut record = vec![0x01, 0x00, 0x51, 0x02, 0x1C];
+    record.extend_from_slice(&[0x20, 0x00, 0x10, 0x03, 0x01, 0x00, 0x00]);
+    record.extend_from_slice(&[0x00; 11]);
+    record.extend_from_slice(&[0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]);
+    record.extend_from_slice(&[0xC1, b'A']);
+
+    let mut ipmi = Ipmi::new(MockConnection::default());
+    ipmi.inner_mut()
+        // Reserve SDR Repository is not supported, so the record is read in full.
+        .respond(NetFn::Storage, 0x22, 0xC1, &[])
+        .respond(
+            NetFn::Storage,
+            0x23,
+            0x00,
+            &[[0xFF, 0xFF].as_slice(), &record].concat(),
+        )
+        .respond(NetFn::SensorEvent, 0x2A, 0x00, &[])
+        .respond(NetFn::SensorEvent, 0x2A, 0x00, &[])
+        .respond(NetFn::SensorEvent, 0x2A, 0x00, &[]);
+
+    let rearmed = ipmi.rearm_manual_sensors();
+
+    let sensors: Vec<_> = rearmed
+        .iter()
+        .map(|v| (v.sensor_id.to_string(), v.key.sensor_number.get()))
+        .collect();
+    assert_eq!(
+        vec![
+            ("A0".to_string(), 0x10),
+            ("A1".to_string(), 0x11),
+            ("A2".to_string(), 0x12)
+        ],
+        sensors
+    );
+    assert!(rearmed.iter().all(|v| v.result.is_ok()));
+}